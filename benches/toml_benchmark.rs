@@ -1,6 +1,7 @@
-use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion::{Criterion, criterion_group, criterion_main};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::hint::black_box;
 
 // Minimal config struct for parsing
 #[derive(Debug, Deserialize)]
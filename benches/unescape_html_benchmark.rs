@@ -1,6 +1,7 @@
 // benches/unescape_html_benchmark.rs
 
-use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
 use std::time::Duration;
 
 // Current implementation from src/syntax.rs
@@ -57,7 +58,7 @@ pub fn unescape_html_entities_single_pass(input: &str) -> String {
                 }
             }
             s if s.starts_with("&#") => {
-                if let Ok(v) = u32::from_str_radix(&s[2..], 10) {
+                if let Ok(v) = s[2..].parse::<u32>() {
                     Some(char::from_u32(v).unwrap_or('?'))
                 } else {
                     None
@@ -1,18 +1,41 @@
 // src/build.rs
 
+use minijinja::Value;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use std::path::PathBuf;
-use tracing::{debug, info, instrument};
-
-use crate::asset_hash::{export_manifest_to_json, hash_static_assets};
-use crate::config::Config;
-use crate::content::{Content, convert_content_with_highlighting, load_content};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use tracing::{debug, error, info, instrument, warn};
+
+use crate::asset_hash::{AssetManifest, export_manifest_to_json, hash_static_assets};
+use crate::bundle::{asset_dir, asset_url, bundle_assets, bundle_dir, rewrite_asset_links};
+use crate::cache::{
+    BuildCache, CACHE_DIR, PageEntry, hash_markdown, hash_page_render, hash_render_inputs,
+    hash_section,
+};
+use crate::collision::claim_outputs;
+use crate::config::{BrokenLinks, Config, ListingOrder};
+use crate::content::{
+    Content, MetaDefaults, SectionIndex, convert_content_with_highlighting, excerpt_html,
+    load_content, load_section_index,
+};
 use crate::error::RunError;
-use crate::output::{copy_static_files, write_output_file};
-use crate::template::{create_environment_with_manifest, render_html, render_index_from_loaded};
+use crate::links::LinkMap;
+use crate::output::{copy_bundle_assets, copy_static_files, write_output_file};
+use crate::pagination::{page_output_path, paginate};
+use crate::publish::Visibility;
+use crate::section::SectionTree;
+use crate::taxonomy::{Taxonomy, collect_taxonomies, index_output_path, term_output_path};
+use crate::template::{
+    SECTION_VARIABLES, Section, create_environment_with_manifest, render_html,
+    render_index_from_loaded, render_index_page, render_taxonomy_index, render_taxonomy_term,
+    sort_listing,
+};
+use crate::template_deps::TemplateGraph;
+use crate::toc::insert_toc;
 use crate::utils::{
-    build_output_path, find_markdown_files, get_content_type, get_content_type_template,
-    resolve_url_pattern,
+    build_output_path, find_markdown_files, find_section_indexes, get_content_type,
+    get_page_template, get_section, resolve_url_pattern,
 };
 use crate::{rss, sitemap};
 
@@ -22,78 +45,318 @@ pub(crate) struct LoadedContent {
     pub(crate) path: PathBuf,
     pub(crate) content: Content,
     pub(crate) html: String,
+    /// Excerpt for indexes and the RSS feed, see [`excerpt_html`]; its links are
+    /// rewritten along with `html`
+    pub(crate) excerpt: String,
     pub(crate) content_type: String,
+    /// Directory below `content_dir` (e.g., "docs/guides"), see [`get_section`]
+    pub(crate) section: String,
+    /// Files colocated with a page bundle's `index.md`, see [`bundle_assets`]
+    pub(crate) assets: Vec<String>,
     pub(crate) output_path: PathBuf,
 }
 
 /// The main entry point for the application logic.
-pub(crate) fn build(config_file: &str, include_drafts: bool) -> Result<(), RunError> {
+pub(crate) fn build(config_file: &str, visibility: Visibility) -> Result<(), RunError> {
     let config = Config::load_from_file(config_file)?;
+    run_build(config_file, &config, StaticAssets::Hash, visibility).map(drop)
+}
 
-    // Hash static assets if enabled
-    let manifest = if config.site.asset_hashing_enabled {
-        Some(hash_static_assets(
-            &config.site.static_dir,
-            &config.site.output_dir,
-        )?)
-    } else {
-        None
-    };
+/// Build with detailed tracing spans for flamechart profiling.
+#[instrument(name = "build", skip_all)]
+pub(crate) fn build_with_spans(config_file: &str, visibility: Visibility) -> Result<(), RunError> {
+    let config = Config::load_from_file(config_file)?;
+    run_build_with_spans(config_file, &config, visibility)
+}
 
-    // Export manifest to JSON if path is configured
-    if let (Some(manifest), Some(path)) = (&manifest, &config.site.asset_manifest_path) {
-        export_manifest_to_json(manifest, path)?;
+/// Build with a fresh template environment (for watch mode).
+///
+/// Returns the asset manifest so later [`build_pages`] calls can reuse it.
+pub(crate) fn build_fresh(
+    config_file: &str,
+    visibility: Visibility,
+) -> Result<Option<AssetManifest>, RunError> {
+    let config = Config::load_from_file(config_file)?;
+    run_build(config_file, &config, StaticAssets::Hash, visibility)
+}
+
+/// Rebuild pages with a fresh template environment, reusing the asset manifest of an
+/// earlier build instead of re-hashing static assets (for watch mode).
+pub(crate) fn build_pages(
+    config_file: &str,
+    visibility: Visibility,
+    manifest: Option<&AssetManifest>,
+) -> Result<(), RunError> {
+    let config = Config::load_from_file(config_file)?;
+    run_build(
+        config_file,
+        &config,
+        StaticAssets::Reuse(manifest),
+        visibility,
+    )
+    .map(drop)
+}
+
+/// Where a build gets its asset manifest from.
+#[derive(Clone, Copy)]
+enum StaticAssets<'a> {
+    /// Hash static assets (when `asset_hashing_enabled`) once the output layout is checked
+    Hash,
+    /// Reuse the manifest of an earlier build
+    Reuse(Option<&'a AssetManifest>),
+}
+
+/// Hashes static assets if enabled, exporting the manifest if a path is configured.
+fn hash_assets(config: &Config) -> Result<Option<AssetManifest>, RunError> {
+    if !config.site.asset_hashing_enabled {
+        return Ok(None);
     }
 
-    let env = create_environment_with_manifest(&config.site.template_dir, manifest.as_ref());
-    run_build(config_file, &config, &env, include_drafts)
+    let manifest = hash_static_assets(&config.site.static_dir, &config.site.output_dir)?;
+    if let Some(path) = &config.site.asset_manifest_path {
+        export_manifest_to_json(&manifest, path)?;
+    }
+    Ok(Some(manifest))
 }
 
-/// Build with detailed tracing spans for flamechart profiling.
-#[instrument(name = "build", skip_all)]
-pub(crate) fn build_with_spans(config_file: &str, include_drafts: bool) -> Result<(), RunError> {
-    let config = Config::load_from_file(config_file)?;
+/// Fails with [`RunError::MissingTemplate`] when a page's template cannot be loaded,
+/// so a typo in a per-page `template` override names the page and the template.
+fn ensure_template_exists(
+    env: &minijinja::Environment,
+    config: &Config,
+    page: &Path,
+    template: &str,
+) -> Result<(), RunError> {
+    match env.get_template(template) {
+        Err(e) if e.kind() == minijinja::ErrorKind::TemplateNotFound => {
+            Err(RunError::MissingTemplate {
+                page: page.to_path_buf(),
+                template: template.to_string(),
+                template_dir: config.site.template_dir.clone(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Loads every section's `_index.md`, keyed by section path.
+fn load_section_indexes(
+    config: &Config,
+    files: &[PathBuf],
+) -> Result<HashMap<String, SectionIndex>, RunError> {
+    files
+        .iter()
+        .map(|file| {
+            debug!("section::load ← {}", file.display());
+            let section = get_section(file, &config.site.content_dir);
+            Ok((section, load_section_index(file, &config.site)?))
+        })
+        .collect()
+}
+
+/// Finds a page bundle's assets and points the relative links to them in the page
+/// HTML and its excerpt at the directory they are copied to (see
+/// [`rewrite_asset_links`]).
+///
+/// Returns the assets, the HTML and the excerpt; ordinary pages come back unchanged.
+fn localize_bundle(
+    file: &Path,
+    html: String,
+    excerpt: String,
+    output_path: &Path,
+    config: &Config,
+) -> (Vec<String>, String, String) {
+    let assets = bundle_assets(file, &config.site.content_dir);
+    if assets.is_empty() {
+        return (assets, html, excerpt);
+    }
 
-    // Hash static assets if enabled
-    let manifest = if config.site.asset_hashing_enabled {
-        Some(hash_static_assets(
-            &config.site.static_dir,
-            &config.site.output_dir,
-        )?)
-    } else {
-        None
+    let base_url = asset_url(
+        &asset_dir(output_path, config.site.clean_urls),
+        &config.site.output_dir,
+    );
+    let html = rewrite_asset_links(&html, &assets, &base_url);
+    let excerpt = rewrite_asset_links(&excerpt, &assets, &base_url);
+    (assets, html, excerpt)
+}
+
+/// Copies a page bundle's assets into the directory of its output page.
+fn copy_page_assets(loaded: &LoadedContent, config: &Config) -> Result<(), RunError> {
+    let Some(dir) = bundle_dir(&loaded.path, &config.site.content_dir) else {
+        return Ok(());
     };
 
-    // Export manifest to JSON if path is configured
-    if let (Some(manifest), Some(path)) = (&manifest, &config.site.asset_manifest_path) {
-        export_manifest_to_json(manifest, path)?;
+    let dest = asset_dir(&loaded.output_path, config.site.clean_urls);
+    let copied = copy_bundle_assets(dir, &loaded.assets, &dest)?;
+    if copied > 0 {
+        debug!(
+            "bundle::copy {} assets of {} → {}",
+            copied,
+            loaded.path.display(),
+            dest.display()
+        );
     }
+    Ok(())
+}
 
-    let env = create_environment_with_manifest(&config.site.template_dir, manifest.as_ref());
-    run_build_with_spans(config_file, &config, &env, include_drafts)
+/// Points links to other markdown files in pages and their excerpts at the pages
+/// they render to (see [`LinkMap::resolve`]). Links that match no page are logged,
+/// or fail the build with `broken_links = "error"`.
+fn resolve_internal_links(
+    config: &Config,
+    loaded_contents: &mut [LoadedContent],
+    section_index_files: &[PathBuf],
+) -> Result<(), RunError> {
+    let links = LinkMap::new(config, loaded_contents, section_index_files);
+    for loaded in loaded_contents.iter_mut() {
+        let (html, mut broken) = links.resolve(&loaded.html, &loaded.path);
+        let (excerpt, excerpt_broken) = links.resolve(&loaded.excerpt, &loaded.path);
+        for link in excerpt_broken {
+            if !broken.contains(&link) {
+                broken.push(link);
+            }
+        }
+        for link in broken {
+            match config.site.broken_links {
+                BrokenLinks::Warn => {
+                    warn!("links::broken {} → {}", loaded.path.display(), link);
+                }
+                BrokenLinks::Error => {
+                    return Err(RunError::BrokenLink {
+                        page: loaded.path.clone(),
+                        link,
+                    });
+                }
+            }
+        }
+        loaded.html = html;
+        loaded.excerpt = excerpt;
+    }
+    Ok(())
 }
 
-/// Build with a fresh template environment (for watch mode).
-pub(crate) fn build_fresh(config_file: &str, include_drafts: bool) -> Result<(), RunError> {
-    let config = Config::load_from_file(config_file)?;
+/// Fails with [`RunError::OutputCollisions`] when two sources would write the same
+/// output file (see [`claim_outputs`]), logging each collision with its sources.
+fn check_output_collisions(
+    config: &Config,
+    loaded_contents: &[LoadedContent],
+    tree: &SectionTree,
+    taxonomies: &[Taxonomy],
+) -> Result<(), RunError> {
+    let collisions = claim_outputs(config, loaded_contents, tree, taxonomies).collisions();
+    if collisions.is_empty() {
+        return Ok(());
+    }
+
+    for collision in &collisions {
+        error!(
+            "output::collision {} ← {}",
+            collision.output.display(),
+            collision.sources.join(", ")
+        );
+    }
+    Err(RunError::OutputCollisions(collisions))
+}
+
+/// Groups pages by section for prev/next/sibling navigation in content templates.
+fn build_sections<'a>(
+    loaded_contents: &'a [LoadedContent],
+    tree: &SectionTree,
+    config: &Config,
+) -> HashMap<&'a str, Section> {
+    let mut grouped: HashMap<&str, Vec<&LoadedContent>> = HashMap::new();
+    for loaded in loaded_contents {
+        grouped.entry(&loaded.section).or_default().push(loaded);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(section, pages)| {
+            let order = config
+                .section_config(section)
+                .map(|ct| ct.listing_order())
+                .unwrap_or_default();
+            let info = if tree.contains(section) {
+                Value::from_serialize(tree.info(section))
+            } else {
+                Value::UNDEFINED
+            };
+            (section, Section::new(&pages, &order, config, info))
+        })
+        .collect()
+}
+
+/// Settings of one index listing (a section or the site index).
+struct IndexSpec<'a> {
+    /// Index template name
+    template: &'a str,
+    /// Listing directory below the output root (`""` for the site index)
+    base: &'a str,
+    /// Items per page, if the listing is paginated
+    paginate_by: Option<NonZeroUsize>,
+    /// Order of the listed items
+    order: ListingOrder,
+    /// The listed section as seen by templates (undefined for the site index)
+    section: Value,
+}
 
-    // Hash static assets if enabled
-    let manifest = if config.site.asset_hashing_enabled {
-        Some(hash_static_assets(
-            &config.site.static_dir,
-            &config.site.output_dir,
-        )?)
-    } else {
-        None
+/// Renders an index listing to `<output_dir>/<base>/index.html`, or to one file per
+/// page when `paginate_by` is set (see [`paginate`]).
+///
+/// Returns the number of pages written.
+fn write_index(
+    env: &minijinja::Environment,
+    config: &Config,
+    spec: &IndexSpec,
+    listing: Vec<&LoadedContent>,
+    all_content: &[LoadedContent],
+) -> Result<usize, RunError> {
+    let IndexSpec {
+        template: index_template,
+        base,
+        paginate_by,
+        ref order,
+        ref section,
+    } = *spec;
+    let output_dir = &config.site.output_dir;
+    let clean_urls = config.site.clean_urls;
+
+    let Some(per_page) = paginate_by else {
+        let rendered = render_index_from_loaded(
+            env,
+            config,
+            index_template,
+            listing,
+            all_content.iter().collect(),
+            order,
+            section,
+        )?;
+        write_output_file(
+            &page_output_path(output_dir, base, 1, clean_urls),
+            &rendered,
+        )?;
+        return Ok(1);
     };
 
-    // Export manifest to JSON if path is configured
-    if let (Some(manifest), Some(path)) = (&manifest, &config.site.asset_manifest_path) {
-        export_manifest_to_json(manifest, path)?;
+    let sorted = sort_listing(&listing, order);
+    let pages = paginate(base, sorted.len(), per_page, clean_urls);
+    for page in &pages {
+        let rendered = render_index_page(
+            env,
+            config,
+            index_template,
+            page,
+            &sorted,
+            all_content.iter().collect(),
+            section,
+        )?;
+        write_output_file(
+            &page_output_path(output_dir, base, page.current, clean_urls),
+            &rendered,
+        )?;
     }
 
-    let env = create_environment_with_manifest(&config.site.template_dir, manifest.as_ref());
-    run_build(config_file, &config, &env, include_drafts)
+    Ok(pages.len())
 }
 
 /// Get the list of file paths/directories to watch for changes.
@@ -106,49 +369,68 @@ pub(crate) fn get_paths_to_watch(config_file: &str, config: &Config) -> Vec<Stri
     ]
 }
 
-/// Core build logic that accepts a template environment.
+/// Core build logic.
+///
+/// Pages are rebuilt incrementally using the build cache in [`CACHE_DIR`]. Nothing
+/// is written, static assets included, until every output has a single source.
+/// Returns the manifest of the assets hashed by this build.
 fn run_build(
     config_file: &str,
     config: &Config,
-    env: &minijinja::Environment,
-    include_drafts: bool,
-) -> Result<(), RunError> {
+    assets: StaticAssets,
+    visibility: Visibility,
+) -> Result<Option<AssetManifest>, RunError> {
     debug!("config::load ← {}", config_file);
 
-    // 0. Copy static files first
-    //
-    copy_static_files(config)?;
+    let cache = BuildCache::load(CACHE_DIR);
+    let templates = TemplateGraph::load(&config.site.template_dir);
 
     // 1. Find all markdown files in `config.content_dir`.
     //
     let files = find_markdown_files(&config.site.content_dir);
-    debug!("content::scan found {} files", files.len());
+    let section_index_files = find_section_indexes(&config.site.content_dir);
+    debug!(
+        "content::scan found {} files, {} section indexes",
+        files.len(),
+        section_index_files.len()
+    );
+    let meta_defaults = MetaDefaults::from_site(&config.site)?;
 
     // 2. Loading all content
     //
     let start = std::time::Instant::now();
 
-    let loaded_contents: Vec<LoadedContent> = files
+    let loaded_contents: Vec<(LoadedContent, String)> = files
         .into_par_iter() // Parallel iterator - consumes Vec for owned PathBufs
-        .map(|file| -> Result<LoadedContent, RunError> {
+        .map(|file| -> Result<(LoadedContent, String), RunError> {
             debug!("content::load ← {}", file.display());
 
             let content_type = get_content_type(&file, &config.site.content_dir);
-            let content = load_content(&file)?;
-            let html = convert_content_with_highlighting(
-                &content,
-                &file, // Pass reference - no clone needed
-                config.site.syntax_highlighting_enabled,
-                &config.site.syntax_highlighting_theme,
-                config.site.allow_dangerous_html,
-                config.site.header_uri_fragment,
-            )?;
+            let section = get_section(&file, &config.site.content_dir);
+            let content = load_content(&file, &meta_defaults)?;
+
+            // Reuse the cached conversion when the markdown is unchanged
+            let html_hash = hash_markdown(&content.data, config);
+            let html = match cache.cached_html(&file, &html_hash) {
+                Some(html) => html,
+                None => {
+                    let html = convert_content_with_highlighting(
+                        &content,
+                        &file, // Pass reference - no clone needed
+                        config.site.syntax_highlighting_enabled,
+                        &config.site.syntax_highlighting_theme,
+                        config.site.allow_dangerous_html,
+                        config.site.header_uri_fragment,
+                    )?;
+                    cache.store_html(&html_hash, &html);
+                    html
+                }
+            };
 
             // Get URL pattern for this content type
             // Priority: url_pattern (new) > output_naming (deprecated) > default
             let pattern = config
-                .content
-                .get(&content_type)
+                .section_config(&section)
                 .and_then(|ct| {
                     ct.url_pattern.clone().or_else(|| {
                         // Backwards compatibility: map output_naming to url_pattern
@@ -160,39 +442,55 @@ fn run_build(
                 })
                 .unwrap_or_else(|| "{stem}".to_string());
 
-            // Get the filename from the file path
-            let filename = file.file_name().and_then(|f| f.to_str()).unwrap_or("index");
+            // Get the filename from the file path (a page bundle is named after its directory)
+            let filename = bundle_dir(&file, &config.site.content_dir)
+                .unwrap_or(&file)
+                .file_name()
+                .and_then(|f| f.to_str())
+                .unwrap_or("index");
 
             // Resolve the URL pattern using meta.date
             let resolved = resolve_url_pattern(&pattern, filename, &content.meta.date);
 
             // Build the output path
             let output_path = build_output_path(
-                &content_type,
+                &section,
                 &resolved,
                 &config.site.output_dir,
                 config.site.clean_urls,
             );
-
-            Ok(LoadedContent {
-                path: file, // Move owned PathBuf - no clone needed
-                content,
-                html,
-                content_type,
-                output_path,
-            })
+            let html = insert_toc(&html, content.meta.toc, &config.site.toc);
+            let excerpt = excerpt_html(
+                &content,
+                &html,
+                config.excerpt_config(&section),
+                config.site.allow_dangerous_html,
+            );
+            let (assets, html, excerpt) =
+                localize_bundle(&file, html, excerpt, &output_path, config);
+
+            Ok((
+                LoadedContent {
+                    path: file, // Move owned PathBuf - no clone needed
+                    content,
+                    html,
+                    excerpt,
+                    content_type,
+                    section,
+                    assets,
+                    output_path,
+                },
+                html_hash,
+            ))
         })
         .collect::<Result<Vec<_>, _>>()?; // Collect Results, fail fast on error
 
-    // Filter out draft content unless --include-drafts is set
-    let loaded_contents: Vec<LoadedContent> = if include_drafts {
-        loaded_contents
-    } else {
-        loaded_contents
-            .into_iter()
-            .filter(|lc| !lc.content.meta.draft)
-            .collect()
-    };
+    // Filter out drafts, scheduled and expired content (see `Visibility`)
+    let now = visibility.now();
+    let (mut loaded_contents, html_hashes): (Vec<LoadedContent>, Vec<String>) = loaded_contents
+        .into_iter()
+        .filter(|(lc, _)| visibility.includes(&lc.content.meta, now))
+        .unzip();
 
     info!(
         "content::load {} files in {:.2?}",
@@ -200,66 +498,209 @@ fn run_build(
         start.elapsed()
     );
 
-    // 3. Write individual pages
+    // Point links to other markdown files at the pages they render to
+    resolve_internal_links(config, &mut loaded_contents, &section_index_files)?;
+
+    // 3. Write individual pages (skipping pages whose inputs are unchanged)
     //
-    for loaded in &loaded_contents {
-        info!(
-            "content::render {} → {}",
-            loaded.path.display(),
-            loaded.output_path.display()
-        );
+    let section_indexes = load_section_indexes(config, &section_index_files)?;
+    let tree = SectionTree::collect(config, &loaded_contents, section_indexes);
+    let taxonomies = collect_taxonomies(config, &loaded_contents);
+
+    // Nothing is written until every output file has a single source
+    check_output_collisions(config, &loaded_contents, &tree, &taxonomies)?;
+    let hashed = match assets {
+        StaticAssets::Hash => hash_assets(config)?,
+        StaticAssets::Reuse(_) => None,
+    };
+    let manifest = match assets {
+        StaticAssets::Hash => hashed.as_ref(),
+        StaticAssets::Reuse(manifest) => manifest,
+    };
+    let env = &create_environment_with_manifest(&config.site.template_dir, manifest);
+    let render_inputs = hash_render_inputs(config_file, manifest);
+    copy_static_files(config)?;
 
-        let content_template = get_content_type_template(config, &loaded.content_type);
-        let rendered = render_html(
-            env,
-            &loaded.html,
-            &loaded.content.meta,
-            config,
-            &content_template,
-        )?;
-        write_output_file(&loaded.output_path, &rendered)?;
+    let sections = build_sections(&loaded_contents, &tree, config);
+    let mut section_pages: HashMap<&str, Vec<(&Path, &str)>> = HashMap::new();
+    for (loaded, html_hash) in loaded_contents.iter().zip(&html_hashes) {
+        section_pages
+            .entry(&loaded.content_type)
+            .or_default()
+            .push((&loaded.path, html_hash));
     }
+    // Section titles and intros from `_index.md` show up in breadcrumbs
+    let index_types: Vec<(String, &Path)> = section_index_files
+        .iter()
+        .map(|file| {
+            (
+                get_content_type(file, &config.site.content_dir),
+                file.as_path(),
+            )
+        })
+        .collect();
+    for (content_type, file) in &index_types {
+        section_pages
+            .entry(content_type)
+            .or_default()
+            .push((file, ""));
+    }
+    let section_hashes: HashMap<&str, String> = section_pages
+        .into_iter()
+        .map(|(content_type, pages)| (content_type, hash_section(pages)))
+        .collect();
+
+    let mut fresh = 0;
+    let mut template_hashes: HashMap<String, (String, bool)> = HashMap::new();
+    for (loaded, html_hash) in loaded_contents.iter().zip(html_hashes) {
+        let content_template = get_page_template(config, &loaded.content.meta, &loaded.section);
+        let (template_hash, uses_section) = template_hashes
+            .entry(content_template.clone())
+            .or_insert_with(|| {
+                (
+                    templates.hash(&content_template),
+                    templates.uses_any(&content_template, &SECTION_VARIABLES),
+                )
+            });
+        // Only pages whose templates look at their siblings depend on them
+        let section_hash = if *uses_section {
+            section_hashes[loaded.content_type.as_str()].as_str()
+        } else {
+            ""
+        };
+        let entry = PageEntry {
+            render: hash_page_render(
+                &loaded.html,
+                &loaded.path,
+                template_hash,
+                section_hash,
+                &render_inputs,
+            ),
+            html: html_hash,
+        };
+
+        copy_page_assets(loaded, config)?;
+        if cache.is_fresh(&loaded.path, &entry, &loaded.output_path) {
+            debug!(
+                "content::fresh {} [{}]",
+                loaded.path.display(),
+                content_template
+            );
+            fresh += 1;
+        } else {
+            info!(
+                "content::render {} → {} [{}]",
+                loaded.path.display(),
+                loaded.output_path.display(),
+                content_template
+            );
 
-    // 4. Render content type indexes
-    //
-    for (content_type, v) in config.content.iter() {
-        info!("index::render {} → {}", content_type, v.index_template);
+            ensure_template_exists(env, config, &loaded.path, &content_template)?;
+
+            let rendered = render_html(
+                env,
+                &loaded.html,
+                &loaded.content.meta,
+                config,
+                &content_template,
+                &sections[loaded.section.as_str()].nav(&loaded.path),
+            )?;
+            write_output_file(&loaded.output_path, &rendered)?;
+        }
+        cache.record(&loaded.path, entry);
+    }
+    info!(
+        "cache::pages {} unchanged, {} rendered",
+        fresh,
+        loaded_contents.len() - fresh
+    );
 
-        let filtered: Vec<_> = loaded_contents
-            .iter()
-            .filter(|lc| &lc.content_type == content_type)
-            .collect();
+    // 4. Render section indexes (every configured section and its subsections)
+    //
+    for path in tree.paths() {
+        let v = config
+            .section_config(path)
+            .expect("sections in the tree are configured");
+        info!("index::render {} → {}", path, v.index_template);
 
-        let index_rendered = render_index_from_loaded(
+        write_index(
             env,
             config,
-            &v.index_template,
-            filtered,
-            loaded_contents.iter().collect(),
+            &IndexSpec {
+                template: &v.index_template,
+                base: path,
+                paginate_by: v.paginate_by,
+                order: v.listing_order(),
+                section: Value::from_serialize(tree.info(path)),
+            },
+            tree.pages(path),
+            &loaded_contents,
         )?;
-
-        let output_path = PathBuf::from(&config.site.output_dir)
-            .join(content_type)
-            .join("index.html");
-
-        write_output_file(&output_path, &index_rendered)?;
     }
 
     // 5. Render site index
     //
-    let site_index_rendered = render_index_from_loaded(
+    let site_index_pages = write_index(
         env,
         config,
-        &config.site.site_index_template,
-        loaded_contents.iter().collect(),
+        &IndexSpec {
+            template: &config.site.site_index_template,
+            base: "",
+            paginate_by: config.site.paginate_by,
+            order: ListingOrder::default(),
+            section: Value::from_serialize(tree.root_info(&config.site.title)),
+        },
         loaded_contents.iter().collect(),
+        &loaded_contents,
     )?;
+    info!(
+        "index::render site → {} ({} pages)",
+        PathBuf::from(&config.site.output_dir)
+            .join("index.html")
+            .display(),
+        site_index_pages
+    );
+
+    // 6. Render taxonomy term listings and term pages
+    //
+    for taxonomy in &taxonomies {
+        let tc = &config.taxonomies[&taxonomy.name];
+        info!(
+            "taxonomy::render {} ({} terms) → {}",
+            taxonomy.name,
+            taxonomy.terms.len(),
+            tc.index_template
+        );
 
-    let site_index_path = PathBuf::from(&config.site.output_dir).join("index.html");
-    info!("index::render site → {}", site_index_path.display());
-    write_output_file(&site_index_path, &site_index_rendered)?;
+        let index_rendered = render_taxonomy_index(
+            env,
+            config,
+            &tc.index_template,
+            taxonomy,
+            loaded_contents.iter().collect(),
+        )?;
+        write_output_file(
+            &index_output_path(&config.site.output_dir, &taxonomy.name),
+            &index_rendered,
+        )?;
+
+        for term in &taxonomy.terms {
+            let term_rendered = render_taxonomy_term(
+                env,
+                config,
+                &tc.term_template,
+                taxonomy,
+                term,
+                loaded_contents.iter().collect(),
+            )?;
+            write_output_file(
+                &term_output_path(&config.site.output_dir, &taxonomy.name, &term.item.slug),
+                &term_rendered,
+            )?;
+        }
+    }
 
-    // 6. Generate sitemap.xml (if enabled)
+    // 7. Generate sitemap.xml (if enabled)
     //
     if config.site.sitemap_enabled {
         let sitemap_xml = sitemap::generate_sitemap(config, &loaded_contents);
@@ -270,7 +711,7 @@ fn run_build(
         info!("sitemap::write → sitemap.xml");
     }
 
-    // 7. Generate RSS feed (if enabled)
+    // 8. Generate RSS feed (if enabled)
     //
     if config.site.rss_enabled {
         let rss_xml = rss::generate_rss(config, &loaded_contents);
@@ -281,7 +722,7 @@ fn run_build(
         info!("rss::write → feed.xml");
     }
 
-    // 8. Generate redirect HTML files (if configured)
+    // 9. Generate redirect HTML files (if configured)
     //
     if !config.redirects.is_empty() {
         for (from_path, to_path) in &config.redirects {
@@ -294,29 +735,54 @@ fn run_build(
         }
     }
 
+    // 10. Render custom 404 page (if configured)
+    //
+    if let Some(not_found_template) = &config.site.not_found_template {
+        let not_found_rendered = render_index_from_loaded(
+            env,
+            config,
+            not_found_template,
+            vec![],
+            loaded_contents.iter().collect(),
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        )?;
+        let not_found_path = PathBuf::from(&config.site.output_dir).join("404.html");
+        info!(
+            "404::render {} → {}",
+            not_found_template,
+            not_found_path.display()
+        );
+        write_output_file(&not_found_path, &not_found_rendered)?;
+    }
+
+    cache.save();
+
     info!("build::complete ✓");
-    Ok(())
+    Ok(hashed)
 }
 
 /// Core build logic with detailed tracing spans for profiling.
+///
+/// Bypasses the build cache so every profile measures a full build.
 #[instrument(name = "run_build", skip_all)]
 fn run_build_with_spans(
     config_file: &str,
     config: &Config,
-    env: &minijinja::Environment,
-    include_drafts: bool,
+    visibility: Visibility,
 ) -> Result<(), RunError> {
     debug!("config::load ← {}", config_file);
 
-    // 0. Copy static files first
-    let _static_span = tracing::info_span!("copy_static_files").entered();
-    copy_static_files(config)?;
-    drop(_static_span);
-
     // 1. Find all markdown files
     let _scan_span = tracing::info_span!("find_markdown_files").entered();
     let files = find_markdown_files(&config.site.content_dir);
-    debug!("content::scan found {} files", files.len());
+    let section_index_files = find_section_indexes(&config.site.content_dir);
+    debug!(
+        "content::scan found {} files, {} section indexes",
+        files.len(),
+        section_index_files.len()
+    );
+    let meta_defaults = MetaDefaults::from_site(&config.site)?;
     drop(_scan_span);
 
     // 2. Loading all content (parallel)
@@ -329,7 +795,8 @@ fn run_build_with_spans(
             let _file_span = tracing::info_span!("process_file").entered();
 
             let content_type = get_content_type(&file, &config.site.content_dir);
-            let content = load_content(&file)?;
+            let section = get_section(&file, &config.site.content_dir);
+            let content = load_content(&file, &meta_defaults)?;
             let html = convert_content_with_highlighting(
                 &content,
                 &file,
@@ -340,8 +807,7 @@ fn run_build_with_spans(
             )?;
 
             let pattern = config
-                .content
-                .get(&content_type)
+                .section_config(&section)
                 .and_then(|ct| {
                     ct.url_pattern
                         .clone()
@@ -352,35 +818,48 @@ fn run_build_with_spans(
                 })
                 .unwrap_or_else(|| "{stem}".to_string());
 
-            let filename = file.file_name().and_then(|f| f.to_str()).unwrap_or("index");
+            let filename = bundle_dir(&file, &config.site.content_dir)
+                .unwrap_or(&file)
+                .file_name()
+                .and_then(|f| f.to_str())
+                .unwrap_or("index");
             let resolved = resolve_url_pattern(&pattern, filename, &content.meta.date);
             let output_path = build_output_path(
-                &content_type,
+                &section,
                 &resolved,
                 &config.site.output_dir,
                 config.site.clean_urls,
             );
+            let html = insert_toc(&html, content.meta.toc, &config.site.toc);
+            let excerpt = excerpt_html(
+                &content,
+                &html,
+                config.excerpt_config(&section),
+                config.site.allow_dangerous_html,
+            );
+            let (assets, html, excerpt) =
+                localize_bundle(&file, html, excerpt, &output_path, config);
 
             Ok(LoadedContent {
                 path: file,
                 content,
                 html,
+                excerpt,
                 content_type,
+                section,
+                assets,
                 output_path,
             })
         })
         .collect::<Result<Vec<_>, _>>()?;
     drop(_load_span);
 
-    // Filter out draft content unless --include-drafts is set
-    let loaded_contents: Vec<LoadedContent> = if include_drafts {
-        loaded_contents
-    } else {
-        loaded_contents
-            .into_iter()
-            .filter(|lc| !lc.content.meta.draft)
-            .collect()
-    };
+    // Filter out drafts, scheduled and expired content (see `Visibility`)
+    let now = visibility.now();
+    let mut loaded_contents: Vec<LoadedContent> = loaded_contents
+        .into_iter()
+        .filter(|lc| visibility.includes(&lc.content.meta, now))
+        .collect();
 
     info!(
         "content::load {} files in {:.2?}",
@@ -388,64 +867,119 @@ fn run_build_with_spans(
         start.elapsed()
     );
 
+    let _links_span = tracing::info_span!("resolve_links").entered();
+    resolve_internal_links(config, &mut loaded_contents, &section_index_files)?;
+    drop(_links_span);
+
+    // Check the output layout, then copy static files
+    let _outputs_span = tracing::info_span!("check_outputs").entered();
+    let section_indexes = load_section_indexes(config, &section_index_files)?;
+    let tree = SectionTree::collect(config, &loaded_contents, section_indexes);
+    let taxonomies = collect_taxonomies(config, &loaded_contents);
+    check_output_collisions(config, &loaded_contents, &tree, &taxonomies)?;
+    drop(_outputs_span);
+
+    let _static_span = tracing::info_span!("copy_static_files").entered();
+    let manifest = hash_assets(config)?;
+    let env = &create_environment_with_manifest(&config.site.template_dir, manifest.as_ref());
+    copy_static_files(config)?;
+    drop(_static_span);
+
     // 3. Write individual pages
     let _render_span = tracing::info_span!("render_pages", count = loaded_contents.len()).entered();
+    let sections = build_sections(&loaded_contents, &tree, config);
     for loaded in &loaded_contents {
         let _page_span = tracing::info_span!("render_page").entered();
-        let content_template = get_content_type_template(config, &loaded.content_type);
+        copy_page_assets(loaded, config)?;
+        let content_template = get_page_template(config, &loaded.content.meta, &loaded.section);
+        ensure_template_exists(env, config, &loaded.path, &content_template)?;
         let rendered = render_html(
             env,
             &loaded.html,
             &loaded.content.meta,
             config,
             &content_template,
+            &sections[loaded.section.as_str()].nav(&loaded.path),
         )?;
         write_output_file(&loaded.output_path, &rendered)?;
     }
     drop(_render_span);
 
-    // 4. Render content type indexes
+    // 4. Render section indexes
     let _index_span = tracing::info_span!("render_indexes").entered();
-    for (content_type, v) in config.content.iter() {
-        let _ct_span =
-            tracing::info_span!("render_content_type_index", content_type = %content_type)
-                .entered();
-
-        let filtered: Vec<_> = loaded_contents
-            .iter()
-            .filter(|lc| &lc.content_type == content_type)
-            .collect();
+    for path in tree.paths() {
+        let _section_span = tracing::info_span!("render_section_index", section = %path).entered();
+        let v = config
+            .section_config(path)
+            .expect("sections in the tree are configured");
 
-        let index_rendered = render_index_from_loaded(
+        write_index(
             env,
             config,
-            &v.index_template,
-            filtered,
-            loaded_contents.iter().collect(),
+            &IndexSpec {
+                template: &v.index_template,
+                base: path,
+                paginate_by: v.paginate_by,
+                order: v.listing_order(),
+                section: Value::from_serialize(tree.info(path)),
+            },
+            tree.pages(path),
+            &loaded_contents,
         )?;
-
-        let output_path = PathBuf::from(&config.site.output_dir)
-            .join(content_type)
-            .join("index.html");
-
-        write_output_file(&output_path, &index_rendered)?;
     }
     drop(_index_span);
 
     // 5. Render site index
     let _site_index_span = tracing::info_span!("render_site_index").entered();
-    let site_index_rendered = render_index_from_loaded(
+    write_index(
         env,
         config,
-        &config.site.site_index_template,
-        loaded_contents.iter().collect(),
+        &IndexSpec {
+            template: &config.site.site_index_template,
+            base: "",
+            paginate_by: config.site.paginate_by,
+            order: ListingOrder::default(),
+            section: Value::from_serialize(tree.root_info(&config.site.title)),
+        },
         loaded_contents.iter().collect(),
+        &loaded_contents,
     )?;
-    let site_index_path = PathBuf::from(&config.site.output_dir).join("index.html");
-    write_output_file(&site_index_path, &site_index_rendered)?;
     drop(_site_index_span);
 
-    // 6. Generate sitemap.xml
+    // 6. Render taxonomy pages
+    let _taxonomy_span = tracing::info_span!("render_taxonomies").entered();
+    for taxonomy in &taxonomies {
+        let tc = &config.taxonomies[&taxonomy.name];
+        let index_rendered = render_taxonomy_index(
+            env,
+            config,
+            &tc.index_template,
+            taxonomy,
+            loaded_contents.iter().collect(),
+        )?;
+        write_output_file(
+            &index_output_path(&config.site.output_dir, &taxonomy.name),
+            &index_rendered,
+        )?;
+
+        for term in &taxonomy.terms {
+            let term_rendered = render_taxonomy_term(
+                env,
+                config,
+                &tc.term_template,
+                taxonomy,
+                term,
+                loaded_contents.iter().collect(),
+            )?;
+            write_output_file(
+                &term_output_path(&config.site.output_dir, &taxonomy.name, &term.item.slug),
+                &term_rendered,
+            )?;
+        }
+    }
+    drop(_taxonomy_span);
+
+    // 7. Generate sitemap.xml
     if config.site.sitemap_enabled {
         let _sitemap_span = tracing::info_span!("generate_sitemap").entered();
         let sitemap_xml = sitemap::generate_sitemap(config, &loaded_contents);
@@ -455,7 +989,7 @@ fn run_build_with_spans(
         )?;
     }
 
-    // 7. Generate RSS feed
+    // 8. Generate RSS feed
     if config.site.rss_enabled {
         let _rss_span = tracing::info_span!("generate_rss").entered();
         let rss_xml = rss::generate_rss(config, &loaded_contents);
@@ -465,7 +999,7 @@ fn run_build_with_spans(
         )?;
     }
 
-    // 8. Generate redirect HTML files
+    // 9. Generate redirect HTML files
     if !config.redirects.is_empty() {
         let _redirect_span =
             tracing::info_span!("generate_redirects", count = config.redirects.len()).entered();
@@ -478,6 +1012,24 @@ fn run_build_with_spans(
         }
     }
 
+    // 10. Render custom 404 page
+    if let Some(not_found_template) = &config.site.not_found_template {
+        let _not_found_span = tracing::info_span!("render_not_found").entered();
+        let not_found_rendered = render_index_from_loaded(
+            env,
+            config,
+            not_found_template,
+            vec![],
+            loaded_contents.iter().collect(),
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        )?;
+        write_output_file(
+            &PathBuf::from(&config.site.output_dir).join("404.html"),
+            &not_found_rendered,
+        )?;
+    }
+
     info!("build::complete ✓");
     Ok(())
 }
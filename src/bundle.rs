@@ -0,0 +1,206 @@
+// src/bundle.rs
+
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::utils::rewrite_link_attributes;
+
+/// File that turns a directory into a page bundle (e.g., `content/blog/my-trip/index.md`)
+pub(crate) const BUNDLE_INDEX_FILE: &str = "index.md";
+
+/// Returns the bundle directory when `file` is the index of a page bundle.
+///
+/// A bundle is a directory below `content_dir` holding an [`BUNDLE_INDEX_FILE`]
+/// and the files it refers to. The page takes its name from the directory and
+/// lives in the directory's parent section, so `content/blog/my-trip/index.md`
+/// renders like `content/blog/my-trip.md`. An `index.md` directly in
+/// `content_dir` is an ordinary page.
+///
+/// # Examples
+/// ```
+/// use std::path::Path;
+/// use your_crate::bundle_dir;
+///
+/// let file = Path::new("content/blog/my-trip/index.md");
+/// assert_eq!(bundle_dir(file, "content"), Some(Path::new("content/blog/my-trip")));
+/// assert_eq!(bundle_dir(Path::new("content/index.md"), "content"), None);
+/// ```
+pub(crate) fn bundle_dir<'a>(file: &'a Path, content_dir: &str) -> Option<&'a Path> {
+    if file.file_name()? != BUNDLE_INDEX_FILE {
+        return None;
+    }
+    file.parent().filter(|dir| {
+        dir.strip_prefix(content_dir)
+            .is_ok_and(|rel| !rel.as_os_str().is_empty())
+    })
+}
+
+/// Lists the files colocated with a bundle's index, relative to the bundle
+/// directory with `/` separators (e.g., "photo1.jpg", "maps/day1.png").
+///
+/// Markdown files and `.meta.toml` sidecars are not assets. Returns an empty list
+/// when `file` is not a bundle index.
+pub(crate) fn bundle_assets(file: &Path, content_dir: &str) -> Vec<String> {
+    let Some(dir) = bundle_dir(file, content_dir) else {
+        return vec![];
+    };
+
+    let mut assets: Vec<String> = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            !(name.ends_with(".md") || name.ends_with(".markdown") || name.ends_with(".meta.toml"))
+        })
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(dir).ok()?;
+            let parts: Vec<_> = rel
+                .components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .collect();
+            Some(parts.join("/"))
+        })
+        .collect();
+    assets.sort();
+    assets
+}
+
+/// Output directory of a bundle's assets: the directory of the page's URL.
+///
+/// With `clean_urls` the page is `<dir>/index.html` and the assets sit next to it;
+/// otherwise the page is `<dir>.html` and the assets go into `<dir>/`.
+pub(crate) fn asset_dir(output_path: &Path, clean_urls: bool) -> PathBuf {
+    if clean_urls {
+        output_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    } else {
+        output_path.with_extension("")
+    }
+}
+
+/// Site-relative URL of an asset directory, with a trailing slash (e.g., "/blog/my-trip/").
+pub(crate) fn asset_url(asset_dir: &Path, output_dir: &str) -> String {
+    let rel = asset_dir.strip_prefix(output_dir).unwrap_or(asset_dir);
+    let parts: Vec<_> = rel
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+    if parts.is_empty() {
+        "/".to_string()
+    } else {
+        format!("/{}/", parts.join("/"))
+    }
+}
+
+/// Rewrites `src` and `href` attributes that point at a bundle asset by relative
+/// path (e.g., `photo1.jpg` or `./photo1.jpg`) to the asset's site-relative URL.
+///
+/// Absolute links keep the HTML working wherever it ends up: on the page itself
+/// under either URL style, in index listings and in the RSS feed. Links to
+/// anything other than a listed asset are left untouched.
+pub(crate) fn rewrite_asset_links(html: &str, assets: &[String], base_url: &str) -> String {
+    if assets.is_empty() {
+        return html.to_string();
+    }
+
+    rewrite_link_attributes(html, |target| {
+        let (path, suffix) = target.split_at(target.find(['?', '#']).unwrap_or(target.len()));
+        let path = path.strip_prefix("./").unwrap_or(path);
+        assets
+            .iter()
+            .any(|asset| asset == path)
+            .then(|| format!("{}{}{}", base_url, path, suffix))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_bundle_dir() {
+        let file = Path::new("content/blog/my-trip/index.md");
+        assert_eq!(
+            bundle_dir(file, "content"),
+            Some(Path::new("content/blog/my-trip"))
+        );
+        assert_eq!(
+            bundle_dir(Path::new("content/about/index.md"), "content"),
+            Some(Path::new("content/about"))
+        );
+
+        // Root index and ordinary files are not bundles
+        assert_eq!(bundle_dir(Path::new("content/index.md"), "content"), None);
+        assert_eq!(
+            bundle_dir(Path::new("content/blog/post.md"), "content"),
+            None
+        );
+        assert_eq!(
+            bundle_dir(Path::new("content/blog/_index.md"), "content"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bundle_assets_skips_markdown_and_sidecars() {
+        let temp_dir = tempdir().unwrap();
+        let content_dir = temp_dir.path().join("content");
+        let bundle = content_dir.join("blog/my-trip");
+        fs::create_dir_all(bundle.join("maps")).unwrap();
+        for name in [
+            "index.md",
+            "index.meta.toml",
+            "notes.md",
+            "photo1.jpg",
+            "data.csv",
+        ] {
+            fs::write(bundle.join(name), "").unwrap();
+        }
+        fs::write(bundle.join("maps/day1.png"), "").unwrap();
+
+        let content_dir = content_dir.to_str().unwrap();
+        assert_eq!(
+            bundle_assets(&bundle.join("index.md"), content_dir),
+            vec!["data.csv", "maps/day1.png", "photo1.jpg"]
+        );
+        assert!(bundle_assets(&bundle.join("notes.md"), content_dir).is_empty());
+    }
+
+    #[test]
+    fn test_asset_dir_and_url() {
+        let clean = asset_dir(Path::new("dist/blog/my-trip/index.html"), true);
+        assert_eq!(clean, PathBuf::from("dist/blog/my-trip"));
+        let plain = asset_dir(Path::new("dist/blog/my-trip.html"), false);
+        assert_eq!(plain, PathBuf::from("dist/blog/my-trip"));
+
+        assert_eq!(asset_url(&plain, "dist"), "/blog/my-trip/");
+        assert_eq!(asset_url(Path::new("dist"), "dist"), "/");
+    }
+
+    #[test]
+    fn test_rewrite_asset_links() {
+        let assets = vec!["data.csv".to_string(), "maps/day1.png".to_string()];
+        let html = concat!(
+            r#"<p><img src="maps/day1.png" alt="Day 1" /> "#,
+            r#"<a href="./data.csv#row-2">data</a> "#,
+            r#"<a href="other.csv">other</a> "#,
+            r#"<a href="/static/data.csv">static</a></p>"#,
+        );
+
+        assert_eq!(
+            rewrite_asset_links(html, &assets, "/blog/my-trip/"),
+            concat!(
+                r#"<p><img src="/blog/my-trip/maps/day1.png" alt="Day 1" /> "#,
+                r#"<a href="/blog/my-trip/data.csv#row-2">data</a> "#,
+                r#"<a href="other.csv">other</a> "#,
+                r#"<a href="/static/data.csv">static</a></p>"#,
+            )
+        );
+        assert_eq!(rewrite_asset_links(html, &[], "/blog/my-trip/"), html);
+    }
+}
@@ -0,0 +1,385 @@
+// src/cache.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{debug, info, warn};
+
+use crate::asset_hash::AssetManifest;
+use crate::config::Config;
+
+/// Directory (relative to the working directory) holding the persistent build cache
+pub(crate) const CACHE_DIR: &str = ".marie-cache";
+
+/// Bumped whenever the on-disk layout or hashed inputs change
+const CACHE_VERSION: u32 = 3;
+
+const INDEX_FILE: &str = "index.toml";
+const HTML_DIR: &str = "html";
+
+/// On-disk index: one entry per markdown source path
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheIndex {
+    version: u32,
+    #[serde(default)]
+    pages: BTreeMap<String, PageEntry>,
+}
+
+/// Hashes recorded for a page the last time it was written.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct PageEntry {
+    /// Hash of the markdown body and conversion settings; names the cached HTML fragment
+    pub(crate) html: String,
+    /// Hash of everything `render_html` consumes for this page
+    pub(crate) render: String,
+}
+
+/// Build cache keyed by blake3 hashes of page inputs.
+///
+/// Converted markdown is stored under `html/<hash>.html` and reused while the markdown
+/// and highlighting settings are unchanged. A page whose render inputs (metadata, the
+/// templates it depends on, config file, asset manifest) are unchanged is not rendered
+/// or written again.
+///
+/// The cache is an optimisation only: unreadable or outdated caches are ignored and
+/// failures to write it are logged, never returned.
+pub(crate) struct BuildCache {
+    dir: PathBuf,
+    previous: BTreeMap<String, PageEntry>,
+    current: Mutex<BTreeMap<String, PageEntry>>,
+}
+
+impl BuildCache {
+    /// Loads the cache index from `dir`, starting empty if it is missing or outdated.
+    pub(crate) fn load(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        let index_path = dir.join(INDEX_FILE);
+
+        let previous = match fs::read_to_string(&index_path) {
+            Ok(text) => match toml::from_str::<CacheIndex>(&text) {
+                Ok(index) if index.version == CACHE_VERSION => index.pages,
+                Ok(_) => {
+                    info!("cache::reset version changed");
+                    BTreeMap::new()
+                }
+                Err(e) => {
+                    warn!(
+                        "cache::reset unreadable index {}: {}",
+                        index_path.display(),
+                        e
+                    );
+                    BTreeMap::new()
+                }
+            },
+            Err(_) => BTreeMap::new(),
+        };
+        debug!("cache::load {} entries ← {}", previous.len(), dir.display());
+
+        Self {
+            dir,
+            previous,
+            current: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the converted HTML stored for `html_hash`, if this source was cached with it.
+    pub(crate) fn cached_html(&self, source: &Path, html_hash: &str) -> Option<String> {
+        let entry = self.previous.get(&cache_key(source))?;
+        if entry.html != html_hash {
+            return None;
+        }
+        fs::read_to_string(self.html_path(html_hash)).ok()
+    }
+
+    /// Stores converted HTML so later builds can skip markdown conversion.
+    pub(crate) fn store_html(&self, html_hash: &str, html: &str) {
+        let path = self.html_path(html_hash);
+        if path.exists() {
+            return;
+        }
+        let result =
+            fs::create_dir_all(self.dir.join(HTML_DIR)).and_then(|_| fs::write(&path, html));
+        if let Err(e) = result {
+            warn!("cache::write {}: {}", path.display(), e);
+        }
+    }
+
+    /// Whether `output_path` was produced from exactly these render inputs and still exists.
+    pub(crate) fn is_fresh(&self, source: &Path, entry: &PageEntry, output_path: &Path) -> bool {
+        self.previous.get(&cache_key(source)) == Some(entry) && output_path.is_file()
+    }
+
+    /// Records the entry for a page written (or confirmed fresh) in this build.
+    pub(crate) fn record(&self, source: &Path, entry: PageEntry) {
+        self.current
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(cache_key(source), entry);
+    }
+
+    /// Writes the index for this build and removes HTML fragments no page refers to.
+    pub(crate) fn save(&self) {
+        let pages = std::mem::take(&mut *self.current.lock().unwrap_or_else(|e| e.into_inner()));
+        let referenced: HashSet<String> =
+            pages.values().map(|e| format!("{}.html", e.html)).collect();
+        let count = pages.len();
+
+        let index = CacheIndex {
+            version: CACHE_VERSION,
+            pages,
+        };
+        let result = toml::to_string(&index)
+            .map_err(std::io::Error::other)
+            .and_then(|text| {
+                fs::create_dir_all(&self.dir)?;
+                fs::write(self.dir.join(INDEX_FILE), text)
+            });
+        if let Err(e) = result {
+            warn!("cache::write {}: {}", self.dir.display(), e);
+            return;
+        }
+
+        if let Ok(entries) = fs::read_dir(self.dir.join(HTML_DIR)) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !referenced.contains(&name) {
+                    debug!("cache::prune {}", name);
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+        }
+
+        debug!("cache::save {} entries → {}", count, self.dir.display());
+    }
+
+    fn html_path(&self, html_hash: &str) -> PathBuf {
+        self.dir.join(HTML_DIR).join(format!("{}.html", html_hash))
+    }
+}
+
+fn cache_key(source: &Path) -> String {
+    source.to_string_lossy().to_string()
+}
+
+/// Hash of a markdown body plus every setting that affects its HTML conversion.
+pub(crate) fn hash_markdown(markdown: &str, config: &Config) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.update(
+        format!(
+            "{}|{}|{}|{}",
+            config.site.syntax_highlighting_enabled,
+            config.site.syntax_highlighting_theme,
+            config.site.allow_dangerous_html,
+            config.site.header_uri_fragment,
+        )
+        .as_bytes(),
+    );
+    hasher.update(markdown.as_bytes());
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Hash of the inputs shared by every page render: the config file and the asset
+/// manifest. Computed once per build.
+pub(crate) fn hash_render_inputs(config_file: &str, manifest: Option<&AssetManifest>) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.update(&fs::read(config_file).unwrap_or_default());
+
+    if let Some(manifest) = manifest {
+        let sorted: BTreeMap<_, _> = manifest.iter().collect();
+        for (original, hashed) in sorted {
+            hasher.update(original.as_bytes());
+            hasher.update(hashed.as_bytes());
+        }
+    }
+
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Hash of everything a single page render depends on.
+///
+/// `html` is the page's converted markdown after links to bundle assets and other
+/// pages were rewritten, so moving a linked page re-renders the page. `template_hash` covers the page template and every template it extends, includes
+/// or imports (see [`crate::template_deps::TemplateGraph::hash`]). `section_hash`
+/// covers the other pages of its content type (see [`hash_section`]).
+pub(crate) fn hash_page_render(
+    html: &str,
+    source: &Path,
+    template_hash: &str,
+    section_hash: &str,
+    render_inputs: &str,
+) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(html.as_bytes());
+    hasher.update(template_hash.as_bytes());
+    hasher.update(section_hash.as_bytes());
+    hasher.update(render_inputs.as_bytes());
+    hash_metadata(&mut hasher, source);
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Hash of every page in a content type, given as `(source, html_hash)` pairs.
+///
+/// Pages see their siblings (and prev/next items) when rendered, so a change to
+/// any page of a content type re-renders the whole content type.
+pub(crate) fn hash_section<'a>(pages: impl IntoIterator<Item = (&'a Path, &'a str)>) -> String {
+    let mut pages: Vec<_> = pages.into_iter().collect();
+    pages.sort();
+
+    let mut hasher = blake3::Hasher::new();
+    for (source, html_hash) in pages {
+        hasher.update(source.to_string_lossy().as_bytes());
+        hasher.update(html_hash.as_bytes());
+        hash_metadata(&mut hasher, source);
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+/// Metadata lives in front matter or in the sidecar file next to the markdown
+fn hash_metadata(hasher: &mut blake3::Hasher, source: &Path) {
+    hasher.update(&fs::read(source).unwrap_or_default());
+    hasher.update(&fs::read(source.with_extension("meta.toml")).unwrap_or_default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn entry(html: &str, render: &str) -> PageEntry {
+        PageEntry {
+            html: html.to_string(),
+            render: render.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_empty_cache_has_no_entries() {
+        let temp_dir = tempdir().unwrap();
+        let cache = BuildCache::load(temp_dir.path().join("cache"));
+
+        assert!(cache.cached_html(Path::new("a.md"), "abc").is_none());
+        assert!(!cache.is_fresh(Path::new("a.md"), &entry("abc", "def"), Path::new("a.md")));
+    }
+
+    #[test]
+    fn test_html_round_trip_across_builds() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path().join("cache");
+        let source = Path::new("content/blog/post.md");
+
+        let cache = BuildCache::load(&dir);
+        cache.store_html("abc", "<p>Hi</p>");
+        cache.record(source, entry("abc", "def"));
+        cache.save();
+
+        let cache = BuildCache::load(&dir);
+        assert_eq!(cache.cached_html(source, "abc").unwrap(), "<p>Hi</p>");
+        assert!(cache.cached_html(source, "changed").is_none());
+        assert!(cache.cached_html(Path::new("other.md"), "abc").is_none());
+    }
+
+    #[test]
+    fn test_is_fresh_requires_matching_entry_and_output() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path().join("cache");
+        let source = Path::new("post.md");
+        let output = temp_dir.path().join("post.html");
+
+        let cache = BuildCache::load(&dir);
+        cache.record(source, entry("abc", "def"));
+        cache.save();
+
+        let cache = BuildCache::load(&dir);
+        assert!(!cache.is_fresh(source, &entry("abc", "def"), &output));
+
+        fs::write(&output, "<html></html>").unwrap();
+        assert!(cache.is_fresh(source, &entry("abc", "def"), &output));
+        assert!(!cache.is_fresh(source, &entry("abc", "other"), &output));
+    }
+
+    #[test]
+    fn test_save_prunes_unreferenced_html() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path().join("cache");
+
+        let cache = BuildCache::load(&dir);
+        cache.store_html("old", "<p>Old</p>");
+        cache.store_html("new", "<p>New</p>");
+        cache.record(Path::new("post.md"), entry("new", "r"));
+        cache.save();
+
+        assert!(!dir.join("html/old.html").exists());
+        assert!(dir.join("html/new.html").exists());
+    }
+
+    #[test]
+    fn test_outdated_version_is_ignored() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path().join("cache");
+        fs::create_dir_all(dir.join("html")).unwrap();
+        fs::write(dir.join("html/abc.html"), "<p>Hi</p>").unwrap();
+        fs::write(
+            dir.join(INDEX_FILE),
+            "version = 0\n[pages.\"post.md\"]\nhtml = \"abc\"\nrender = \"def\"\n",
+        )
+        .unwrap();
+
+        let cache = BuildCache::load(&dir);
+        assert!(cache.cached_html(Path::new("post.md"), "abc").is_none());
+    }
+
+    #[test]
+    fn test_corrupt_index_is_ignored() {
+        let temp_dir = tempdir().unwrap();
+        let dir = temp_dir.path().join("cache");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(INDEX_FILE), "not [valid toml").unwrap();
+
+        let cache = BuildCache::load(&dir);
+        assert!(cache.cached_html(Path::new("post.md"), "abc").is_none());
+    }
+
+    #[test]
+    fn test_hash_render_inputs_tracks_config_and_manifest() {
+        let temp_dir = tempdir().unwrap();
+        let config_file = temp_dir.path().join("site.toml");
+        fs::write(&config_file, "[site]").unwrap();
+
+        let config_file = config_file.to_str().unwrap();
+        let before = hash_render_inputs(config_file, None);
+        assert_eq!(before, hash_render_inputs(config_file, None));
+
+        fs::write(config_file, "[site]\ntitle = \"New\"").unwrap();
+        let after = hash_render_inputs(config_file, None);
+        assert_ne!(before, after);
+
+        let mut manifest = AssetManifest::new();
+        manifest.insert("style.css".to_string(), "style.abc.css".to_string());
+        assert_ne!(after, hash_render_inputs(config_file, Some(&manifest)));
+    }
+
+    #[test]
+    fn test_hash_section_tracks_every_page() {
+        let temp_dir = tempdir().unwrap();
+        let first = temp_dir.path().join("first.md");
+        let second = temp_dir.path().join("second.md");
+        fs::write(&first, "# First").unwrap();
+        fs::write(&second, "# Second").unwrap();
+
+        let before = hash_section([(first.as_path(), "a"), (second.as_path(), "b")]);
+        // Order of pages does not matter
+        assert_eq!(
+            before,
+            hash_section([(second.as_path(), "b"), (first.as_path(), "a")])
+        );
+
+        fs::write(second.with_extension("meta.toml"), "title = \"Second\"").unwrap();
+        let after = hash_section([(first.as_path(), "a"), (second.as_path(), "b")]);
+        assert_ne!(before, after);
+        assert_ne!(after, hash_section([(first.as_path(), "a")]));
+    }
+}
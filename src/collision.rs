@@ -0,0 +1,306 @@
+// src/collision.rs
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use crate::LoadedContent;
+use crate::bundle::{asset_dir, bundle_dir};
+use crate::config::Config;
+use crate::output::static_file_copies;
+use crate::pagination::{page_output_path, paginate};
+use crate::redirect::get_redirect_output_path;
+use crate::section::SectionTree;
+use crate::taxonomy::{Taxonomy, index_output_path, term_output_path};
+
+/// Output files of a build and what writes each of them.
+///
+/// Date prefixes are stripped from URLs and patterns like `{stem}` drop parts of
+/// the path, so two sources can map to the same file. Collecting every output up
+/// front turns a silent overwrite into an error before anything is written.
+#[derive(Debug, Default)]
+pub(crate) struct OutputClaims {
+    claims: BTreeMap<PathBuf, Vec<String>>,
+}
+
+/// An output file claimed by more than one source.
+#[derive(Debug, PartialEq)]
+pub(crate) struct OutputCollision {
+    pub(crate) output: PathBuf,
+    /// Sorted descriptions of the sources (e.g., "content/blog/2024-01-01-intro.md")
+    pub(crate) sources: Vec<String>,
+}
+
+impl OutputClaims {
+    /// Records that `source` writes `output`.
+    pub(crate) fn claim(&mut self, output: PathBuf, source: impl Into<String>) {
+        self.claims.entry(output).or_default().push(source.into());
+    }
+
+    /// Output files claimed more than once, sorted by path.
+    pub(crate) fn collisions(self) -> Vec<OutputCollision> {
+        self.claims
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(|(output, mut sources)| {
+                sources.sort();
+                OutputCollision { output, sources }
+            })
+            .collect()
+    }
+}
+
+/// Claims every file the build writes: pages and their bundle assets, section and
+/// site indexes (with their pagination), taxonomy pages, redirects, static and root
+/// static files and the generated sitemap, feed and 404 page.
+pub(crate) fn claim_outputs(
+    config: &Config,
+    loaded_contents: &[LoadedContent],
+    tree: &SectionTree,
+    taxonomies: &[Taxonomy],
+) -> OutputClaims {
+    let output_dir = &config.site.output_dir;
+    let clean_urls = config.site.clean_urls;
+    let mut claims = OutputClaims::default();
+
+    for loaded in loaded_contents {
+        claims.claim(
+            loaded.output_path.clone(),
+            loaded.path.display().to_string(),
+        );
+
+        if let Some(dir) = bundle_dir(&loaded.path, &config.site.content_dir) {
+            let dest = asset_dir(&loaded.output_path, clean_urls);
+            for asset in &loaded.assets {
+                claims.claim(dest.join(asset), dir.join(asset).display().to_string());
+            }
+        }
+    }
+
+    let mut claim_index =
+        |base: &str, paginate_by: Option<NonZeroUsize>, count: usize, source: String| {
+            let pages = paginate_by.map_or(1, |per_page| {
+                paginate(base, count, per_page, clean_urls).len()
+            });
+            for page in 1..=pages {
+                claims.claim(
+                    page_output_path(output_dir, base, page, clean_urls),
+                    source.clone(),
+                );
+            }
+        };
+    for path in tree.paths() {
+        let paginate_by = config.section_config(path).and_then(|ct| ct.paginate_by);
+        let source = format!("index of section {:?}", path);
+        claim_index(path, paginate_by, tree.pages(path).len(), source);
+    }
+    claim_index(
+        "",
+        config.site.paginate_by,
+        loaded_contents.len(),
+        "site index".to_string(),
+    );
+
+    for taxonomy in taxonomies {
+        claims.claim(
+            index_output_path(output_dir, &taxonomy.name),
+            format!("taxonomy {:?}", taxonomy.name),
+        );
+        for term in &taxonomy.terms {
+            claims.claim(
+                term_output_path(output_dir, &taxonomy.name, &term.item.slug),
+                format!("term {:?} of taxonomy {:?}", term.item.name, taxonomy.name),
+            );
+        }
+    }
+
+    for from_path in config.redirects.keys() {
+        claims.claim(
+            get_redirect_output_path(from_path, output_dir),
+            format!("redirect from {}", from_path),
+        );
+    }
+
+    for (source, dest) in static_file_copies(config) {
+        claims.claim(dest, source.display().to_string());
+    }
+
+    let output_root = PathBuf::from(output_dir);
+    for filename in config.site.root_static.keys() {
+        claims.claim(
+            output_root.join(filename),
+            format!("root_static {:?}", filename),
+        );
+    }
+
+    if config.site.sitemap_enabled {
+        claims.claim(output_root.join("sitemap.xml"), "sitemap");
+    }
+    if config.site.rss_enabled {
+        claims.claim(output_root.join("feed.xml"), "RSS feed");
+    }
+    if config.site.not_found_template.is_some() {
+        claims.claim(output_root.join("404.html"), "not_found_template");
+    }
+
+    claims
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Content, ContentMeta};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+    use time::macros::datetime;
+
+    fn create_test_config() -> Config {
+        Config::from_str(
+            r#"
+[site]
+title = "Test Site"
+tagline = "A test tagline"
+domain = "example.com"
+author = "Test Author"
+output_dir = "output"
+content_dir = "content"
+template_dir = "templates"
+static_dir = "static"
+site_index_template = "index.html"
+clean_urls = true
+
+[site.root_static]
+"robots.txt" = "seo/robots.txt"
+
+[content.blog]
+index_template = "blog_index.html"
+content_template = "post.html"
+
+[redirects]
+"/old-blog/" = "/blog/"
+"/blog/" = "/articles/"
+"#,
+        )
+        .unwrap()
+    }
+
+    fn create_test_loaded_content(source: &str, output: &str) -> LoadedContent {
+        LoadedContent {
+            path: PathBuf::from(source),
+            content: Content {
+                meta: ContentMeta {
+                    title: "Test".to_string(),
+                    date: datetime!(2024-01-15 10:00:00 UTC),
+                    updated: None,
+                    author: "Test Author".to_string(),
+                    tags: vec![],
+                    template: None,
+                    cover: None,
+                    extra: HashMap::new(),
+                    extra_js: vec![],
+                    draft: false,
+                    publish_date: None,
+                    expiry_date: None,
+                    weight: None,
+                    summary: None,
+                    toc: false,
+                },
+                data: String::new(),
+            },
+            html: String::new(),
+            excerpt: String::new(),
+            content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
+            output_path: PathBuf::from(output),
+        }
+    }
+
+    #[test]
+    fn test_claims_without_collisions() {
+        let mut claims = OutputClaims::default();
+        claims.claim(PathBuf::from("output/a.html"), "content/a.md");
+        claims.claim(PathBuf::from("output/b.html"), "content/b.md");
+        assert!(claims.collisions().is_empty());
+    }
+
+    #[test]
+    fn test_claim_outputs_reports_every_colliding_source() {
+        let config = create_test_config();
+        let contents = vec![
+            create_test_loaded_content(
+                "content/blog/2025-01-01-intro.md",
+                "output/blog/intro/index.html",
+            ),
+            create_test_loaded_content(
+                "content/blog/2024-01-01-intro.md",
+                "output/blog/intro/index.html",
+            ),
+            create_test_loaded_content("content/robots.txt.md", "output/robots.txt"),
+        ];
+        let tree = SectionTree::collect(&config, &contents, HashMap::new());
+
+        let collisions = claim_outputs(&config, &contents, &tree, &[]).collisions();
+        assert_eq!(
+            collisions,
+            vec![
+                OutputCollision {
+                    output: PathBuf::from("output/blog/index.html"),
+                    sources: vec![
+                        "index of section \"blog\"".to_string(),
+                        "redirect from /blog/".to_string(),
+                    ],
+                },
+                OutputCollision {
+                    output: PathBuf::from("output/blog/intro/index.html"),
+                    sources: vec![
+                        "content/blog/2024-01-01-intro.md".to_string(),
+                        "content/blog/2025-01-01-intro.md".to_string(),
+                    ],
+                },
+                OutputCollision {
+                    output: PathBuf::from("output/robots.txt"),
+                    sources: vec![
+                        "content/robots.txt.md".to_string(),
+                        "root_static \"robots.txt\"".to_string(),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_claim_outputs_includes_static_and_bundle_assets() {
+        let static_dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(static_dir.path().join("css")).unwrap();
+        fs::write(static_dir.path().join("css/site.css"), "body {}").unwrap();
+
+        let mut config = create_test_config();
+        config.site.static_dir = static_dir.path().display().to_string();
+
+        let mut trip =
+            create_test_loaded_content("content/blog/trip/index.md", "output/blog/trip/index.html");
+        trip.assets = vec!["photo.jpg".to_string()];
+        let contents = vec![
+            trip,
+            create_test_loaded_content("content/blog/trip-photo.md", "output/blog/trip/photo.jpg"),
+            create_test_loaded_content("content/static/css/site.md", "output/static/css/site.css"),
+        ];
+        let tree = SectionTree::collect(&config, &contents, HashMap::new());
+
+        let collisions = claim_outputs(&config, &contents, &tree, &[]).collisions();
+        let outputs: Vec<_> = collisions.iter().map(|c| c.output.clone()).collect();
+        assert!(outputs.contains(&PathBuf::from("output/blog/trip/photo.jpg")));
+        assert!(outputs.contains(&PathBuf::from("output/static/css/site.css")));
+
+        let bundle = collisions
+            .iter()
+            .find(|c| c.output == Path::new("output/blog/trip/photo.jpg"))
+            .unwrap();
+        assert_eq!(
+            bundle.sources,
+            vec!["content/blog/trip-photo.md", "content/blog/trip/photo.jpg"]
+        );
+    }
+}
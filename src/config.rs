@@ -1,29 +1,40 @@
 // src/config.rs
 
-use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fs};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::{fs, num::NonZeroUsize};
 use thiserror::Error;
+use time::UtcOffset;
+use time::macros::format_description;
 use tracing::debug;
 
 use crate::syntax::DEFAULT_THEME;
+use crate::utils::serialize_toml_map;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub(crate) struct Config {
     /// Site
     pub site: SiteConfig,
 
-    /// Content type configurations (e.g., posts, pages, projects)
-    #[serde(default)]
+    /// Content type configurations (e.g., posts, pages, projects), also keyed by
+    /// nested section path (e.g., "docs/guides") to override a parent's settings
+    #[serde(default, deserialize_with = "deserialize_sections")]
     pub content: HashMap<String, ContentTypeConfig>,
 
-    /// Custom variables accessible in templates
-    #[serde(default)]
-    pub dynamic: HashMap<String, String>,
+    /// Custom variables accessible in templates (any TOML value)
+    #[serde(default, serialize_with = "serialize_toml_map")]
+    pub dynamic: HashMap<String, toml::Value>,
 
     /// URL redirect mappings: old path -> new path
     /// Generates HTML redirect files at old paths pointing to new paths
     #[serde(default)]
     pub redirects: HashMap<String, String>,
+
+    /// Taxonomy configurations keyed by name (e.g., tags)
+    /// Generates /<name>/ listing all terms and /<name>/<term>/ for each term
+    #[serde(default)]
+    pub taxonomies: HashMap<String, TaxonomyConfig>,
 }
 
 impl Config {
@@ -34,9 +45,35 @@ impl Config {
         Self::from_str(&content)
     }
 
-    // Helper for tests - parses TOML from string
+    /// Parses a config from TOML and validates the settings serde cannot check
+    /// (the `timezone` offset and `toc` levels).
     pub(crate) fn from_str(content: &str) -> Result<Self, ConfigError> {
-        Ok(toml::from_str(content)?)
+        let config: Self = toml::from_str(content)?;
+        config.site.utc_offset()?;
+        config.site.toc.validate()?;
+        Ok(config)
+    }
+
+    /// Excerpt settings of a section: its content type's `excerpt` table, or else
+    /// `[site.excerpt]`.
+    pub(crate) fn excerpt_config(&self, section: &str) -> &ExcerptConfig {
+        self.section_config(section)
+            .and_then(|ct| ct.excerpt.as_ref())
+            .unwrap_or(&self.site.excerpt)
+    }
+
+    /// Settings of a section (e.g., "docs/guides"): its own `[content."docs/guides"]`
+    /// table, or else the nearest configured parent section's. Fields a nested
+    /// table leaves out are already filled from its parents (see
+    /// [`deserialize_sections`]).
+    pub(crate) fn section_config(&self, section: &str) -> Option<&ContentTypeConfig> {
+        let mut path = section;
+        loop {
+            if let Some(ct) = self.content.get(path) {
+                return Some(ct);
+            }
+            path = path.rsplit_once('/')?.0;
+        }
     }
 }
 
@@ -94,38 +131,322 @@ pub(crate) struct SiteConfig {
     /// Path to write asset manifest JSON file (requires asset_hashing_enabled)
     #[serde(default)]
     pub asset_manifest_path: Option<String>,
+    /// Template rendered to 404.html (served by `marie-ssg serve` and most static hosts)
+    #[serde(default)]
+    pub not_found_template: Option<String>,
+    /// Timezone for content dates without an offset: "UTC" or a fixed offset like "+02:00"
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Split the site index into pages of this many items (/, /page/2/, ...)
+    #[serde(default)]
+    pub paginate_by: Option<NonZeroUsize>,
+    /// Template for pages placed directly in content_dir (e.g., content/about.md)
+    /// Falls back to "default.html" when unset
+    #[serde(default)]
+    pub page_template: Option<String>,
+    /// What to do when a link to another markdown file matches no rendered page:
+    /// "warn" (default) logs it and leaves the link as is, "error" fails the build
+    #[serde(default)]
+    pub broken_links: BrokenLinks,
+    /// How excerpts for indexes and the RSS feed are picked, see [`ExcerptConfig`]
+    #[serde(default)]
+    pub excerpt: ExcerptConfig,
+    /// Heading levels listed in page tables of contents, see [`TocConfig`]
+    #[serde(default)]
+    pub toc: TocConfig,
+    /// Reading speed behind `reading_time_minutes` (default: 200 words per minute)
+    #[serde(default = "default_words_per_minute")]
+    pub words_per_minute: NonZeroUsize,
+}
+
+/// Handling of links to markdown files that match no rendered page.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum BrokenLinks {
+    #[default]
+    Warn,
+    Error,
+}
+
+impl SiteConfig {
+    /// Offset applied to content dates that do not specify one (UTC when unset).
+    pub(crate) fn utc_offset(&self) -> Result<UtcOffset, ConfigError> {
+        let Some(timezone) = self.timezone.as_deref().map(str::trim) else {
+            return Ok(UtcOffset::UTC);
+        };
+        if timezone.eq_ignore_ascii_case("utc") || timezone.eq_ignore_ascii_case("z") {
+            return Ok(UtcOffset::UTC);
+        }
+        UtcOffset::parse(
+            timezone,
+            format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+        )
+        .map_err(|_| ConfigError::InvalidTimezone(timezone.to_string()))
+    }
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_words_per_minute() -> NonZeroUsize {
+    NonZeroUsize::new(200).expect("200 is non-zero")
+}
+
 fn default_syntax_theme() -> String {
     DEFAULT_THEME.to_string()
 }
 
+/// How a page's excerpt is picked.
+///
+/// In order: the `summary` metadata field, the markdown before a `<!-- more -->`
+/// line, the section under `heading`, then `fallback`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct ExcerptConfig {
+    /// Heading whose section is the excerpt (default: "## Context"); "" to skip
+    #[serde(default = "default_excerpt_heading")]
+    pub heading: String,
+    /// Excerpt of pages without a summary, marker or heading
+    #[serde(default)]
+    pub fallback: ExcerptFallback,
+    /// Word limit of the "words" fallback
+    #[serde(default = "default_excerpt_words")]
+    pub words: usize,
+}
+
+impl Default for ExcerptConfig {
+    fn default() -> Self {
+        Self {
+            heading: default_excerpt_heading(),
+            fallback: ExcerptFallback::default(),
+            words: default_excerpt_words(),
+        }
+    }
+}
+
+/// Automatic excerpt for pages that do not mark one.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ExcerptFallback {
+    /// The first paragraph
+    #[default]
+    Paragraph,
+    /// The first `words` words, cut between words with open tags closed
+    Words,
+    /// No excerpt
+    None,
+}
+
+fn default_excerpt_heading() -> String {
+    "## Context".to_string()
+}
+
+fn default_excerpt_words() -> usize {
+    50
+}
+
+/// Heading levels included in a page's table of contents.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct TocConfig {
+    /// Highest heading level listed (default: 2, skipping the `# Title`)
+    #[serde(default = "default_toc_min_level")]
+    pub min_level: u8,
+    /// Deepest heading level listed (default: 3)
+    #[serde(default = "default_toc_max_level")]
+    pub max_level: u8,
+}
+
+impl Default for TocConfig {
+    fn default() -> Self {
+        Self {
+            min_level: default_toc_min_level(),
+            max_level: default_toc_max_level(),
+        }
+    }
+}
+
+impl TocConfig {
+    /// Checks that `min_level..=max_level` is a non-empty range within 1-6.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if (1..=self.max_level).contains(&self.min_level) && self.max_level <= 6 {
+            Ok(())
+        } else {
+            Err(ConfigError::InvalidTocLevels {
+                min: self.min_level,
+                max: self.max_level,
+            })
+        }
+    }
+}
+
+fn default_toc_min_level() -> u8 {
+    2
+}
+
+fn default_toc_max_level() -> u8 {
+    3
+}
+
 #[derive(Error, Debug)]
 pub(crate) enum ConfigError {
     #[error("IO error reading config file: {0}")]
     Io(#[from] std::io::Error),
     #[error("TOML parsing error in config file: {0}")]
     TomlParse(#[from] toml::de::Error),
+    #[error("Invalid site.timezone {0:?}: expected \"UTC\" or a fixed offset like \"+02:00\"")]
+    InvalidTimezone(String),
+    #[error("Invalid site.toc levels {min}..{max}: expected 1 <= min_level <= max_level <= 6")]
+    InvalidTocLevels { min: u8, max: u8 },
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Serialize)]
 pub(crate) struct ContentTypeConfig {
     pub index_template: String,
     pub content_template: String,
     /// URL pattern template with placeholders: {filename}, {date}, {year}, {month}, {day}
     /// Example: "{date}-{filename}" or "{year}/{month}/{day}/{filename}"
-    #[serde(default)]
     pub url_pattern: Option<String>,
     /// DEPRECATED: Use `url_pattern` instead. Options: "default" or "date"
     /// Maps to: "default" -> "{filename}", "date" -> "{date}-{filename}"
-    #[serde(default)]
     pub output_naming: Option<String>,
-    #[serde(default)]
     pub rss_include: Option<bool>, // Include in RSS feed (default: true if None)
+    /// Split the index into pages of this many items (/<type>/, /<type>/page/2/, ...)
+    pub paginate_by: Option<NonZeroUsize>,
+    /// Field listings are ordered by: "date" (default), "title", "weight" or an [extra] key
+    pub sort_by: Option<String>,
+    /// Listing direction (default: "desc" for date, "asc" for everything else)
+    pub sort_order: Option<SortOrder>,
+    /// Excerpt settings for this content type, replacing `[site.excerpt]`
+    pub excerpt: Option<ExcerptConfig>,
+}
+
+/// A `[content.*]` table as written, before inheritance: every field is optional
+/// so nested sections only state what they change.
+#[derive(Debug, Deserialize)]
+struct SectionTable {
+    index_template: Option<String>,
+    content_template: Option<String>,
+    url_pattern: Option<String>,
+    output_naming: Option<String>,
+    rss_include: Option<bool>,
+    paginate_by: Option<NonZeroUsize>,
+    sort_by: Option<String>,
+    sort_order: Option<SortOrder>,
+    excerpt: Option<ExcerptConfig>,
+}
+
+impl SectionTable {
+    /// Fills the fields this table leaves out from `parent`, or fails with the name
+    /// of a required field neither sets.
+    fn resolve(
+        self,
+        parent: Option<&ContentTypeConfig>,
+    ) -> Result<ContentTypeConfig, &'static str> {
+        Ok(ContentTypeConfig {
+            index_template: self
+                .index_template
+                .or_else(|| parent.map(|p| p.index_template.clone()))
+                .ok_or("index_template")?,
+            content_template: self
+                .content_template
+                .or_else(|| parent.map(|p| p.content_template.clone()))
+                .ok_or("content_template")?,
+            url_pattern: self
+                .url_pattern
+                .or_else(|| parent.and_then(|p| p.url_pattern.clone())),
+            output_naming: self
+                .output_naming
+                .or_else(|| parent.and_then(|p| p.output_naming.clone())),
+            rss_include: self.rss_include.or(parent.and_then(|p| p.rss_include)),
+            paginate_by: self.paginate_by.or(parent.and_then(|p| p.paginate_by)),
+            sort_by: self
+                .sort_by
+                .or_else(|| parent.and_then(|p| p.sort_by.clone())),
+            sort_order: self.sort_order.or(parent.and_then(|p| p.sort_order)),
+            excerpt: self
+                .excerpt
+                .or_else(|| parent.and_then(|p| p.excerpt.clone())),
+        })
+    }
+}
+
+/// Reads the `[content.*]` tables, resolving each nested section (`docs/api`)
+/// against its nearest configured parent (`docs`): every field it leaves out is
+/// inherited. A table without a configured parent must set both templates.
+fn deserialize_sections<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, ContentTypeConfig>, D::Error> {
+    let tables = BTreeMap::<String, SectionTable>::deserialize(deserializer)?;
+    let mut sections: HashMap<String, ContentTypeConfig> = HashMap::new();
+
+    // Sorted by path, so parents are resolved before their subsections
+    for (path, table) in tables {
+        let mut parent = None;
+        let mut current = path.as_str();
+        while let Some((up, _)) = current.rsplit_once('/') {
+            if let Some(config) = sections.get(up) {
+                parent = Some(config);
+                break;
+            }
+            current = up;
+        }
+
+        let section = table.resolve(parent).map_err(|field| {
+            D::Error::custom(format!(
+                "[content.{:?}] has no {} and no configured parent section to inherit it from",
+                path, field
+            ))
+        })?;
+        sections.insert(path, section);
+    }
+
+    Ok(sections)
+}
+
+impl ContentTypeConfig {
+    /// Order of this content type's index, pagination and prev/next navigation.
+    pub(crate) fn listing_order(&self) -> ListingOrder {
+        let field = self.sort_by.clone().unwrap_or_else(|| "date".to_string());
+        let direction = self.sort_order.unwrap_or(if field == "date" {
+            SortOrder::Desc
+        } else {
+            SortOrder::Asc
+        });
+        ListingOrder { field, direction }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// How a listing of content is ordered: a metadata field and a direction.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ListingOrder {
+    /// "date", "title", "weight" or an [extra] key
+    pub field: String,
+    pub direction: SortOrder,
+}
+
+impl Default for ListingOrder {
+    /// Newest first, used by the site index, taxonomy pages and the RSS feed
+    fn default() -> Self {
+        Self {
+            field: "date".to_string(),
+            direction: SortOrder::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct TaxonomyConfig {
+    /// Template for /<name>/, listing every term with its page count
+    pub index_template: String,
+    /// Template for /<name>/<term>/, listing the pages filed under one term
+    pub term_template: String,
 }
 
 #[cfg(test)]
@@ -226,10 +547,13 @@ twitter_handle = "@user"
 
         assert_eq!(config.dynamic.len(), 2);
         assert_eq!(
-            config.dynamic.get("github_url").unwrap(),
-            "https://github.com/user"
+            config.dynamic.get("github_url").unwrap().as_str(),
+            Some("https://github.com/user")
+        );
+        assert_eq!(
+            config.dynamic.get("twitter_handle").unwrap().as_str(),
+            Some("@user")
         );
-        assert_eq!(config.dynamic.get("twitter_handle").unwrap(), "@user");
     }
 
     #[test]
@@ -568,6 +892,87 @@ clean_urls = true
         );
     }
 
+    #[test]
+    fn test_config_broken_links() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert_eq!(config.site.broken_links, BrokenLinks::Warn);
+
+        let toml = minimal_config_toml().replace("[site]\n", "[site]\nbroken_links = \"error\"\n");
+        let config = Config::from_str(&toml).unwrap();
+        assert_eq!(config.site.broken_links, BrokenLinks::Error);
+    }
+
+    #[test]
+    fn test_config_excerpt() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert_eq!(config.site.excerpt, ExcerptConfig::default());
+        assert_eq!(config.site.excerpt.heading, "## Context");
+
+        let toml = format!(
+            "{}{}",
+            minimal_config_toml().replace("[site]\n", "[site]\nexcerpt = { words = 30 }\n"),
+            r#"
+[content.blog]
+index_template = "blog_index.html"
+content_template = "post.html"
+
+[content.blog.excerpt]
+heading = ""
+fallback = "words"
+"#
+        );
+        let config = Config::from_str(&toml).unwrap();
+        assert_eq!(config.site.excerpt.words, 30);
+        assert_eq!(config.site.excerpt.fallback, ExcerptFallback::Paragraph);
+
+        let blog = config.excerpt_config("blog");
+        assert_eq!(blog.heading, "");
+        assert_eq!(blog.fallback, ExcerptFallback::Words);
+        assert_eq!(blog.words, 50);
+        assert_eq!(config.excerpt_config("pages"), &config.site.excerpt);
+    }
+
+    #[test]
+    fn test_config_toc_levels() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert_eq!(config.site.toc, TocConfig::default());
+
+        let toml = format!(
+            "{}\n[site.toc]\nmin_level = 1\nmax_level = 4\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+        assert_eq!(
+            (config.site.toc.min_level, config.site.toc.max_level),
+            (1, 4)
+        );
+
+        for levels in [
+            "min_level = 4\nmax_level = 2",
+            "min_level = 0",
+            "max_level = 7",
+        ] {
+            let toml = format!("{}\n[site.toc]\n{}\n", minimal_config_toml(), levels);
+            assert!(matches!(
+                Config::from_str(&toml),
+                Err(ConfigError::InvalidTocLevels { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_config_words_per_minute() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert_eq!(config.site.words_per_minute.get(), 200);
+
+        let toml = minimal_config_toml().replace("[site]\n", "[site]\nwords_per_minute = 250\n");
+        let config = Config::from_str(&toml).unwrap();
+        assert_eq!(config.site.words_per_minute.get(), 250);
+
+        let toml = minimal_config_toml().replace("[site]\n", "[site]\nwords_per_minute = 0\n");
+        assert!(Config::from_str(&toml).is_err());
+    }
+
     #[test]
     fn test_config_redirects_default() {
         let config = Config::from_str(minimal_config_toml()).unwrap();
@@ -669,4 +1074,149 @@ content_template = "page.html"
         let pages = config.content.get("pages").unwrap();
         assert!(pages.url_pattern.is_none());
     }
+
+    #[test]
+    fn test_config_timezone() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert_eq!(config.site.utc_offset().unwrap(), UtcOffset::UTC);
+
+        for (timezone, hours, minutes) in [("+02:00", 2, 0), ("-05:30", -5, -30), ("UTC", 0, 0)] {
+            let toml = format!("{}timezone = \"{}\"\n", minimal_config_toml(), timezone);
+            let config = Config::from_str(&toml).unwrap();
+            assert_eq!(
+                config.site.utc_offset().unwrap(),
+                UtcOffset::from_hms(hours, minutes, 0).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_invalid_timezone() {
+        let toml = format!("{}timezone = \"Europe/Berlin\"\n", minimal_config_toml());
+        let result = Config::from_str(&toml);
+
+        assert!(matches!(result, Err(ConfigError::InvalidTimezone(tz)) if tz == "Europe/Berlin"));
+    }
+
+    #[test]
+    fn test_config_with_taxonomies() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert!(config.taxonomies.is_empty());
+
+        let toml = format!(
+            "{}\n[taxonomies.tags]\nindex_template = \"tags.html\"\nterm_template = \"tag.html\"\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+
+        let tags = config.taxonomies.get("tags").unwrap();
+        assert_eq!(tags.index_template, "tags.html");
+        assert_eq!(tags.term_template, "tag.html");
+    }
+
+    #[test]
+    fn test_config_paginate_by() {
+        let config = Config::from_str(minimal_config_toml()).unwrap();
+        assert!(config.site.paginate_by.is_none());
+
+        let toml = format!(
+            "{}paginate_by = 20\n\n[content.blog]\nindex_template = \"i.html\"\ncontent_template = \"c.html\"\npaginate_by = 5\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+        assert_eq!(config.site.paginate_by.map(NonZeroUsize::get), Some(20));
+        assert_eq!(
+            config.content["blog"].paginate_by.map(NonZeroUsize::get),
+            Some(5)
+        );
+
+        // Zero items per page is rejected
+        let toml = format!("{}paginate_by = 0\n", minimal_config_toml());
+        assert!(matches!(
+            Config::from_str(&toml),
+            Err(ConfigError::TomlParse(_))
+        ));
+    }
+
+    #[test]
+    fn test_content_type_listing_order() {
+        let toml = format!(
+            "{}\n[content.blog]\nindex_template = \"i.html\"\ncontent_template = \"c.html\"\n\n[content.docs]\nindex_template = \"i.html\"\ncontent_template = \"c.html\"\nsort_by = \"weight\"\n\n[content.pages]\nindex_template = \"i.html\"\ncontent_template = \"c.html\"\nsort_by = \"title\"\nsort_order = \"desc\"\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+
+        assert_eq!(
+            config.content["blog"].listing_order(),
+            ListingOrder::default()
+        );
+        assert_eq!(
+            config.content["docs"].listing_order(),
+            ListingOrder {
+                field: "weight".to_string(),
+                direction: SortOrder::Asc,
+            }
+        );
+        assert_eq!(
+            config.content["pages"].listing_order().direction,
+            SortOrder::Desc
+        );
+
+        let invalid = toml.replace("sort_order = \"desc\"", "sort_order = \"down\"");
+        assert!(Config::from_str(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_section_config_falls_back_to_parent() {
+        let toml = format!(
+            "{}\n[content.docs]\nindex_template = \"docs_index.html\"\ncontent_template = \"doc.html\"\n\n[content.\"docs/api\"]\nindex_template = \"api_index.html\"\ncontent_template = \"api.html\"\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+
+        let template = |section| {
+            config
+                .section_config(section)
+                .map(|ct| ct.content_template.as_str())
+        };
+        assert_eq!(template("docs"), Some("doc.html"));
+        assert_eq!(template("docs/guides/install"), Some("doc.html"));
+        assert_eq!(template("docs/api"), Some("api.html"));
+        assert_eq!(template("docs/api/v2"), Some("api.html"));
+        assert_eq!(template("blog"), None);
+        assert_eq!(template(""), None);
+    }
+
+    #[test]
+    fn test_nested_section_inherits_unset_fields() {
+        let toml = format!(
+            "{}\n[content.docs]\nindex_template = \"docs_index.html\"\ncontent_template = \"doc.html\"\n\
+             sort_by = \"weight\"\npaginate_by = 10\nrss_include = false\n\n\
+             [content.\"docs/api\"]\ncontent_template = \"api.html\"\n\n\
+             [content.\"docs/api/v2\"]\nsort_by = \"title\"\n",
+            minimal_config_toml()
+        );
+        let config = Config::from_str(&toml).unwrap();
+
+        let api = config.section_config("docs/api").unwrap();
+        assert_eq!(api.content_template, "api.html");
+        assert_eq!(api.index_template, "docs_index.html");
+        assert_eq!(api.sort_by.as_deref(), Some("weight"));
+        assert_eq!(api.paginate_by, NonZeroUsize::new(10));
+        assert_eq!(api.rss_include, Some(false));
+
+        // Deeper sections inherit through their parents' resolved settings
+        let v2 = config.section_config("docs/api/v2").unwrap();
+        assert_eq!(v2.content_template, "api.html");
+        assert_eq!(v2.sort_by.as_deref(), Some("title"));
+        assert_eq!(v2.paginate_by, NonZeroUsize::new(10));
+
+        // Without a configured parent, both templates are required
+        let orphan = format!(
+            "{}\n[content.\"docs/api\"]\ncontent_template = \"api.html\"\n",
+            minimal_config_toml()
+        );
+        let error = Config::from_str(&orphan).unwrap_err().to_string();
+        assert!(error.contains("has no index_template"), "{}", error);
+    }
 }
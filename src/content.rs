@@ -2,16 +2,28 @@
 
 use serde::{Deserialize, Serialize};
 use std::{
+    cmp::Ordering,
     collections::HashMap,
     fs,
     path::{Path, PathBuf},
 };
 use thiserror::Error;
-use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 use tracing::{debug, error, instrument};
 
+use crate::bundle::BUNDLE_INDEX_FILE;
+use crate::config::{
+    ConfigError, ExcerptConfig, ExcerptFallback, ListingOrder, SiteConfig, SortOrder,
+};
 use crate::syntax::highlight_html;
-use crate::utils::add_header_anchors;
+use crate::utils::{
+    add_header_anchors, add_header_ids, count_text_words, extract_date_from_filename,
+    find_marker_line, html_to_text, serialize_toml_map, truncate_html,
+};
+
+/// Line that opens and closes TOML front matter in a markdown file
+pub(crate) const FRONT_MATTER_DELIMITER: &str = "+++";
 
 /// Creates markdown parsing options with optional dangerous HTML support.
 fn markdown_options(allow_dangerous_html: bool) -> markdown::Options {
@@ -45,16 +57,24 @@ pub(crate) struct Content {
 /// This struct represents metadata that accompanies each
 /// markdown content file. It contains essential information about the
 /// content such as title, publication date, author, and tags.
+///
+/// `title`, `date`, `author` and `tags` may be left out of a file: loading
+/// derives them from the content, filename and site config (see [`load_content`]).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(crate) struct ContentMeta {
     /// Title of the content piece (article, post, page, etc.)
     pub title: String,
     /// Publication date of the content (recommended format: YYYY-MM-DD)
+    /// Metadata loading normalizes lenient forms to RFC 3339, see [`parse_date`]
     #[serde(with = "time::serde::rfc3339")]
     pub date: OffsetDateTime,
+    /// Optional time of the last significant revision, for lastmod and feeds
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub updated: Option<OffsetDateTime>,
     /// Author of the content
     pub author: String,
     /// List of tags/categories associated with the content
+    #[serde(default)]
     pub tags: Vec<String>,
     /// Optional custom template to use for rendering this content
     /// If not specified, a default template will be used
@@ -64,9 +84,10 @@ pub(crate) struct ContentMeta {
     #[serde(default)]
     pub cover: Option<String>,
     /// Additional custom fields defined in [extra] section of metadata
+    /// Any TOML value is accepted (strings, numbers, booleans, arrays, tables)
     /// Access in templates via meta.extra.field_name
-    #[serde(default)]
-    pub extra: HashMap<String, String>,
+    #[serde(default, serialize_with = "serialize_toml_map")]
+    pub extra: HashMap<String, toml::Value>,
     /// JavaScript files to load for this content
     /// Access in templates via meta.extra_js
     #[serde(default)]
@@ -74,6 +95,113 @@ pub(crate) struct ContentMeta {
     /// Whether this content is a draft (excluded from builds unless --include-drafts)
     #[serde(default)]
     pub draft: bool,
+    /// Optional time the content goes live, instead of `date` (see [`crate::publish`])
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub publish_date: Option<OffsetDateTime>,
+    /// Optional time from which the content is no longer built
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub expiry_date: Option<OffsetDateTime>,
+    /// Optional position for listings sorted by `weight` (lower comes first)
+    #[serde(default)]
+    pub weight: Option<i64>,
+    /// Optional markdown excerpt, used instead of one taken from the content
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Insert the table of contents at the top of the page (see [`crate::toc`])
+    #[serde(default)]
+    pub toc: bool,
+}
+
+impl ContentMeta {
+    /// When the content last changed: `updated`, or else `date`.
+    pub(crate) fn last_modified(&self) -> OffsetDateTime {
+        self.updated.unwrap_or(self.date)
+    }
+}
+
+/// Site-wide values used for metadata fields a content file leaves out.
+#[derive(Clone, Debug)]
+pub(crate) struct MetaDefaults {
+    /// Author used when `author` is missing (`site.author`)
+    pub(crate) author: String,
+    /// Offset for dates without one (`site.timezone`)
+    pub(crate) timezone: UtcOffset,
+}
+
+impl MetaDefaults {
+    pub(crate) fn from_site(site: &SiteConfig) -> Result<Self, ConfigError> {
+        Ok(Self {
+            author: site.author.clone(),
+            timezone: site.utc_offset()?,
+        })
+    }
+}
+
+/// Orders two pages for a listing by `order.field`: "date", "title", "weight" or an
+/// `[extra]` key.
+///
+/// Pages without the field come last in either direction. Ties fall back to newest
+/// first, then title, so listings are stable across builds.
+pub(crate) fn compare_meta(a: &ContentMeta, b: &ContentMeta, order: &ListingOrder) -> Ordering {
+    let directed = |ordering: Ordering| match order.direction {
+        SortOrder::Asc => ordering,
+        SortOrder::Desc => ordering.reverse(),
+    };
+
+    let ordering = match order.field.as_str() {
+        "date" => directed(a.date.cmp(&b.date)),
+        "title" => directed(a.title.to_lowercase().cmp(&b.title.to_lowercase())),
+        "weight" => match (a.weight, b.weight) {
+            (Some(x), Some(y)) => directed(x.cmp(&y)),
+            (x, y) => x.is_none().cmp(&y.is_none()),
+        },
+        key => match (a.extra.get(key), b.extra.get(key)) {
+            (Some(x), Some(y)) => directed(compare_toml(x, y)),
+            (x, y) => x.is_none().cmp(&y.is_none()),
+        },
+    };
+
+    ordering
+        .then_with(|| b.date.cmp(&a.date))
+        .then_with(|| a.title.cmp(&b.title))
+}
+
+/// Compares `[extra]` values: numbers numerically, other values of the same type
+/// naturally, and mixed types by their TOML representation.
+fn compare_toml(a: &toml::Value, b: &toml::Value) -> Ordering {
+    use toml::Value;
+
+    match (a, b) {
+        (Value::Integer(x), Value::Integer(y)) => x.cmp(y),
+        (Value::Float(x), Value::Float(y)) => x.total_cmp(y),
+        (Value::Integer(x), Value::Float(y)) => (*x as f64).total_cmp(y),
+        (Value::Float(x), Value::Integer(y)) => x.total_cmp(&(*y as f64)),
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        (Value::Boolean(x), Value::Boolean(y)) => x.cmp(y),
+        _ => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+/// Intro content of a section, loaded from the `_index.md` in its directory.
+///
+/// All metadata is optional; `title` falls back to the first `# Heading`.
+#[derive(Debug, Default)]
+pub(crate) struct SectionIndex {
+    /// Section title from the metadata or the first heading
+    pub(crate) title: Option<String>,
+    /// The rendered markdown body
+    pub(crate) html: String,
+    /// Custom fields from the [extra] table
+    pub(crate) extra: HashMap<String, toml::Value>,
+}
+
+/// Metadata accepted in a section's `_index.md`.
+#[derive(Deserialize)]
+struct SectionIndexMeta {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    extra: HashMap<String, toml::Value>,
 }
 
 /// Processed content item ready for template rendering and output.
@@ -89,12 +217,22 @@ pub(crate) struct ContentItem {
     pub(crate) meta: ContentMeta,
     /// Human-readable formatted date string
     pub(crate) formatted_date: String,
+    /// Human-readable `updated` date, when the metadata sets one
+    pub(crate) formatted_updated: Option<String>,
     /// Output filename for this content piece
     pub(crate) filename: String,
     /// Content type category (e.g., "blog", "projects", "page")
     pub(crate) content_type: String,
-    /// HTML excerpt extracted from the content
+    /// Section the content lives in (e.g., "blog", "docs/guides")
+    pub(crate) section: String,
+    /// HTML excerpt, see [`excerpt_html`]
     pub(crate) excerpt: String,
+    /// The excerpt as plain text (e.g., for `<meta name="description">`)
+    pub(crate) excerpt_text: String,
+    /// Words in the markdown body, see [`crate::utils::count_words`]
+    pub(crate) word_count: usize,
+    /// Minutes to read `word_count` words at `site.words_per_minute`, rounded up
+    pub(crate) reading_time_minutes: usize,
 }
 
 /// Error types that can occur during content loading and processing.
@@ -113,13 +251,39 @@ pub(crate) enum ContentError {
         source: std::io::Error,
     },
     /// TOML parsing error in metadata files
-    #[error("TOML parsing error in metadata file {path:?}: {source}")]
+    #[error("TOML parsing error in metadata file {path:?}: {source}{}", field_hint(.field.as_deref()))]
     TomlParse {
         /// Path to the TOML file that failed to parse
         path: PathBuf,
+        /// Metadata field the error points at, when it can be determined
+        field: Option<String>,
         /// The underlying TOML parsing error
         #[source]
-        source: toml::de::Error,
+        source: Box<toml::de::Error>,
+    },
+    /// A required metadata field is missing and cannot be derived
+    #[error("Missing `{field}` for {path:?}: {hint}")]
+    MissingField {
+        /// Path to the markdown file
+        path: PathBuf,
+        /// Name of the missing field
+        field: &'static str,
+        /// How to provide the field
+        hint: &'static str,
+    },
+    /// Front matter opened with `+++` but never closed
+    #[error("Unterminated front matter in {path:?}: missing closing `+++` line")]
+    UnterminatedFrontMatter {
+        /// Path to the markdown file with the unterminated block
+        path: PathBuf,
+    },
+    /// Front matter and sidecar metadata both exist with different values
+    #[error("Metadata in front matter of {path:?} conflicts with sidecar file {meta_path:?}")]
+    MetadataConflict {
+        /// Path to the markdown file with front matter
+        path: PathBuf,
+        /// Path to the sidecar `.meta.toml` file
+        meta_path: PathBuf,
     },
     /// Markdown parsing or conversion failure
     #[error("Markdown parsing failed for file {path:?}: {message}")]
@@ -141,43 +305,166 @@ pub(crate) enum ContentError {
 
 /// Loads both metadata and content from a markdown file.
 ///
-/// This function reads a markdown file and its corresponding `.meta.toml` file,
-/// returning a complete `Content` struct with both the parsed metadata and
-/// raw markdown content.
+/// Metadata comes from `+++`-delimited TOML front matter at the top of the
+/// markdown file when present, otherwise from the corresponding `.meta.toml`
+/// sidecar file. The returned `data` never includes the front matter block.
+///
+/// Missing fields are derived: `title` from the first `# H1` of the markdown,
+/// `date` from a `YYYY-MM-DD-` filename prefix, `author` from `site.author`
+/// and `tags` as empty. A file may have no metadata at all.
 ///
 /// # Arguments
 /// * `path` - Path to the markdown file to load
+/// * `defaults` - Site author and timezone for derived and offset-less values
 ///
 /// # Returns
 /// `Result<Content, ContentError>` - The loaded content or an error
 ///
 /// # Errors
 /// Returns `ContentError::Io` if the markdown file cannot be read.
-/// Returns `ContentError::TomlParse` if the metadata file cannot be parsed.
+/// Returns `ContentError::TomlParse` if the metadata cannot be parsed.
+/// Returns `ContentError::MetadataConflict` if front matter and a sidecar file
+/// both exist and disagree.
+/// Returns `ContentError::MissingField` if a field is missing and cannot be derived.
 ///
 /// # Examples
 /// ```
 /// # use std::path::PathBuf;
 /// # use your_crate::content::load_content;
-/// let content = load_content(&PathBuf::from("content/blog/post.md"))?;
+/// let content = load_content(&PathBuf::from("content/blog/post.md"), &defaults)?;
 /// println!("Title: {}", content.meta.title);
 /// ```
 #[instrument(skip_all)]
-pub(crate) fn load_content(path: &PathBuf) -> Result<Content, ContentError> {
-    // 1. Load the metadata from the corresponding `.meta.toml` file.
-    let meta = load_metadata(path)?;
-
-    // 2. Read the entire markdown file content into a string.
+pub(crate) fn load_content(
+    path: &PathBuf,
+    defaults: &MetaDefaults,
+) -> Result<Content, ContentError> {
+    // 1. Read the entire markdown file content into a string.
     debug!("io::read ← {:?}", path);
-    let data = fs::read_to_string(path).map_err(|e| ContentError::Io {
+    let text = fs::read_to_string(path).map_err(|e| ContentError::Io {
         path: path.clone(),
         source: e,
     })?;
-    debug!("io::read {} bytes", data.len());
+    debug!("io::read {} bytes", text.len());
+
+    // 2. Prefer front matter, falling back to the `.meta.toml` sidecar file.
+    let (mut table, meta_path, data) = match split_front_matter(&text, path)? {
+        Some((front_matter, body)) => {
+            let table = parse_table(front_matter, path)?;
+            check_sidecar_agrees(path, &table)?;
+            (table, path.clone(), body.to_string())
+        }
+        None => (
+            load_metadata(path)?.unwrap_or_default(),
+            path.with_extension("meta.toml"),
+            text,
+        ),
+    };
+
+    // 3. Fill in what the metadata leaves out.
+    derive_missing_fields(&mut table, path, &data, defaults)?;
+    let meta = parse_metadata(table, &meta_path, defaults.timezone)?;
 
     Ok(Content { meta, data })
 }
 
+/// Loads a section's `_index.md` and renders its markdown with the site settings.
+///
+/// Metadata is read from front matter or a `_index.meta.toml` sidecar, like for
+/// regular content (see [`load_content`]), but every field is optional.
+///
+/// # Errors
+/// Returns the same errors as [`load_content`], except `MissingField`.
+#[instrument(skip_all)]
+pub(crate) fn load_section_index(
+    path: &Path,
+    site: &SiteConfig,
+) -> Result<SectionIndex, ContentError> {
+    debug!("io::read ← {:?}", path);
+    let text = fs::read_to_string(path).map_err(|e| ContentError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    let (table, meta_path, data) = match split_front_matter(&text, path)? {
+        Some((front_matter, body)) => {
+            let table = parse_table(front_matter, path)?;
+            check_sidecar_agrees(path, &table)?;
+            (table, path.to_path_buf(), body)
+        }
+        None => (
+            load_metadata(path)?.unwrap_or_default(),
+            path.with_extension("meta.toml"),
+            text.as_str(),
+        ),
+    };
+    let meta: SectionIndexMeta = table
+        .try_into()
+        .map_err(|e| toml_error(&meta_path, "", e))?;
+
+    let html = convert_markdown(
+        data,
+        path,
+        site.syntax_highlighting_enabled,
+        &site.syntax_highlighting_theme,
+        site.allow_dangerous_html,
+        site.header_uri_fragment,
+    )?;
+
+    Ok(SectionIndex {
+        title: meta.title.or_else(|| first_heading(data)),
+        html,
+        extra: meta.extra,
+    })
+}
+
+/// Splits `+++`-delimited TOML front matter from the start of a markdown file.
+///
+/// Returns the TOML source and the markdown body after the closing line, or
+/// `None` when the file does not open with a `+++` line.
+///
+/// # Errors
+/// Returns `ContentError::UnterminatedFrontMatter` if the block is never closed.
+pub(crate) fn split_front_matter<'a>(
+    text: &'a str,
+    path: &Path,
+) -> Result<Option<(&'a str, &'a str)>, ContentError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let Some(rest) = text.strip_prefix(FRONT_MATTER_DELIMITER) else {
+        return Ok(None);
+    };
+    let Some(rest) = rest
+        .strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))
+    else {
+        return Ok(None);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Ok(Some((&rest[..offset], &rest[offset + line.len()..])));
+        }
+        offset += line.len();
+    }
+
+    Err(ContentError::UnterminatedFrontMatter {
+        path: path.to_path_buf(),
+    })
+}
+
+/// Errors if a sidecar `.meta.toml` exists next to a file with front matter and
+/// holds different values. An identical sidecar (e.g. mid-migration) is accepted.
+fn check_sidecar_agrees(markdown_path: &Path, inline: &toml::Table) -> Result<(), ContentError> {
+    match load_metadata(markdown_path)? {
+        Some(sidecar) if &sidecar != inline => Err(ContentError::MetadataConflict {
+            path: markdown_path.to_path_buf(),
+            meta_path: markdown_path.with_extension("meta.toml"),
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Loads metadata from a `.meta.toml` file corresponding to a markdown file.
 ///
 /// For a given markdown file path, this function looks for and parses the
@@ -187,22 +474,26 @@ pub(crate) fn load_content(path: &PathBuf) -> Result<Content, ContentError> {
 /// * `markdown_path` - Path to the markdown file
 ///
 /// # Returns
-/// `Result<ContentMeta, ContentError>` - The parsed metadata or an error
+/// The parsed TOML table, or `None` when there is no sidecar file
 ///
 /// # Errors
-/// Returns `ContentError::Io` if the metadata file cannot be read.
+/// Returns `ContentError::Io` if the metadata file exists but cannot be read.
 /// Returns `ContentError::TomlParse` if the TOML content cannot be parsed.
 ///
 /// # Examples
 /// ```
 /// # use std::path::PathBuf;
 /// # use your_crate::content::load_metadata;
-/// let meta = load_metadata(&PathBuf::from("content/blog/post.md"))?;
-/// println!("Author: {}", meta.author);
+/// let table = load_metadata(&PathBuf::from("content/blog/post.md"))?;
+/// println!("Has sidecar: {}", table.is_some());
 /// ```
-pub(crate) fn load_metadata(markdown_path: &Path) -> Result<ContentMeta, ContentError> {
+pub(crate) fn load_metadata(markdown_path: &Path) -> Result<Option<toml::Table>, ContentError> {
     // hello-world.md" -> "hello-world.meta.toml"
     let meta_path = markdown_path.with_extension("meta.toml");
+    if !meta_path.exists() {
+        return Ok(None);
+    }
+
     debug!("io::read ← {:?}", meta_path);
     let meta_content = fs::read_to_string(&meta_path).map_err(|e| ContentError::Io {
         path: meta_path.clone(),
@@ -210,13 +501,253 @@ pub(crate) fn load_metadata(markdown_path: &Path) -> Result<ContentMeta, Content
     })?;
     debug!("io::read {} bytes", meta_content.len());
 
-    let metadata: ContentMeta =
-        toml::from_str(&meta_content).map_err(|e| ContentError::TomlParse {
-            path: meta_path,
-            source: e,
+    parse_table(&meta_content, &meta_path).map(Some)
+}
+
+/// Parses metadata TOML from a sidecar file or front matter into a table.
+fn parse_table(source: &str, path: &Path) -> Result<toml::Table, ContentError> {
+    toml::from_str(source).map_err(|e| toml_error(path, source, e))
+}
+
+/// Fills in `title`, `date`, `author` and `tags` when the metadata leaves them out.
+///
+/// # Errors
+/// Returns `ContentError::MissingField` naming the file and field when a value
+/// cannot be derived.
+fn derive_missing_fields(
+    table: &mut toml::Table,
+    markdown_path: &Path,
+    markdown: &str,
+    defaults: &MetaDefaults,
+) -> Result<(), ContentError> {
+    let missing = |field, hint| ContentError::MissingField {
+        path: markdown_path.to_path_buf(),
+        field,
+        hint,
+    };
+
+    if !table.contains_key("title") {
+        let title = first_heading(markdown).ok_or_else(|| {
+            missing(
+                "title",
+                "set `title` in the metadata or start the markdown with a `# Heading`",
+            )
+        })?;
+        debug!("content::derive title {:?} for {:?}", title, markdown_path);
+        table.insert("title".to_string(), toml::Value::String(title));
+    }
+
+    if !table.contains_key("date") {
+        // A page bundle's index takes its date from the bundle directory
+        let source = match markdown_path.file_name() {
+            Some(name) if name == BUNDLE_INDEX_FILE => markdown_path.parent(),
+            _ => Some(markdown_path),
+        };
+        let filename = source
+            .and_then(Path::file_name)
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let date = extract_date_from_filename(filename).ok_or_else(|| {
+            missing(
+                "date",
+                "set `date` in the metadata or prefix the filename with YYYY-MM-DD-",
+            )
         })?;
+        debug!("content::derive date {} for {:?}", date, markdown_path);
+        table.insert("date".to_string(), toml::Value::String(date.to_string()));
+    }
 
-    Ok(metadata)
+    if !table.contains_key("author") {
+        if defaults.author.is_empty() {
+            return Err(missing(
+                "author",
+                "set `author` in the metadata or `author` in [site]",
+            ));
+        }
+        table.insert(
+            "author".to_string(),
+            toml::Value::String(defaults.author.clone()),
+        );
+    }
+
+    Ok(())
+}
+
+/// Text of the first level-one ATX heading (`# Title`) outside code blocks.
+fn first_heading(markdown: &str) -> Option<String> {
+    let mut fence: Option<&str> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if let Some(open) = fence {
+            if trimmed.starts_with(open) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+        // More than three spaces of indentation is an indented code block
+        if line.len() - trimmed.len() > 3 {
+            continue;
+        }
+
+        let Some(heading) = trimmed
+            .strip_prefix('#')
+            .filter(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+        else {
+            continue;
+        };
+        // Closing sequence: `# Title #`
+        let title = heading.trim().trim_end_matches('#').trim_end();
+        if !title.is_empty() {
+            return Some(title.to_string());
+        }
+    }
+
+    None
+}
+
+/// Metadata fields holding a point in time, see [`parse_date`]
+const DATE_FIELDS: [&str; 4] = ["date", "updated", "publish_date", "expiry_date"];
+
+/// Deserializes metadata from its TOML table.
+///
+/// The [`DATE_FIELDS`] are resolved with [`parse_date`] before the rest of the
+/// metadata is deserialized, so any of the lenient date forms are accepted.
+///
+/// # Errors
+/// Returns `ContentError::TomlParse` naming the offending field when a field
+/// has the wrong type, is missing, or the date cannot be parsed.
+pub(crate) fn parse_metadata(
+    mut table: toml::Table,
+    path: &Path,
+    timezone: UtcOffset,
+) -> Result<ContentMeta, ContentError> {
+    for field in DATE_FIELDS {
+        let Some(value) = table.get_mut(field) else {
+            continue;
+        };
+        let date = parse_date(value, timezone)
+            .and_then(|date| date.format(&Rfc3339).map_err(|e| e.to_string()))
+            .map_err(|reason| ContentError::TomlParse {
+                path: path.to_path_buf(),
+                field: Some(field.to_string()),
+                source: Box::new(serde::de::Error::custom(format!(
+                    "invalid date {value}: {reason}"
+                ))),
+            })?;
+        *value = toml::Value::String(date);
+    }
+
+    table.try_into().map_err(|e| toml_error(path, "", e))
+}
+
+/// Resolves a metadata `date` value to a point in time.
+///
+/// Accepts RFC 3339 (`2025-01-07T10:00:00+02:00`), plain dates (`2025-01-07`) and
+/// local date-times (`2025-01-07T10:00:00`, `2025-01-07 10:00`), either quoted or
+/// as TOML date/datetime literals. Values without an offset are taken to be in
+/// `timezone`; plain dates mean midnight.
+pub(crate) fn parse_date(
+    value: &toml::Value,
+    timezone: UtcOffset,
+) -> Result<OffsetDateTime, String> {
+    let datetime = match value {
+        toml::Value::Datetime(datetime) => *datetime,
+        toml::Value::String(text) => text
+            .trim()
+            .parse::<toml::value::Datetime>()
+            .map_err(|_| "not a recognised date format".to_string())?,
+        other => return Err(format!("expected a date, found a {}", other.type_str())),
+    };
+
+    let Some(date) = datetime.date else {
+        return Err("a time without a date".to_string());
+    };
+    let month = Month::try_from(date.month).map_err(|e| e.to_string())?;
+    let date = Date::from_calendar_date(i32::from(date.year), month, date.day)
+        .map_err(|e| e.to_string())?;
+    let time = match datetime.time {
+        Some(time) => Time::from_hms_nano(time.hour, time.minute, time.second, time.nanosecond)
+            .map_err(|e| e.to_string())?,
+        None => Time::MIDNIGHT,
+    };
+    let offset = match datetime.offset {
+        None => timezone,
+        Some(toml::value::Offset::Z) => UtcOffset::UTC,
+        Some(toml::value::Offset::Custom { minutes }) => {
+            UtcOffset::from_whole_seconds(i32::from(minutes) * 60).map_err(|e| e.to_string())?
+        }
+    };
+
+    Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+}
+
+/// Wraps a TOML error with the metadata field it points at.
+fn toml_error(path: &Path, source: &str, error: toml::de::Error) -> ContentError {
+    ContentError::TomlParse {
+        path: path.to_path_buf(),
+        field: error_field(source, &error),
+        source: Box::new(error),
+    }
+}
+
+/// Finds the metadata field a TOML error refers to.
+///
+/// Syntax errors carry a span into `source`, whose line starts with the key.
+/// Deserialization errors name the field in their message instead.
+fn error_field(source: &str, error: &toml::de::Error) -> Option<String> {
+    if let Some(span) = error.span()
+        && !span.is_empty()
+        && let Some(before) = source.get(..span.start)
+    {
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = source[line_start..].lines().next().unwrap_or_default();
+        if let Some((key, _)) = line.split_once('=') {
+            return Some(key.trim().trim_matches('"').to_string());
+        }
+    }
+
+    let message = error.to_string();
+    let named = |prefix: &str| {
+        let start = message.find(prefix)? + prefix.len();
+        let len = message[start..].find('`')?;
+        Some(message[start..start + len].to_string())
+    };
+    named("missing field `")
+        .or_else(|| named("in `"))
+        .map(|key| key.split('.').next().unwrap_or_default().to_string())
+}
+
+/// Hint appended to metadata parse errors: the field and an example of a valid value.
+fn field_hint(field: Option<&str>) -> String {
+    let Some(field) = field else {
+        return String::new();
+    };
+    let example = match field {
+        "title" => r#"title = "Hello World""#,
+        "date" => r#"date = "2025-01-07" (or "2025-01-07T10:00:00", "2025-01-07T10:00:00+02:00")"#,
+        "author" => r#"author = "Jane Doe""#,
+        "updated" => r#"updated = "2025-02-01" (or "2025-02-01T10:00:00+02:00")"#,
+        "tags" => r#"tags = ["rust", "web"] (or tags = [])"#,
+        "template" => r#"template = "custom.html""#,
+        "cover" => r#"cover = "/images/cover.jpg""#,
+        "extra" => "an [extra] table, e.g. [extra] followed by category = \"tutorials\"",
+        "extra_js" => r#"extra_js = ["js/chart.js"]"#,
+        "draft" => "draft = true",
+        "publish_date" => r#"publish_date = "2025-01-07T09:00:00+02:00" (or "2025-01-07")"#,
+        "expiry_date" => r#"expiry_date = "2025-12-31" (or "2025-12-31T23:59:00+02:00")"#,
+        "weight" => "weight = 10",
+        _ => return format!("\n  in field `{field}`"),
+    };
+    format!("\n  in field `{field}`, expected e.g. {example}")
 }
 
 /// Convert markdown content to HTML with optional syntax highlighting.
@@ -265,25 +796,50 @@ pub(crate) fn convert_content_with_highlighting(
     allow_dangerous_html: bool,
     header_uri_fragment: bool,
 ) -> Result<String, ContentError> {
-    // Convert markdown to HTML
-    let mut html = match markdown::to_html_with_options(
+    convert_markdown(
         &content.data,
-        &markdown_options(allow_dangerous_html),
-    ) {
-        Ok(html) => html,
-        Err(e) => {
-            error!("Markdown parsing failed: {}", e);
-            return Err(ContentError::MarkdownParsingFailed {
-                path: path.to_path_buf(),
-                message: e.to_string(),
-            });
-        }
+        path,
+        highlighting_enabled,
+        theme,
+        allow_dangerous_html,
+        header_uri_fragment,
+    )
+}
+
+/// Markdown to HTML conversion behind [`convert_content_with_highlighting`].
+fn convert_markdown(
+    markdown: &str,
+    path: &Path,
+    highlighting_enabled: bool,
+    theme: &str,
+    allow_dangerous_html: bool,
+    header_uri_fragment: bool,
+) -> Result<String, ContentError> {
+    // The excerpt marker only splits the content, it is not part of the page
+    let markdown = match find_marker_line(markdown, MORE_MARKER) {
+        Some(marker) => format!("{}{}", &markdown[..marker.start], &markdown[marker.end..]),
+        None => markdown.to_string(),
     };
 
-    // Add header anchor links if enabled
-    if header_uri_fragment {
-        html = add_header_anchors(&html);
-    }
+    // Convert markdown to HTML
+    let mut html =
+        match markdown::to_html_with_options(&markdown, &markdown_options(allow_dangerous_html)) {
+            Ok(html) => html,
+            Err(e) => {
+                error!("Markdown parsing failed: {}", e);
+                return Err(ContentError::MarkdownParsingFailed {
+                    path: path.to_path_buf(),
+                    message: e.to_string(),
+                });
+            }
+        };
+
+    // Give headers ids for the table of contents, with anchor links if enabled
+    html = if header_uri_fragment {
+        add_header_anchors(&html)
+    } else {
+        add_header_ids(&html)
+    };
 
     // Apply syntax highlighting if enabled
     if highlighting_enabled {
@@ -355,23 +911,93 @@ pub(crate) fn get_excerpt_html(
             .unwrap_or(content_after_summary.len());
 
         let excerpt_markdown = content_after_summary[..end_idx].trim();
-
-        // Convert the excerpt markdown to HTML with better error handling
-        match markdown::to_html_with_options(
-            excerpt_markdown,
-            &markdown_options(allow_dangerous_html),
-        ) {
-            Ok(html) => html,
-            Err(e) => {
-                tracing::warn!("Failed to convert excerpt to HTML: {}", e);
-                String::new()
-            }
-        }
+        excerpt_markdown_to_html(excerpt_markdown, allow_dangerous_html)
     } else {
         String::new() // Return empty string if no summary found
     }
 }
 
+/// Converts excerpt markdown to HTML, logging failures and returning "" instead.
+fn excerpt_markdown_to_html(markdown: &str, allow_dangerous_html: bool) -> String {
+    match markdown::to_html_with_options(markdown, &markdown_options(allow_dangerous_html)) {
+        Ok(html) => html,
+        Err(e) => {
+            tracing::warn!("Failed to convert excerpt to HTML: {}", e);
+            String::new()
+        }
+    }
+}
+
+/// Marker ending a page's excerpt (see [`excerpt_html`]); it is dropped from the page.
+pub(crate) const MORE_MARKER: &str = "<!-- more -->";
+
+/// Picks the HTML excerpt of a page for indexes and the RSS feed.
+///
+/// In order: the `summary` metadata field, the markdown before [`MORE_MARKER`]
+/// (without a leading `# Title`), the section under `excerpt.heading` (see
+/// [`get_excerpt_html`]), then `excerpt.fallback` applied to the page's rendered
+/// `html`.
+pub(crate) fn excerpt_html(
+    content: &Content,
+    html: &str,
+    excerpt: &ExcerptConfig,
+    allow_dangerous_html: bool,
+) -> String {
+    if let Some(summary) = &content.meta.summary {
+        return excerpt_markdown_to_html(summary, allow_dangerous_html);
+    }
+
+    if let Some(marker) = find_marker_line(&content.data, MORE_MARKER) {
+        let before = &content.data[..marker.start];
+        return excerpt_markdown_to_html(strip_title(before).trim(), allow_dangerous_html);
+    }
+
+    if !excerpt.heading.is_empty() {
+        let section = get_excerpt_html(&content.data, &excerpt.heading, allow_dangerous_html);
+        if !section.is_empty() {
+            return section;
+        }
+    }
+
+    match excerpt.fallback {
+        ExcerptFallback::Paragraph => paragraphs(html).next().unwrap_or_default().to_string(),
+        ExcerptFallback::Words => {
+            // Only as many paragraphs as the word limit needs
+            let mut words = 0;
+            let leading: Vec<&str> = paragraphs(html)
+                .take_while(|p| {
+                    let more = words < excerpt.words;
+                    words += count_text_words(&html_to_text(p));
+                    more
+                })
+                .collect();
+            truncate_html(&leading.join("\n"), excerpt.words)
+        }
+        ExcerptFallback::None => String::new(),
+    }
+}
+
+/// Drops a leading `# Title` line, which repeats the page title in excerpts.
+fn strip_title(markdown: &str) -> &str {
+    let trimmed = markdown.trim_start();
+    match trimmed.strip_prefix("# ") {
+        Some(rest) => rest.split_once('\n').map_or("", |(_, body)| body),
+        None => trimmed,
+    }
+}
+
+/// The `<p>` elements of rendered markdown, in order.
+fn paragraphs(html: &str) -> impl Iterator<Item = &str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find("<p>")?;
+        let end = rest[start..].find("</p>")? + start + "</p>".len();
+        let paragraph = &rest[start..end];
+        rest = &rest[end..];
+        Some(paragraph)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,6 +1011,7 @@ mod tests {
         ContentMeta {
             title: "Test Post".to_string(),
             date: datetime!(2023-12-15 10:30:00 +5), // UTC+5
+            updated: None,
             author: "Test Author".to_string(),
             tags: vec!["rust".to_string(), "testing".to_string()],
             template: Some("custom.html".to_string()),
@@ -392,6 +1019,11 @@ mod tests {
             extra: HashMap::new(),
             extra_js: vec![],
             draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
         }
     }
 
@@ -500,7 +1132,7 @@ Should not be included.
         );
         assert!(result.is_ok());
         let html = result.unwrap();
-        assert!(html.contains("<h1>Hello World</h1>"));
+        assert!(html.contains("<h1 id=\"hello-world\">Hello World</h1>"));
         // Should have highlighted the code block
         assert!(html.contains("fn"));
         assert!(html.contains("main"));
@@ -524,7 +1156,7 @@ Should not be included.
         );
         assert!(result.is_ok());
         let html = result.unwrap();
-        assert!(html.contains("<h1>Hello World</h1>"));
+        assert!(html.contains("<h1 id=\"hello-world\">Hello World</h1>"));
         // Should have plain code block without highlighting
         assert!(html.contains("<pre><code"));
         // Should not have inline styles from highlighting
@@ -577,7 +1209,8 @@ Should not be included.
             result.err()
         );
 
-        let meta = result.unwrap();
+        let table = result.unwrap().expect("sidecar table");
+        let meta = parse_metadata(table, &meta_path, UtcOffset::UTC).unwrap();
         assert_eq!(meta.title, "Test Post");
         assert_eq!(meta.author, "Test Author");
         assert_eq!(meta.tags, vec!["rust", "testing"]);
@@ -593,14 +1226,9 @@ Should not be included.
         let temp_dir = tempdir().unwrap();
         let md_path = temp_dir.path().join("nonexistent.md");
 
+        // No sidecar is not an error: the metadata is derived instead
         let result = load_metadata(&md_path);
-        assert!(result.is_err());
-
-        if let Err(ContentError::Io { path, source: _ }) = result {
-            assert!(path.ends_with("nonexistent.meta.toml"));
-        } else {
-            panic!("Expected Io error");
-        }
+        assert!(matches!(result, Ok(None)));
     }
 
     #[test]
@@ -618,13 +1246,173 @@ Should not be included.
         let result = load_metadata(&md_path);
         assert!(result.is_err());
 
-        if let Err(ContentError::TomlParse { path, source: _ }) = result {
+        if let Err(ContentError::TomlParse { path, .. }) = result {
             assert!(path.ends_with("test.meta.toml"));
         } else {
             panic!("Expected TomlParse error");
         }
     }
 
+    #[test]
+    fn test_parse_date_lenient_forms() {
+        let plus_two = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let cases = [
+            (r#""2025-01-07""#, datetime!(2025-01-07 00:00:00 +2)),
+            (
+                r#""2025-01-07T10:30:00""#,
+                datetime!(2025-01-07 10:30:00 +2),
+            ),
+            (r#""2025-01-07 10:30""#, datetime!(2025-01-07 10:30:00 +2)),
+            (
+                r#""2025-01-07T10:30:00Z""#,
+                datetime!(2025-01-07 10:30:00 UTC),
+            ),
+            (
+                r#""2025-01-07T10:30:00-05:00""#,
+                datetime!(2025-01-07 10:30:00 -5),
+            ),
+            ("2025-01-07", datetime!(2025-01-07 00:00:00 +2)),
+            ("2025-01-07T10:30:00", datetime!(2025-01-07 10:30:00 +2)),
+            (
+                "2025-01-07T10:30:00+01:00",
+                datetime!(2025-01-07 10:30:00 +1),
+            ),
+        ];
+
+        for (literal, expected) in cases {
+            let table: toml::Table = toml::from_str(&format!("date = {literal}")).unwrap();
+            let parsed = parse_date(&table["date"], plus_two).unwrap();
+            assert_eq!(parsed, expected, "parsing {literal}");
+            assert_eq!(parsed.offset(), expected.offset(), "offset of {literal}");
+        }
+    }
+
+    #[test]
+    fn test_parse_date_rejects_invalid_values() {
+        for literal in [r#""yesterday""#, r#""2025-02-30""#, "10:30:00", "20250107"] {
+            let table: toml::Table = toml::from_str(&format!("date = {literal}")).unwrap();
+            assert!(
+                parse_date(&table["date"], UtcOffset::UTC).is_err(),
+                "accepted {literal}"
+            );
+        }
+    }
+
+    fn test_defaults() -> MetaDefaults {
+        MetaDefaults {
+            author: "Site Author".to_string(),
+            timezone: UtcOffset::UTC,
+        }
+    }
+
+    fn parse(source: &str, timezone: UtcOffset) -> Result<ContentMeta, ContentError> {
+        let path = Path::new("post.meta.toml");
+        parse_metadata(parse_table(source, path)?, path, timezone)
+    }
+
+    #[test]
+    fn test_parse_metadata_plain_date_uses_timezone() {
+        let source = "title = \"T\"\ndate = \"2025-01-07\"\nauthor = \"A\"\ntags = []\n";
+        let timezone = UtcOffset::from_hms(-5, 0, 0).unwrap();
+
+        let meta = parse(source, timezone).unwrap();
+        assert_eq!(meta.date, datetime!(2025-01-07 00:00:00 -5));
+    }
+
+    #[test]
+    fn test_parse_metadata_schedule_dates() {
+        let source = "title = \"T\"\ndate = 2025-01-07\nauthor = \"A\"\n\
+                      publish_date = \"2025-01-08 09:30\"\nexpiry_date = 2025-02-01T00:00:00Z\n";
+        let timezone = UtcOffset::from_hms(2, 0, 0).unwrap();
+
+        let meta = parse(source, timezone).unwrap();
+        assert_eq!(meta.publish_date, Some(datetime!(2025-01-08 09:30:00 +2)));
+        assert_eq!(meta.expiry_date, Some(datetime!(2025-02-01 00:00:00 UTC)));
+
+        let meta = parse(
+            "title = \"T\"\ndate = 2025-01-07\nauthor = \"A\"\n",
+            timezone,
+        )
+        .unwrap();
+        assert_eq!((meta.publish_date, meta.expiry_date), (None, None));
+
+        let error = parse(
+            &format!(
+                "{}expiry_date = \"soon\"\n",
+                "title = \"T\"\ndate = 2025-01-07\nauthor = \"A\"\n"
+            ),
+            timezone,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContentError::TomlParse { field: Some(ref f), .. } if f == "expiry_date")
+        );
+    }
+
+    #[test]
+    fn test_parse_metadata_updated() {
+        let source = "title = \"T\"\ndate = 2025-01-07\nauthor = \"A\"\nupdated = \"2025-03-02\"\n";
+        let timezone = UtcOffset::from_hms(2, 0, 0).unwrap();
+
+        let meta = parse(source, timezone).unwrap();
+        assert_eq!(meta.updated, Some(datetime!(2025-03-02 00:00:00 +2)));
+        assert_eq!(meta.last_modified(), datetime!(2025-03-02 00:00:00 +2));
+
+        let meta = parse(
+            "title = \"T\"\ndate = 2025-01-07\nauthor = \"A\"\n",
+            timezone,
+        )
+        .unwrap();
+        assert_eq!(meta.updated, None);
+        assert_eq!(meta.last_modified(), meta.date);
+    }
+
+    #[test]
+    fn test_parse_metadata_error_names_field() {
+        let cases = [
+            // Unparseable date
+            (
+                "title = \"T\"\ndate = \"next week\"\nauthor = \"A\"\ntags = []\n",
+                "date",
+                "2025-01-07",
+            ),
+            // Wrong type
+            (
+                "title = \"T\"\ndate = \"2025-01-07\"\nauthor = \"A\"\ntags = \"rust\"\n",
+                "tags",
+                "[\"rust\", \"web\"]",
+            ),
+            // Missing field
+            (
+                "title = \"T\"\ndate = \"2025-01-07\"\ntags = []\n",
+                "author",
+                "author = \"Jane Doe\"",
+            ),
+            // Invalid TOML
+            (
+                "title = \"T\"\ndate = 2025-13-45\nauthor = \"A\"\ntags = []\n",
+                "date",
+                "2025-01-07T10:00:00+02:00",
+            ),
+        ];
+
+        for (source, expected_field, example) in cases {
+            let error = parse(source, UtcOffset::UTC).unwrap_err();
+            let message = error.to_string();
+            match error {
+                ContentError::TomlParse { field, .. } => {
+                    assert_eq!(field.as_deref(), Some(expected_field), "{message}")
+                }
+                other => panic!("Expected TomlParse error, got {:?}", other),
+            }
+            assert!(
+                message.contains(&format!("in field `{expected_field}`")),
+                "{message}"
+            );
+            assert!(message.contains(example), "{message}");
+        }
+    }
+
     #[test]
     fn test_load_content_success() {
         let temp_dir = tempdir().unwrap();
@@ -650,7 +1438,7 @@ Should not be included.
             .write_all(b"# Test Content\n\nThis is test content.")
             .unwrap();
 
-        let result = load_content(&md_path);
+        let result = load_content(&md_path, &test_defaults());
         assert!(result.is_ok(), "Failed to load content: {:?}", result.err());
 
         let content = result.unwrap();
@@ -662,6 +1450,206 @@ Should not be included.
         assert_eq!(content.meta.date, expected_date);
     }
 
+    #[test]
+    fn test_load_content_front_matter() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("test.md");
+        let text = "+++\ntitle = \"Inline\"\ndate = \"2023-12-15T10:30:00Z\"\nauthor = \"A\"\ntags = [\"rust\"]\n+++\n# Body\n";
+        fs::write(&md_path, text).unwrap();
+
+        let content = load_content(&md_path, &test_defaults()).unwrap();
+        assert_eq!(content.meta.title, "Inline");
+        assert_eq!(content.meta.tags, vec!["rust"]);
+        assert_eq!(content.data, "# Body\n");
+    }
+
+    #[test]
+    fn test_load_section_index() {
+        let temp_dir = tempdir().unwrap();
+        let site = crate::config::Config::from_str(
+            "[site]\ntitle = \"T\"\ntagline = \"T\"\ndomain = \"example.com\"\nauthor = \"A\"\noutput_dir = \"o\"\ncontent_dir = \"c\"\ntemplate_dir = \"t\"\nstatic_dir = \"s\"\nsite_index_template = \"i.html\"\nsyntax_highlighting_enabled = false\n",
+        )
+        .unwrap()
+        .site;
+
+        // Without metadata the title comes from the first heading
+        let path = temp_dir.path().join("_index.md");
+        fs::write(&path, "# Guides\n\nStart *here*.\n").unwrap();
+        let index = load_section_index(&path, &site).unwrap();
+        assert_eq!(index.title.as_deref(), Some("Guides"));
+        assert!(index.html.contains("<em>here</em>"));
+        assert!(index.extra.is_empty());
+
+        fs::write(
+            &path,
+            "+++\ntitle = \"All Guides\"\n[extra]\norder = 2\n+++\n# Guides\n",
+        )
+        .unwrap();
+        let index = load_section_index(&path, &site).unwrap();
+        assert_eq!(index.title.as_deref(), Some("All Guides"));
+        assert_eq!(index.extra["order"].as_integer(), Some(2));
+
+        fs::write(&path, "+++\ntitle = 3\n+++\n").unwrap();
+        assert!(matches!(
+            load_section_index(&path, &site),
+            Err(ContentError::TomlParse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_content_front_matter_matching_sidecar() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("test.md");
+        let meta = "title = \"Same\"\ndate = \"2023-12-15T10:30:00Z\"\nauthor = \"A\"\ntags = []\n";
+        fs::write(&md_path, format!("+++\r\n{meta}+++\r\nBody")).unwrap();
+        // Formatting differences are not a conflict, only differing values are
+        fs::write(
+            md_path.with_extension("meta.toml"),
+            format!("# comment\n{meta}"),
+        )
+        .unwrap();
+
+        let content = load_content(&md_path, &test_defaults()).unwrap();
+        assert_eq!(content.meta.title, "Same");
+        assert_eq!(content.data, "Body");
+    }
+
+    #[test]
+    fn test_load_content_front_matter_conflicts_with_sidecar() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("test.md");
+        let meta = "date = \"2023-12-15T10:30:00Z\"\nauthor = \"A\"\ntags = []\n";
+        fs::write(&md_path, format!("+++\ntitle = \"One\"\n{meta}+++\nBody")).unwrap();
+        fs::write(
+            md_path.with_extension("meta.toml"),
+            format!("title = \"Two\"\n{meta}"),
+        )
+        .unwrap();
+
+        match load_content(&md_path, &test_defaults()) {
+            Err(ContentError::MetadataConflict { path, meta_path }) => {
+                assert_eq!(path, md_path);
+                assert!(meta_path.ends_with("test.meta.toml"));
+            }
+            other => panic!("Expected MetadataConflict, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_content_unterminated_front_matter() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("test.md");
+        fs::write(&md_path, "+++\ntitle = \"Open\"\n# Body").unwrap();
+
+        assert!(matches!(
+            load_content(&md_path, &test_defaults()),
+            Err(ContentError::UnterminatedFrontMatter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_split_front_matter_requires_opening_line() {
+        let path = Path::new("post.md");
+        assert!(
+            split_front_matter("# Title\n+++\n", path)
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            split_front_matter("+++ not a delimiter\n", path)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            split_front_matter("+++\na = 1\n+++", path).unwrap(),
+            Some(("a = 1\n", ""))
+        );
+    }
+
+    #[test]
+    fn test_first_heading() {
+        assert_eq!(
+            first_heading("Intro\n\n# Hello *World* #\n\n# Second"),
+            Some("Hello *World*".to_string())
+        );
+        assert_eq!(
+            first_heading("```\n# comment\n```\n    # indented\n## Sub\n#NoSpace\n# Real"),
+            Some("Real".to_string())
+        );
+        assert_eq!(first_heading("## Only a subheading"), None);
+    }
+
+    #[test]
+    fn test_load_content_derives_missing_metadata() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("2024-02-03-derived.md");
+        fs::write(&md_path, "# Derived Title\n\nBody").unwrap();
+        let defaults = MetaDefaults {
+            author: "Site Author".to_string(),
+            timezone: UtcOffset::from_hms(1, 0, 0).unwrap(),
+        };
+
+        let content = load_content(&md_path, &defaults).unwrap();
+        assert_eq!(content.meta.title, "Derived Title");
+        assert_eq!(content.meta.date, datetime!(2024-02-03 00:00:00 +1));
+        assert_eq!(content.meta.author, "Site Author");
+        assert!(content.meta.tags.is_empty());
+    }
+
+    #[test]
+    fn test_load_content_derives_bundle_date_from_directory() {
+        let temp_dir = tempdir().unwrap();
+        let bundle = temp_dir.path().join("2024-05-06-my-trip");
+        fs::create_dir(&bundle).unwrap();
+        let md_path = bundle.join("index.md");
+        fs::write(&md_path, "# My Trip").unwrap();
+
+        let content = load_content(&md_path, &test_defaults()).unwrap();
+        assert_eq!(content.meta.date, datetime!(2024-05-06 00:00:00 UTC));
+    }
+
+    #[test]
+    fn test_load_content_explicit_metadata_wins_over_derived() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("2024-02-03-post.md");
+        fs::write(
+            &md_path,
+            "+++\ntitle = \"Explicit\"\nauthor = \"Guest\"\n+++\n# Heading",
+        )
+        .unwrap();
+
+        let content = load_content(&md_path, &test_defaults()).unwrap();
+        assert_eq!(content.meta.title, "Explicit");
+        assert_eq!(content.meta.author, "Guest");
+        assert_eq!(content.meta.date, datetime!(2024-02-03 00:00:00 UTC));
+    }
+
+    #[test]
+    fn test_load_content_underivable_fields() {
+        let temp_dir = tempdir().unwrap();
+        let md_path = temp_dir.path().join("2024-02-03-post.md");
+        fs::write(&md_path, "No heading here").unwrap();
+
+        let error = load_content(&md_path, &test_defaults()).unwrap_err();
+        assert!(
+            matches!(&error, ContentError::MissingField { field: "title", path, .. } if *path == md_path)
+        );
+        assert!(error.to_string().contains("2024-02-03-post.md"));
+
+        fs::write(&md_path, "# Heading").unwrap();
+        let defaults = MetaDefaults {
+            author: String::new(),
+            timezone: UtcOffset::UTC,
+        };
+        assert!(matches!(
+            load_content(&md_path, &defaults),
+            Err(ContentError::MissingField {
+                field: "author",
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_load_content_metadata_file_not_found() {
         let temp_dir = tempdir().unwrap();
@@ -673,13 +1661,15 @@ Should not be included.
             .write_all(b"# Test Content")
             .unwrap();
 
-        let result = load_content(&md_path);
+        // The title is derived from the heading, but "test.md" has no date prefix
+        let result = load_content(&md_path, &test_defaults());
         assert!(result.is_err());
 
-        if let Err(ContentError::Io { path, source: _ }) = result {
-            assert!(path.ends_with("test.meta.toml"));
+        if let Err(ContentError::MissingField { path, field, .. }) = result {
+            assert_eq!(path, md_path);
+            assert_eq!(field, "date");
         } else {
-            panic!("Expected Io error for metadata file");
+            panic!("Expected MissingField error for date, got {:?}", result);
         }
     }
 
@@ -762,12 +1752,44 @@ Should not be included.
 
         let meta: ContentMeta = toml::from_str(meta_content).unwrap();
         assert_eq!(
-            meta.extra.get("custom_field"),
-            Some(&"custom value".to_string())
+            meta.extra.get("custom_field").and_then(|v| v.as_str()),
+            Some("custom value")
         );
         assert_eq!(
-            meta.extra.get("another_field"),
-            Some(&"another value".to_string())
+            meta.extra.get("another_field").and_then(|v| v.as_str()),
+            Some("another value")
+        );
+    }
+
+    #[test]
+    fn test_content_meta_with_typed_extra_fields() {
+        let meta_content = r#"
+    title = "Test Post"
+    date = "2023-12-15T10:30:00+05:00"
+    author = "Test Author"
+    tags = ["rust"]
+
+    [extra]
+    featured = true
+    rating = 4.5
+    views = 1200
+    coauthors = ["Ada", "Grace"]
+
+    [extra.series]
+    name = "Rust Basics"
+    part = 2
+    "#;
+
+        let meta: ContentMeta = toml::from_str(meta_content).unwrap();
+        assert_eq!(meta.extra["featured"].as_bool(), Some(true));
+        assert_eq!(meta.extra["rating"].as_float(), Some(4.5));
+        assert_eq!(meta.extra["views"].as_integer(), Some(1200));
+        assert_eq!(meta.extra["coauthors"].as_array().map(|a| a.len()), Some(2));
+        assert_eq!(
+            meta.extra["series"]
+                .get("part")
+                .and_then(|v| v.as_integer()),
+            Some(2)
         );
     }
 
@@ -788,10 +1810,13 @@ Should not be included.
         let meta: ContentMeta = toml::from_str(meta_content).unwrap();
         assert_eq!(meta.cover, Some("/images/cover.png".to_string()));
         assert_eq!(
-            meta.extra.get("subtitle"),
-            Some(&"A great subtitle".to_string())
+            meta.extra.get("subtitle").and_then(|v| v.as_str()),
+            Some("A great subtitle")
+        );
+        assert_eq!(
+            meta.extra.get("category").and_then(|v| v.as_str()),
+            Some("tutorials")
         );
-        assert_eq!(meta.extra.get("category"), Some(&"tutorials".to_string()));
         // Ensure known fields are not in extra
         assert_eq!(meta.extra.get("title"), None);
         assert_eq!(meta.extra.get("cover"), None);
@@ -844,7 +1869,10 @@ Should not be included.
         assert_eq!(meta.extra_js.len(), 1);
         assert_eq!(meta.extra_js[0], "static/js/viz.js");
         assert_eq!(meta.cover, Some("/images/cover.png".to_string()));
-        assert_eq!(meta.extra.get("reading_time"), Some(&"5 min".to_string()));
+        assert_eq!(
+            meta.extra.get("reading_time").and_then(|v| v.as_str()),
+            Some("5 min")
+        );
     }
 
     #[test]
@@ -887,7 +1915,7 @@ Main content.
             std::fs::remove_file(&md_path).unwrap();
         }
 
-        let result = load_content(&md_path);
+        let result = load_content(&md_path, &test_defaults());
         assert!(result.is_err());
 
         if let Err(ContentError::Io { path, source: _ }) = result {
@@ -1024,9 +2052,9 @@ This continues until the end of the string.
         assert!(result.is_ok());
         let html = result.unwrap();
 
-        // Headers should NOT have id attributes when disabled
-        assert!(!html.contains("id=\"main-title\""));
-        assert!(html.contains("<h1>Main Title</h1>"));
+        // Headers keep their id for the table of contents, without anchor links
+        assert!(!html.contains("href=\"#main-title\""));
+        assert!(html.contains("<h1 id=\"main-title\">Main Title</h1>"));
     }
 
     #[test]
@@ -1069,4 +2097,201 @@ This continues until the end of the string.
         let meta: ContentMeta = toml::from_str(meta_content).unwrap();
         assert!(!meta.draft, "draft should be false when explicitly set");
     }
+
+    fn sorted_titles(
+        mut metas: Vec<ContentMeta>,
+        field: &str,
+        direction: SortOrder,
+    ) -> Vec<String> {
+        let order = ListingOrder {
+            field: field.to_string(),
+            direction,
+        };
+        metas.sort_by(|a, b| compare_meta(a, b, &order));
+        metas.into_iter().map(|m| m.title).collect()
+    }
+
+    fn meta_titled(title: &str, weight: Option<i64>) -> ContentMeta {
+        ContentMeta {
+            title: title.to_string(),
+            weight,
+            ..create_test_metadata()
+        }
+    }
+
+    #[test]
+    fn test_compare_meta_by_weight_puts_unweighted_last() {
+        let metas = vec![
+            meta_titled("Unweighted", None),
+            meta_titled("Second", Some(20)),
+            meta_titled("First", Some(-5)),
+        ];
+        assert_eq!(
+            sorted_titles(metas.clone(), "weight", SortOrder::Asc),
+            vec!["First", "Second", "Unweighted"]
+        );
+        assert_eq!(
+            sorted_titles(metas, "weight", SortOrder::Desc),
+            vec!["Second", "First", "Unweighted"]
+        );
+    }
+
+    #[test]
+    fn test_compare_meta_by_title_ignores_case() {
+        let metas = vec![
+            meta_titled("beta", None),
+            meta_titled("Alpha", None),
+            meta_titled("Gamma", None),
+        ];
+        assert_eq!(
+            sorted_titles(metas, "title", SortOrder::Desc),
+            vec!["Gamma", "beta", "Alpha"]
+        );
+    }
+
+    #[test]
+    fn test_compare_meta_by_extra_key() {
+        let with_order = |title: &str, order: toml::Value| {
+            let mut meta = meta_titled(title, None);
+            meta.extra.insert("order".to_string(), order);
+            meta
+        };
+        let metas = vec![
+            with_order("Ten", toml::Value::Integer(10)),
+            meta_titled("Missing", None),
+            with_order("Two and a half", toml::Value::Float(2.5)),
+        ];
+        assert_eq!(
+            sorted_titles(metas, "order", SortOrder::Asc),
+            vec!["Two and a half", "Ten", "Missing"]
+        );
+    }
+
+    #[test]
+    fn test_compare_meta_ties_fall_back_to_newest_first() {
+        let mut older = meta_titled("Older", Some(1));
+        older.date = datetime!(2020-01-01 0:00 UTC);
+        let newer = meta_titled("Newer", Some(1));
+        assert_eq!(
+            sorted_titles(vec![older, newer], "weight", SortOrder::Asc),
+            vec!["Newer", "Older"]
+        );
+    }
+
+    fn excerpt_of(data: &str, summary: Option<&str>, excerpt: &ExcerptConfig) -> String {
+        let content = Content {
+            meta: ContentMeta {
+                summary: summary.map(str::to_string),
+                ..create_test_metadata()
+            },
+            data: data.to_string(),
+        };
+        let html = convert_content_with_highlighting(
+            &content,
+            Path::new("test.md"),
+            false,
+            "github_dark",
+            false,
+            false,
+        )
+        .unwrap();
+        excerpt_html(&content, &html, excerpt, false)
+    }
+
+    #[test]
+    fn test_excerpt_html_precedence() {
+        let excerpt = ExcerptConfig::default();
+        let data = "# Title\n\nIntro **text**.\n\n<!-- more -->\n\n## Context\n\nContext text.\n";
+
+        // The summary field wins over everything else
+        assert_eq!(
+            excerpt_of(data, Some("Short *summary*"), &excerpt),
+            "<p>Short <em>summary</em></p>"
+        );
+        // Then the markdown before the marker, without the title
+        assert_eq!(
+            excerpt_of(data, None, &excerpt),
+            "<p>Intro <strong>text</strong>.</p>"
+        );
+        // Then the heading section
+        let data = "# Title\n\nIntro.\n\n## Context\n\nContext text.\n\n## Next\n\nMore.";
+        assert_eq!(excerpt_of(data, None, &excerpt), "<p>Context text.</p>");
+    }
+
+    #[test]
+    fn test_excerpt_html_fallbacks() {
+        let data = "# Title\n\nFirst paragraph here.\n\nSecond paragraph has more words.";
+        let mut excerpt = ExcerptConfig::default();
+        assert_eq!(
+            excerpt_of(data, None, &excerpt),
+            "<p>First paragraph here.</p>"
+        );
+
+        excerpt.fallback = ExcerptFallback::Words;
+        excerpt.words = 5;
+        assert_eq!(
+            excerpt_of(data, None, &excerpt),
+            "<p>First paragraph here.</p>\n<p>Second paragraph…</p>"
+        );
+
+        // CJK text is cut by character, as word_count counts it
+        assert_eq!(
+            excerpt_of("静的サイト生成器です。\n\n二段落目。", None, &excerpt),
+            "<p>静的サイト…</p>"
+        );
+
+        excerpt.fallback = ExcerptFallback::None;
+        assert_eq!(excerpt_of(data, None, &excerpt), "");
+
+        // An empty heading skips the heading section
+        excerpt.heading = String::new();
+        excerpt.fallback = ExcerptFallback::Paragraph;
+        let data = "## Context\n\nContext text.\n\nOther.";
+        assert_eq!(excerpt_of(data, None, &excerpt), "<p>Context text.</p>");
+    }
+
+    #[test]
+    fn test_convert_content_drops_more_marker() {
+        let content = Content {
+            meta: create_test_metadata(),
+            data: "Intro.\n\n<!-- more -->\n\nRest.".to_string(),
+        };
+        let html = convert_content_with_highlighting(
+            &content,
+            Path::new("test.md"),
+            false,
+            "github_dark",
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(html, "<p>Intro.</p>\n<p>Rest.</p>");
+    }
+
+    #[test]
+    fn test_more_marker_inside_fence_is_code() {
+        let data = "Intro.\n\n```markdown\nTeaser\n<!-- more -->\n```\n\nRest.\n";
+        let content = Content {
+            meta: create_test_metadata(),
+            data: data.to_string(),
+        };
+        let html = convert_content_with_highlighting(
+            &content,
+            Path::new("test.md"),
+            false,
+            "github_dark",
+            false,
+            false,
+        )
+        .unwrap();
+        assert!(html.contains("Teaser\n&lt;!-- more --&gt;"));
+
+        // Not an excerpt split, so the fallback applies
+        let excerpt = ExcerptConfig::default();
+        assert_eq!(excerpt_of(data, None, &excerpt), "<p>Intro.</p>");
+
+        // A marker after the fence still splits
+        let data = format!("{}\n<!-- more -->\n\nMore.\n", data);
+        assert!(excerpt_of(&data, None, &excerpt).ends_with("<p>Rest.</p>"));
+    }
 }
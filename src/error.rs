@@ -3,7 +3,9 @@
 use std::path::PathBuf;
 use thiserror::Error;
 
-use crate::{config::ConfigError, content::ContentError, output::WriteError};
+use crate::{
+    collision::OutputCollision, config::ConfigError, content::ContentError, output::WriteError,
+};
 
 #[derive(Error, Debug)]
 pub(crate) enum RunError {
@@ -17,6 +19,19 @@ pub(crate) enum RunError {
     #[error("Failed to render template")]
     Template(#[from] minijinja::Error),
     //
+    #[error("Template {template:?} used by {page:?} does not exist in {template_dir:?}")]
+    MissingTemplate {
+        page: PathBuf,
+        template: String,
+        template_dir: String,
+    },
+    //
+    #[error("Link {link:?} in {page:?} does not point to a page (see `broken_links` in site.toml)")]
+    BrokenLink { page: PathBuf, link: String },
+    //
+    #[error("{} output files would be written by more than one source", .0.len())]
+    OutputCollisions(Vec<OutputCollision>),
+    //
     #[error("Failed to process static files")]
     Static(#[from] StaticError),
     //
@@ -9,6 +9,7 @@ use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::build::build_with_spans;
 use crate::error::RunError;
+use crate::publish::Visibility;
 
 /// Build the site with profiling and generate output in requested formats.
 ///
@@ -65,7 +66,7 @@ fn flame_chrome(config_file: &str, output_base: &str) -> Result<(), RunError> {
     info!("flame::start profiling build (chrome timeline)");
 
     // Run the build with detailed spans (drafts excluded during profiling)
-    build_with_spans(config_file, false)?;
+    build_with_spans(config_file, Visibility::default())?;
 
     // Flush the chrome layer
     drop(guard);
@@ -100,7 +101,7 @@ fn flame_folded(
     info!("flame::start profiling build (folded stacks)");
 
     // Run the build with detailed spans (drafts excluded during profiling)
-    build_with_spans(config_file, false)?;
+    build_with_spans(config_file, Visibility::default())?;
 
     // Flush the flame layer
     drop(guard);
@@ -15,8 +15,15 @@ Marie is a static site generator that converts markdown files with TOML metadata
 marie-ssg build              # Build the site
 marie-ssg build -c prod.toml # Build with custom config
 marie-ssg build --include-drafts  # Include draft content in build
-marie-ssg watch              # Watch and rebuild on changes (macOS)
+marie-ssg build --include-future  # Include posts scheduled for later
+marie-ssg build --now 2025-03-01  # Build as of a fixed time (reproducible CI builds)
+marie-ssg watch              # Watch and rebuild on changes (macOS, Linux)
 marie-ssg watch --include-drafts  # Watch mode with drafts included
+marie-ssg serve              # Serve output_dir at http://127.0.0.1:8000/
+marie-ssg serve -p 3000      # Serve on a different port
+marie-ssg serve --watch      # Serve, rebuild on changes and live reload the browser
+marie-ssg migrate            # Fold .meta.toml files into +++ front matter
+marie-ssg migrate --split    # Split front matter back out into .meta.toml files
 marie-ssg flame              # Build with profiling, output flamechart.svg
 marie-ssg flame --time       # Profile with Chrome DevTools JSON output
 marie-ssg guide              # Show this guide
@@ -28,9 +35,14 @@ marie-ssg guide              # Show this guide
 my-site/
 ├── site.toml           # Site configuration
 ├── content/            # Markdown content files
+│   ├── contact.md      # Standalone page → /contact.html
 │   ├── blog/
+│   │   ├── _index.md   # Optional section intro for the blog index
 │   │   ├── hello.md
-│   │   └── hello.meta.toml
+│   │   ├── hello.meta.toml
+│   │   └── my-trip/    # Page bundle → /blog/my-trip.html
+│   │       ├── index.md
+│   │       └── photo1.jpg
 │   └── pages/
 │       ├── about.md
 │       └── about.meta.toml
@@ -69,6 +81,14 @@ header_uri_fragment = false          # Add anchor links to headers for URL fragm
 clean_urls = false                   # Output as slug/index.html for SEO-friendly URLs (/blog/post/ instead of /blog/post.html)
 asset_hashing_enabled = false        # Hash CSS/JS files for cache busting (style.css → style.a1b2c3d4.css)
 # asset_manifest_path = "dist/asset-manifest.json"  # Export manifest to JSON (optional)
+# not_found_template = "404.html"   # Render a custom 404 page to output_dir/404.html (optional)
+# timezone = "+02:00"               # Offset for content dates without one: "UTC" (default) or "+HH:MM"
+# paginate_by = 10                  # Split the site index into pages: /, /page/2/, ... (optional)
+# page_template = "page.html"       # Template for pages directly in content_dir (default: default.html)
+# broken_links = "error"            # Fail on links to .md files that match no page (default: "warn")
+# excerpt = {{ fallback = "words", words = 40 }}  # How excerpts are picked (see Excerpts)
+# toc = {{ min_level = 2, max_level = 3 }}       # Heading levels in tables of contents (default: 2-3)
+# words_per_minute = 200            # Reading speed for reading_time_minutes (default: 200)
 
 # Files copied to output root (e.g., favicon)
 [site.root_static]
@@ -81,21 +101,31 @@ index_template = "blog_index.html"
 content_template = "post.html"
 output_naming = "date"      # Prefix output with date (YYYY-MM-DD-stem.html)
 rss_include = true          # Include in RSS feed (default: true)
+paginate_by = 10            # Split the index into pages: /blog/, /blog/page/2/, ... (optional)
+# sort_by = "date"          # Listing order: date, title, weight or an [extra] key (default: date)
+# sort_order = "desc"       # asc or desc (default: desc for date, asc otherwise)
 
 [content.pages]
 index_template = "pages_index.html"
 content_template = "page.html"
 rss_include = false         # Exclude from RSS feed
 
-# Custom variables for templates
+# Tag pages: /tags/ and /tags/<term>/ (optional)
+[taxonomies.tags]
+index_template = "tags.html"
+term_template = "tag.html"
+
+# Custom variables for templates (any TOML type)
 [dynamic]
 github_url = "https://github.com/user"
 twitter = "@username"
+posts_on_home = 5
 ```
 
 ## Content Files
 
-Each markdown file needs a companion `.meta.toml` file:
+Metadata goes in a companion `.meta.toml` file or in front matter at the top of the
+markdown file (see below). Simple pages can skip it entirely (see Derived Metadata):
 
 **content/blog/hello.md:**
 ```markdown
@@ -105,7 +135,7 @@ This is my first post.
 
 ## Context
 
-This section becomes the excerpt for RSS feeds and index pages.
+This section becomes the excerpt for RSS feeds and index pages (see Excerpts).
 
 ## Main Content
 
@@ -115,7 +145,7 @@ The rest of your article...
 **content/blog/hello.meta.toml:**
 ```toml
 title = "Hello World"
-date = "2024-01-15T10:00:00+00:00"  # RFC 3339 format
+date = "2024-01-15T10:00:00+00:00"  # RFC 3339, or just "2024-01-15"
 author = "Your Name"
 tags = ["intro", "blog"]
 template = "custom.html"             # Optional: override default template
@@ -123,23 +153,100 @@ cover = "/images/hello-cover.jpg"    # Optional: cover image for social sharing
 extra_js = ["static/js/chart.js"]    # Optional: JavaScript files for this article
 
 [extra]
-reading_time = "5 min"               # Custom fields go in [extra] section
+difficulty = "beginner"              # Custom fields go in [extra] section
 category = "tutorials"
+featured = true                      # Any TOML type: booleans, numbers, arrays, tables
+coauthors = ["Ada", "Grace"]
 ```
 
 ### Metadata Fields
 
 | Field    | Required | Description                                              |
 |----------|----------|----------------------------------------------------------|
-| title    | Yes      | Article title                                            |
-| date     | Yes      | Publication date (RFC 3339: `YYYY-MM-DDTHH:MM:SS+00:00`) |
-| author   | Yes      | Author name                                              |
-| tags     | Yes      | Array of tags (can be empty: `[]`)                       |
+| title    | Derived  | Article title (default: first `# H1` in the markdown)    |
+| date     | Derived  | Publication date (default: `YYYY-MM-DD-` filename prefix)|
+| updated  | No       | Date of the last revision (sitemap `<lastmod>`, RSS `<atom:updated>`) |
+| author   | Derived  | Author name (default: `site.author`)                     |
+| tags     | No       | Array of tags (default: `[]`)                            |
 | draft    | No       | Exclude from builds (use `--include-drafts` to include)  |
+| publish_date | No   | Exclude until this time (default: `date`), see Scheduled Publishing |
+| expiry_date  | No   | Exclude from this time on                                |
+| weight   | No       | Integer position for `sort_by = "weight"` (lower first)  |
+| summary  | No       | Markdown excerpt for indexes and RSS (see Excerpts)      |
+| toc      | No       | Insert the table of contents after the title (see Table of Contents) |
 | template | No       | Override the content type's default template             |
 | cover    | No       | Cover image URL/path for social sharing                  |
 | extra_js | No       | JavaScript files to load (array, e.g., `["js/chart.js"]`)|
-| [extra]  | No       | Custom key-value fields (access via `meta.extra.key`)    |
+| [extra]  | No       | Custom fields of any TOML type (access via `meta.extra.key`) |
+
+`date` accepts a plain date (`2024-01-15`), a local date-time (`2024-01-15T10:00:00` or
+`2024-01-15 10:00`) or RFC 3339 with an offset (`2024-01-15T10:00:00+02:00`), quoted or as a
+TOML date literal. Values without an offset are in `site.timezone` (UTC by default), and a
+plain date means midnight. Metadata errors name the offending field and show an example of a
+valid value.
+
+A page's `template` takes precedence over its content type's `content_template`. The build
+fails with an error naming the page and the template if that template does not exist in
+`template_dir`. Each `content::render` log line shows the template used, e.g.
+`content::render content/pages/about.md → output/pages/about.html [landing.html]`.
+
+### Scheduled Publishing
+
+Content goes live at its `publish_date`, or at its `date` when it has none: builds before
+that time leave it out, like a draft. Content is dropped again from its `expiry_date` on.
+Both accept the same forms as `date`.
+
+```toml
+date = "2025-03-01"
+publish_date = "2025-03-03T09:00:00+01:00"   # Merged early, live from Monday morning
+expiry_date = "2025-04-01"                   # Gone from the first build in April
+```
+
+Scheduled content appears with the first build after its time, so a nightly build
+publishes merged posts. `--include-future` builds scheduled content anyway, for previews;
+expired content stays out. `--now 2025-03-03T09:00:00Z` builds as of a fixed time instead
+of the current one, so CI builds are reproducible. `watch` and `serve --watch` take the
+same flags and check the time again on every rebuild.
+
+### Listing Order
+
+Each content type lists its pages newest first unless it sets `sort_by`. With
+`sort_by = "weight"` (or `"title"`, or the name of an `[extra]` key such as `"order"`), the
+index, `siblings`, `prev` and `next` follow that order; `sort_order = "desc"` reverses it.
+Pages without the field come last, and ties fall back to newest first. The site index,
+`all_content`, taxonomy pages and the RSS feed always stay newest first.
+
+### Derived Metadata
+
+Fields left out of the metadata are derived: `title` from the first `# Heading` of the
+markdown (outside code blocks), `date` from a `YYYY-MM-DD-` filename prefix (midnight in
+`site.timezone`), `author` from `site.author`, and `tags` as empty. A file like
+`content/blog/2024-01-15-hello.md` starting with `# Hello World` needs no metadata at all.
+Values set in the metadata always win. If a field is missing and cannot be derived, the
+build fails with a `MissingField` error naming the file and the field.
+
+### Front Matter
+
+Metadata can also live at the top of the markdown file itself, between `+++` lines:
+
+```markdown
++++
+title = "Hello World"
+date = "2024-01-15T10:00:00+00:00"
+author = "Your Name"
+tags = ["intro", "blog"]
++++
+
+# Hello World
+```
+
+Front matter takes the same fields as `.meta.toml`. Files without front matter fall back to
+their `.meta.toml` file. If a file has both and their values differ, the build fails with a
+`MetadataConflict` error naming both files.
+
+`marie-ssg migrate` moves every sidecar file into front matter (deleting the `.meta.toml`);
+`marie-ssg migrate --split` moves front matter back out. Add `--dry-run` to only log what
+would change. Files that already have both are skipped with a warning.
 
 ## Templates (Jinja2/Minijinja)
 
@@ -152,12 +259,41 @@ Templates use Jinja2 syntax via the Minijinja library.
 - `meta.title`, `meta.date`, `meta.author`, `meta.tags`
 - `config.site.title`, `config.site.author`, etc.
 - `config.dynamic.github_url`, etc.
+- `page` - This page's ContentItem (`page.filename`, `page.formatted_date`, `page.excerpt`, ...)
+- `prev`, `next` - The ContentItems listed before and after this page in its section
+  (newest first by default, so `prev` is newer and `next` is older); unset at either end
+- `siblings` - Every ContentItem of the section in listing order, including this page
+- `section` - The page's section (see Sections); unset outside configured sections
+- `toc` - The page's headings, nested (see Table of Contents)
+- `word_count`, `reading_time_minutes` - Same as `page.word_count` and `page.reading_time_minutes`
 
 **In index templates (`blog_index.html`):**
-- `contents` - List of ContentItem for this content type
+- `contents` - List of ContentItem for this section and its subsections
+- `section` - The listed section (the content root in the site index, see Sections)
 - `all_content` - List of all ContentItem across all types
 - `config` - Full site configuration
 
+**In paginated index templates** (`paginate_by` set), `contents` holds only the current
+page's items and `paginator` describes the page:
+- `paginator.current`, `paginator.total_pages`, `paginator.per_page`, `paginator.total_items`
+- `paginator.url`, `paginator.first_url`, `paginator.last_url`
+- `paginator.prev_url`, `paginator.next_url` - Unset on the first/last page
+- `paginator.contents` - The page's ContentItems (same as `contents`)
+
+```jinja
+{{% if paginator.prev_url %}}<a href="{{{{ paginator.prev_url | url }}}}">Newer</a>{{% endif %}}
+{{% if paginator.next_url %}}<a href="{{{{ paginator.next_url | url }}}}">Older</a>{{% endif %}}
+```
+
+Page 1 is always `index.html`; later pages are `page/2/index.html` with `clean_urls`, otherwise
+`page/2.html`. Every page is listed in `sitemap.xml`.
+
+**In taxonomy templates (`tags.html`, `tag.html`):**
+- `taxonomy` - Taxonomy name (e.g., "tags")
+- `terms` - Every term with `name`, `slug`, `url` and `count` (listing template only)
+- `term` - The current term and `contents` - its ContentItems (term template only)
+- `all_content`, `config` - As in index templates
+
 ### ContentItem Properties
 
 ```jinja
@@ -178,11 +314,26 @@ Templates use Jinja2 syntax via the Minijinja library.
 | `item.meta.tags`      | List of tags                                       |
 | `item.meta.cover`     | Cover image URL/path (if set)                      |
 | `item.meta.extra_js`  | JavaScript files array (iterate with for loop)     |
-| `item.meta.extra.*`   | Custom fields (e.g., `item.meta.extra.reading_time`) |
+| `item.meta.extra.*`   | Custom fields (e.g., `item.meta.extra.difficulty`) |
 | `item.formatted_date` | Human-readable date (e.g., "January 15, 2024")     |
+| `item.formatted_updated` | Human-readable `updated` date, or none without one |
 | `item.filename`       | Output path (e.g., `blog/hello/` with clean_urls)  |
 | `item.content_type`   | Content type (e.g., "blog")                        |
-| `item.excerpt`        | HTML excerpt from "## Context" section             |
+| `item.section`        | Section directory (e.g., "docs/guides")            |
+| `item.excerpt`        | HTML excerpt (see Excerpts)                        |
+| `item.excerpt_text`   | Excerpt as plain text (e.g., for meta descriptions) |
+| `item.word_count`     | Words in the markdown, without code blocks or HTML |
+| `item.reading_time_minutes` | `word_count` at `site.words_per_minute`, rounded up |
+
+`word_count` skips fenced code blocks, HTML tags and markup such as list markers and
+link targets. Each Chinese or Japanese character counts as one word, as those scripts put
+no spaces between words. Use it instead of a `wordcount` filter over `content`, which
+also counts tags and highlighted code:
+`<span>{{{{ page.reading_time_minutes }}}} min read</span>`.
+
+Values in `[extra]` and `[dynamic]` keep their TOML type, so templates can write
+`{{% if meta.extra.featured %}}` or `{{% for name in meta.extra.coauthors %}}`. TOML dates
+are passed as RFC 3339 strings.
 
 ### Filters
 
@@ -222,14 +373,143 @@ Themes: `github_dark` (default), `monokai`, and others from Autumnus.
 ### Sitemap Generation
 
 Automatically generates `sitemap.xml` with all pages when `sitemap_enabled = true`.
+A page's `<lastmod>` is its `updated` date, or else its `date`. Site, section and
+taxonomy indexes take the newest `<lastmod>` of the pages they list.
 
 ### RSS Feed Generation
 
 Generates `feed.xml` with RSS 2.0 format when `rss_enabled = true`.
 - Control per content type with `rss_include = true/false`
-- Uses "## Context" section as excerpt
+- Uses the page excerpt as `<description>` (see Excerpts)
+- `<pubDate>` is the page's `date`; pages with an `updated` date also get `<atom:updated>`
 - Set `rss_full_content = true` to include full article HTML via `<content:encoded>` (for syndication to Dev.to, Hashnode, etc.)
 
+### Taxonomies
+
+Each `[taxonomies.<name>]` table renders `/<name>/` with `index_template` and one page per
+term at `/<name>/<term>/` with `term_template`. Term URLs are slugified (`Web Dev` →
+`/tags/web-dev/`) and terms that slugify alike are merged. The `tags` taxonomy reads
+`meta.tags`; any other name reads a string or array from `[extra]` (e.g., `series`).
+Taxonomy pages are listed in `sitemap.xml`.
+
+```jinja
+{{% for term in terms %}}
+  <a href="{{{{ term.url | url }}}}">{{{{ term.name }}}} ({{{{ term.count }}}})</a>
+{{% endfor %}}
+```
+
+### Sections
+
+Every directory below `content_dir` is a section (`content/docs/guides/` is
+`docs/guides`), and pages keep their directory in the output: `docs/guides/install.md`
+renders to `/docs/guides/install.html`. A section uses its own `[content."docs/guides"]`
+table if there is one, otherwise the nearest configured parent's (here `[content.docs]`).
+A nested table only states what it changes and inherits every other setting (templates,
+`url_pattern`, `sort_by`, `paginate_by`, `excerpt`, `rss_include`, ...) from its parent:
+
+```toml
+[content."docs/api"]
+content_template = "api.html"   # Index template, sorting and pagination come from [content.docs]
+```
+
+Every section under a configured one gets an index at `/docs/guides/`, listing the pages
+of the section and its subsections. `prev`, `next` and `siblings` stay within the page's
+own section.
+
+Templates get the section as `section`, with `name`, `title`, `path`, `url` and `count`
+(pages including subsections), plus `ancestors` (outermost first), `breadcrumbs` (ancestors
+and the section itself) and `children` (direct subsections), each with the same fields.
+
+```jinja
+{{% for crumb in section.breadcrumbs %}}<a href="{{{{ crumb.url | url }}}}">{{{{ crumb.name }}}}</a> / {{% endfor %}}
+{{% for child in section.children %}}<a href="{{{{ child.url | url }}}}">{{{{ child.title }}}}</a> ({{{{ child.count }}}}){{% endfor %}}
+```
+
+An optional `_index.md` in a section's directory holds its intro copy. It is not a page:
+its rendered markdown is `section.content`, its `title` (or first `# Heading`) is
+`section.title`, and its `[extra]` table is `section.extra`. Metadata goes in front matter
+or `_index.meta.toml` and every field is optional:
+
+```markdown
++++
+title = "Writing"
+
+[extra]
+subtitle = "Notes on Rust and static sites"
++++
+
+Longer posts about things I build.
+```
+
+`content/_index.md` does the same for the site index, whose `section` is the content
+root: its `title` defaults to `site.title`, `url` is `/`, `count` covers every page and
+`children` are the top-level sections.
+
+### Standalone Pages
+
+Markdown files placed directly in `content_dir` (e.g., `content/about.md`) are standalone
+pages: they render to `/about.html` (`/about/` with `clean_urls`) with `site.page_template`,
+have the content type `page`, and are listed in `sitemap.xml` and `all_content` but in no
+section index.
+
+### Page Bundles
+
+A directory holding an `index.md` is a page bundle: the page is named after the directory
+and belongs to its parent section, so `content/blog/my-trip/index.md` renders like
+`content/blog/my-trip.md` (a `YYYY-MM-DD-` date prefix goes on the directory name). The
+other files in the directory (images, downloads, data; not markdown or `.meta.toml`) are
+copied into the page's directory, `/blog/my-trip/`, with or without `clean_urls`.
+
+Link them relative to the markdown file:
+
+```markdown
+![The beach](photo1.jpg)
+[Route](maps/route.gpx)
+```
+
+These links are rewritten to the asset's URL (`/blog/my-trip/photo1.jpg`), so they work on
+the page, in index listings and in the RSS feed.
+
+### Links Between Pages
+
+Link to another page by its markdown file, relative to the current file or to
+`content_dir` with a leading `/`. The link is rewritten to the page's URL, following
+`url_pattern` and `clean_urls`, and keeps its `#fragment`:
+
+```markdown
+[Setup notes](../blog/2025-01-07-setup.md#install)   → /blog/2025-01-07-setup.html#install
+[About](/about.md)                                  → /about/ (with clean_urls)
+[All posts](/blog/_index.md)                        → /blog/
+```
+
+A link to a markdown file that renders no page (a typo, or a draft outside
+`--include-drafts`) is logged as a warning and left as is. Set `broken_links = "error"`
+to fail the build instead.
+
+### Excerpts
+
+Index pages and the RSS feed show each page's excerpt as `item.excerpt`, taken from the
+first of:
+
+1. The `summary` metadata field, rendered as markdown
+2. Everything before a `<!-- more -->` line (without the leading `# Title`); the marker
+   itself is removed from the page, and one inside a fenced code block is left alone
+3. The section under the `## Context` heading, up to the next heading
+4. The fallback: the first paragraph (default), the first `words` words, or nothing
+
+```toml
+[site.excerpt]
+heading = "## Context"      # "" to skip the heading section
+fallback = "paragraph"      # paragraph, words or none
+words = 50                  # Limit for fallback = "words" (counted like word_count)
+
+[content.blog.excerpt]      # Replaces [site.excerpt] for this content type
+fallback = "words"
+```
+
+A cut excerpt ends with "…" and keeps its HTML well-formed. `item.excerpt_text` is the same
+excerpt as plain text, ready for `<meta name="description">`.
+
 ### Header Anchor Links
 
 When `header_uri_fragment = true`, headers (h1-h6) get anchor links for URL fragment navigation.
@@ -241,6 +521,37 @@ This enables:
 - Direct linking to sections: `https://example.com/page#my-section`
 - Clickable headers for easy link copying
 
+Without it headers still get the `id` (`<h2 id="my-section">My Section</h2>`), so
+`#my-section` links and the table of contents work either way.
+
+### Table of Contents
+
+Content templates get the page's headings as `toc`, nested by level. Each entry has
+`level`, `text`, `id` and `children`:
+
+```html
+{{% macro toc_list(entries) %}}
+<ul>
+  {{%- for entry in entries %}}
+  <li><a href="#{{{{ entry.id }}}}">{{{{ entry.text }}}}</a>{{% if entry.children %}}{{{{ toc_list(entry.children) }}}}{{% endif %}}</li>
+  {{%- endfor %}}
+</ul>
+{{% endmacro %}}
+{{% if toc %}}<nav>{{{{ toc_list(toc) }}}}</nav>{{% endif %}}
+```
+
+To put it in the body instead, write `[[toc]]` on its own line in the markdown, or set
+`toc = true` in the metadata to insert it right after the `# Title`. Both render
+`<nav class="toc">` with nested lists of links.
+
+Only `h2` and `h3` are listed by default; change that with:
+
+```toml
+[site.toc]
+min_level = 2               # Highest heading level listed (1-6)
+max_level = 4               # Deepest heading level listed (1-6)
+```
+
 ### Clean URLs
 
 When `clean_urls = true`, content is output with SEO-friendly directory structure.
@@ -284,6 +595,11 @@ url_pattern = "{{date}}-{{stem}}"  # Flexible URL pattern
 
 **Backwards Compatibility:** `output_naming = "date"` maps to `url_pattern = "{{date}}-{{stem}}"`
 
+`{{stem}}` drops the filename's date prefix, so `2024-01-01-intro.md` and `2025-01-01-intro.md`
+both map to `/blog/intro.html`. The build checks every output file (pages and bundle assets,
+indexes, taxonomy pages, redirects, static and `root_static` files, sitemap, feed and 404
+page) before writing anything, hashed assets included, and fails with a list of the sources of each file claimed more than once.
+
 Benefits:
 - Flexible URL structure with placeholders
 - Date from meta.date (not filename)
@@ -362,13 +678,69 @@ Output (`asset-manifest.json`):
 }}
 ```
 
-### Watch Mode (macOS)
+### Watch Mode (macOS, Linux)
 
 Automatically rebuilds when files change:
 ```bash
 marie-ssg watch
 ```
 
+Uses FSEvents on macOS and inotify on Linux. Rapid saves within 500ms trigger a single
+rebuild, new subdirectories are picked up automatically, and Ctrl+C stops cleanly.
+
+Each batch of changes only redoes the work it needs:
+- `static_dir` only: static files are copied, pages are left alone
+- Content or templates: pages are rebuilt, but only changed pages (and pages whose template
+  chain includes a changed template) are re-rendered; assets are not re-hashed
+- Config file, or static files with `asset_hashing_enabled`: full rebuild
+
+### Local Server
+
+Serves the built output directory over HTTP for previewing:
+```bash
+marie-ssg build && marie-ssg serve
+marie-ssg serve --host 0.0.0.0 --port 3000
+```
+
+URLs resolve the same way static hosts do:
+- `/blog/post/` serves `blog/post/index.html` (clean URLs)
+- `/blog/post` redirects to `/blog/post/` when that directory has an index.html
+- `/blog/post` serves `blog/post.html` when `clean_urls = false`
+- Files get a Content-Type based on their extension
+
+Missing pages return status 404 with `output_dir/404.html` if it exists. Set
+`not_found_template` to render one from a template; it receives `config` and `all_content`
+like the site index, with an empty `contents` list.
+
+### Live Reload
+
+`marie-ssg serve --watch` combines watch mode with the server: after every successful
+rebuild, open browser tabs refresh themselves.
+```bash
+marie-ssg serve --watch --include-drafts
+```
+
+- Changes that only touch `.css` files in `static_dir` swap stylesheets in place, without a full reload
+  (with `asset_hashing_enabled` the page reloads instead, since stylesheet URLs change)
+- Any other change reloads the page
+- The reload script is added to HTML responses by the server and is never written to `output_dir`,
+  so builds for production are unaffected
+
+### Incremental Builds
+
+`build`, `watch` and `serve --watch` keep a build cache in `.marie-cache/` (in the working
+directory). Pages whose inputs are unchanged are not converted, rendered or written again:
+- Markdown conversion is reused while the markdown and highlighting settings are unchanged
+- A page is re-rendered when its markdown, `.meta.toml`, the config file, the asset manifest,
+  or its template changes (including templates it reaches via `extends`, `include`, `import`
+  and `from`; a template name given as a variable makes the page depend on every template)
+- Pages whose templates use `prev`, `next` or `siblings` are re-rendered when any page of
+  their content type changes
+- Output files with identical content are never rewritten, so their timestamps stay stable
+
+Indexes, taxonomy pages, sitemap and feed are regenerated on every build. Delete `.marie-cache/` to force a
+full rebuild, and add it to `.gitignore`. `flame` always profiles a full build.
+
 ## Output
 
 After build, your site is in the output directory:
@@ -378,11 +750,23 @@ output/
 ├── index.html          # Site homepage
 ├── sitemap.xml         # Sitemap (if enabled)
 ├── feed.xml            # RSS feed (if enabled)
+├── tags/               # Taxonomy pages (if configured)
+│   ├── index.html
+│   └── rust/index.html
 ├── favicon.ico         # Root static files
+├── contact.html        # Standalone page from content/contact.md
 ├── static/             # Copied static assets
 ├── blog/
 │   ├── index.html      # Blog index
-│   └── 2024-01-15-hello.html
+│   ├── page/2.html     # Later index pages (with paginate_by)
+│   ├── 2024-01-15-hello.html
+│   ├── my-trip.html    # Page bundle
+│   └── my-trip/photo1.jpg
+├── docs/
+│   ├── index.html      # Section index
+│   └── guides/         # Nested section with its own index
+│       ├── index.html
+│       └── install.html
 └── pages/
     ├── index.html
     └── about.html
@@ -411,7 +795,7 @@ Open SVG in a browser, load `.folded` in speedscope, or import `.json` in Chrome
 ## Tips
 
 1. **Date prefix**: Use `output_naming = "date"` to prefix files with publication date
-2. **Excerpts**: Add a "## Context" section for RSS/index excerpts
+2. **Excerpts**: Put `<!-- more -->` after the intro, or set `summary`, for RSS/index excerpts
 3. **Custom templates**: Override per-article with `template` in metadata
 4. **Dynamic vars**: Add custom variables in `[dynamic]` for use in templates
 
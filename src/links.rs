@@ -0,0 +1,261 @@
+// src/links.rs
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use crate::LoadedContent;
+use crate::config::Config;
+use crate::utils::{get_section, percent_decode, rewrite_link_attributes};
+
+/// Site-relative URLs of rendered pages, keyed by their source markdown file.
+///
+/// Lets writers link to the markdown they see (`[see](../blog/other-post.md)`)
+/// instead of guessing the URL the page ends up at.
+#[derive(Debug)]
+pub(crate) struct LinkMap {
+    content_dir: PathBuf,
+    urls: HashMap<PathBuf, String>,
+}
+
+impl LinkMap {
+    /// Maps every loaded page to its URL, and every section `_index.md` to the
+    /// URL of its section index (`content/_index.md` to the site index).
+    pub(crate) fn new(
+        config: &Config,
+        loaded_contents: &[LoadedContent],
+        section_indexes: &[PathBuf],
+    ) -> Self {
+        let mut urls: HashMap<PathBuf, String> = loaded_contents
+            .iter()
+            .map(|loaded| {
+                (
+                    normalize(&loaded.path),
+                    page_url(&loaded.output_path, config),
+                )
+            })
+            .collect();
+
+        for file in section_indexes {
+            let section = get_section(file, &config.site.content_dir);
+            if section.is_empty() {
+                urls.insert(normalize(file), "/".to_string());
+            } else if config.section_config(&section).is_some() {
+                urls.insert(normalize(file), format!("/{}/", section));
+            }
+        }
+
+        Self {
+            content_dir: normalize(Path::new(&config.site.content_dir)),
+            urls,
+        }
+    }
+
+    /// Rewrites the links to markdown files in the HTML of the page at `source`.
+    ///
+    /// Links are relative to `source` (`../blog/other-post.md`) or to the content
+    /// directory when they start with `/` (`/blog/other-post.md`), may be
+    /// percent-encoded (`my%20post.md`) and may end in a `#fragment`. Returns the HTML and the links that match no page, which are
+    /// left as they are.
+    pub(crate) fn resolve(&self, html: &str, source: &Path) -> (String, Vec<String>) {
+        let mut broken = vec![];
+        let html = rewrite_link_attributes(html, |href| {
+            let (path, fragment) = href.split_at(href.find('#').unwrap_or(href.len()));
+            if !is_markdown_link(path) {
+                return None;
+            }
+
+            let path = percent_decode(path);
+            let target = match path.strip_prefix('/') {
+                Some(rest) => self.content_dir.join(rest),
+                None => source.parent().unwrap_or(Path::new("")).join(&path),
+            };
+            match self.urls.get(&normalize(&target)) {
+                Some(url) => Some(format!("{}{}", url, fragment)),
+                None => {
+                    broken.push(href.to_string());
+                    None
+                }
+            }
+        });
+        (html, broken)
+    }
+}
+
+/// Site-relative URL of a page (e.g., "/blog/post.html", or "/blog/post/" with
+/// `clean_urls`).
+pub(crate) fn page_url(output_path: &Path, config: &Config) -> String {
+    let relative = output_path
+        .strip_prefix(&config.site.output_dir)
+        .unwrap_or(output_path);
+    let parts: Vec<_> = relative
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+    let url = format!("/{}", parts.join("/"));
+
+    if config.site.clean_urls {
+        url.strip_suffix("index.html")
+            .map(str::to_string)
+            .unwrap_or(url)
+    } else {
+        url
+    }
+}
+
+/// Checks whether an href (without fragment) points at a local markdown file.
+fn is_markdown_link(path: &str) -> bool {
+    !path.contains("://")
+        && !path.starts_with("//")
+        && !path.starts_with("mailto:")
+        && (path.ends_with(".md") || path.ends_with(".markdown"))
+}
+
+/// Resolves `.` and `..` components without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                _ => normalized.push(".."),
+            },
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Content, ContentMeta};
+    use time::macros::datetime;
+
+    fn create_test_config(clean_urls: bool) -> Config {
+        let mut config = Config::from_str(
+            r#"
+[site]
+title = "Test Site"
+tagline = "A test tagline"
+domain = "example.com"
+author = "Test Author"
+output_dir = "output"
+content_dir = "content"
+template_dir = "templates"
+static_dir = "static"
+site_index_template = "index.html"
+
+[content.blog]
+index_template = "blog_index.html"
+content_template = "post.html"
+"#,
+        )
+        .unwrap();
+        config.site.clean_urls = clean_urls;
+        config
+    }
+
+    fn create_test_loaded_content(source: &str, output: &str) -> LoadedContent {
+        LoadedContent {
+            path: PathBuf::from(source),
+            content: Content {
+                meta: ContentMeta {
+                    title: "Test".to_string(),
+                    date: datetime!(2024-01-15 10:00:00 UTC),
+                    updated: None,
+                    author: "Test Author".to_string(),
+                    tags: vec![],
+                    template: None,
+                    cover: None,
+                    extra: HashMap::new(),
+                    extra_js: vec![],
+                    draft: false,
+                    publish_date: None,
+                    expiry_date: None,
+                    weight: None,
+                    summary: None,
+                    toc: false,
+                },
+                data: String::new(),
+            },
+            html: String::new(),
+            excerpt: String::new(),
+            content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
+            output_path: PathBuf::from(output),
+        }
+    }
+
+    #[test]
+    fn test_page_url() {
+        let config = create_test_config(false);
+        assert_eq!(
+            page_url(Path::new("output/blog/post.html"), &config),
+            "/blog/post.html"
+        );
+
+        let config = create_test_config(true);
+        assert_eq!(
+            page_url(Path::new("output/blog/post/index.html"), &config),
+            "/blog/post/"
+        );
+        assert_eq!(page_url(Path::new("output/index.html"), &config), "/");
+    }
+
+    #[test]
+    fn test_resolve_rewrites_markdown_links() {
+        let config = create_test_config(true);
+        let contents = vec![
+            create_test_loaded_content(
+                "content/blog/2025-01-07-other-post.md",
+                "output/blog/2025-01-07-other-post/index.html",
+            ),
+            create_test_loaded_content("content/about.md", "output/about/index.html"),
+            create_test_loaded_content("content/blog/my post.md", "output/blog/my-post/index.html"),
+        ];
+        let links = LinkMap::new(
+            &config,
+            &contents,
+            &[PathBuf::from("content/blog/_index.md")],
+        );
+
+        let html = concat!(
+            r#"<a href="2025-01-07-other-post.md#setup">other</a> "#,
+            r#"<a href="../about.md">about</a> "#,
+            r#"<a href="/blog/_index.md">blog</a> "#,
+            r#"<a href="my%20post.md">spaced</a> "#,
+            r#"<a href="https://example.com/readme.md">external</a> "#,
+            r#"<a href="missing.md">missing</a>"#,
+        );
+        let (html, broken) = links.resolve(html, Path::new("content/blog/first.md"));
+
+        assert_eq!(
+            html,
+            concat!(
+                r#"<a href="/blog/2025-01-07-other-post/#setup">other</a> "#,
+                r#"<a href="/about/">about</a> "#,
+                r#"<a href="/blog/">blog</a> "#,
+                r#"<a href="/blog/my-post/">spaced</a> "#,
+                r#"<a href="https://example.com/readme.md">external</a> "#,
+                r#"<a href="missing.md">missing</a>"#,
+            )
+        );
+        assert_eq!(broken, vec!["missing.md"]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize(Path::new("./content/blog/../pages/./about.md")),
+            PathBuf::from("content/pages/about.md")
+        );
+        assert_eq!(
+            normalize(Path::new("../../x.md")),
+            PathBuf::from("../../x.md")
+        );
+    }
+}
@@ -1,29 +1,45 @@
 // src/main.rs
 
 use argh::FromArgs;
+use time::OffsetDateTime;
 use tracing::error;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
 mod asset_hash;
 mod build;
+mod bundle;
+mod cache;
+mod collision;
 mod config;
 mod content;
 mod error;
 mod flame;
 mod guide;
+mod links;
+mod migrate;
 mod output;
+mod pagination;
+mod publish;
 mod redirect;
+mod reload;
 mod rss;
+mod section;
+mod serve;
 mod sitemap;
 mod syntax;
+mod taxonomy;
 mod template;
+mod template_deps;
+mod toc;
 mod utils;
 mod watch;
 
 // Re-export for other modules
 pub(crate) use build::LoadedContent;
 
+use publish::{Visibility, parse_now};
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn default_config_file() -> String {
@@ -46,6 +62,8 @@ struct Argz {
 enum SubCommand {
     Build(BuildArgs),
     Watch(WatchArgs),
+    Serve(ServeArgs),
+    Migrate(MigrateArgs),
     Guide(GuideArgs),
     Flame(FlameArgs),
 }
@@ -61,6 +79,14 @@ struct BuildArgs {
     /// include draft content in the build
     #[argh(switch)]
     include_drafts: bool,
+
+    /// include content whose publish date is in the future
+    #[argh(switch)]
+    include_future: bool,
+
+    /// build as of this time instead of now (e.g., 2025-03-01T06:00:00Z)
+    #[argh(option, from_str_fn(parse_now))]
+    now: Option<OffsetDateTime>,
 }
 
 #[derive(FromArgs, Debug)]
@@ -74,6 +100,68 @@ struct WatchArgs {
     /// include draft content in the build
     #[argh(switch)]
     include_drafts: bool,
+
+    /// include content whose publish date is in the future
+    #[argh(switch)]
+    include_future: bool,
+
+    /// build as of this time instead of now (e.g., 2025-03-01T06:00:00Z)
+    #[argh(option, from_str_fn(parse_now))]
+    now: Option<OffsetDateTime>,
+}
+
+#[derive(FromArgs, Debug)]
+#[argh(subcommand, name = "serve")]
+/// Serve the built site over local HTTP
+struct ServeArgs {
+    /// path to the config file
+    #[argh(option, short = 'c', default = "default_config_file()")]
+    config_file: String,
+
+    /// address to bind to
+    #[argh(option, default = "default_serve_host()")]
+    host: String,
+
+    /// port to listen on
+    #[argh(option, short = 'p', default = "8000")]
+    port: u16,
+
+    /// rebuild on changes and reload connected browsers
+    #[argh(switch, short = 'w')]
+    watch: bool,
+
+    /// include draft content when rebuilding (with --watch)
+    #[argh(switch)]
+    include_drafts: bool,
+
+    /// include future content when rebuilding (with --watch)
+    #[argh(switch)]
+    include_future: bool,
+
+    /// rebuild as of this time instead of now (with --watch)
+    #[argh(option, from_str_fn(parse_now))]
+    now: Option<OffsetDateTime>,
+}
+
+fn default_serve_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+#[derive(FromArgs, Debug)]
+#[argh(subcommand, name = "migrate")]
+/// Move content metadata between .meta.toml files and +++ front matter
+struct MigrateArgs {
+    /// path to the config file
+    #[argh(option, short = 'c', default = "default_config_file()")]
+    config_file: String,
+
+    /// split front matter back out into .meta.toml files
+    #[argh(switch)]
+    split: bool,
+
+    /// report what would change without writing files
+    #[argh(switch)]
+    dry_run: bool,
 }
 
 #[derive(FromArgs, Debug)]
@@ -153,13 +241,51 @@ fn main() {
 
     match argz.command {
         Some(SubCommand::Build(args)) => {
-            if let Err(e) = build::build(&args.config_file, args.include_drafts) {
+            let visibility = Visibility {
+                include_drafts: args.include_drafts,
+                include_future: args.include_future,
+                now: args.now,
+            };
+            if let Err(e) = build::build(&args.config_file, visibility) {
                 error!("{:?}", e);
                 std::process::exit(1);
             }
         }
         Some(SubCommand::Watch(args)) => {
-            if let Err(e) = watch::watch(&args.config_file, args.include_drafts) {
+            let visibility = Visibility {
+                include_drafts: args.include_drafts,
+                include_future: args.include_future,
+                now: args.now,
+            };
+            if let Err(e) = watch::watch(&args.config_file, visibility, None) {
+                error!("{:?}", e);
+                std::process::exit(1);
+            }
+        }
+        Some(SubCommand::Serve(args)) => {
+            let visibility = Visibility {
+                include_drafts: args.include_drafts,
+                include_future: args.include_future,
+                now: args.now,
+            };
+            if let Err(e) = serve::serve(
+                &args.config_file,
+                &args.host,
+                args.port,
+                args.watch,
+                visibility,
+            ) {
+                error!("{:?}", e);
+                std::process::exit(1);
+            }
+        }
+        Some(SubCommand::Migrate(args)) => {
+            let target = if args.split {
+                migrate::MigrateTarget::Sidecar
+            } else {
+                migrate::MigrateTarget::FrontMatter
+            };
+            if let Err(e) = migrate::migrate(&args.config_file, target, args.dry_run) {
                 error!("{:?}", e);
                 std::process::exit(1);
             }
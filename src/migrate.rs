@@ -0,0 +1,262 @@
+// src/migrate.rs
+
+use std::fs;
+use std::path::Path;
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::content::{ContentError, FRONT_MATTER_DELIMITER, split_front_matter};
+use crate::error::RunError;
+use crate::utils::{find_markdown_files, find_section_indexes};
+
+/// Where metadata should live after a migration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MigrateTarget {
+    /// Fold `.meta.toml` sidecar files into `+++` front matter
+    FrontMatter,
+    /// Split front matter back out into `.meta.toml` sidecar files
+    Sidecar,
+}
+
+/// Counts of files touched by a migration.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct MigrateReport {
+    pub(crate) migrated: usize,
+    pub(crate) skipped: usize,
+}
+
+/// Moves metadata of every markdown file in the site's content directory.
+pub(crate) fn migrate(
+    config_file: &str,
+    target: MigrateTarget,
+    dry_run: bool,
+) -> Result<(), RunError> {
+    let config = Config::load_from_file(config_file)?;
+    let report = migrate_dir(&config.site.content_dir, target, dry_run)?;
+
+    info!(
+        "migrate::complete {} migrated, {} skipped{}",
+        report.migrated,
+        report.skipped,
+        if dry_run { " (dry run)" } else { "" }
+    );
+    Ok(())
+}
+
+/// Migrates every markdown file under `content_dir`, section `_index.md` files included.
+///
+/// Files whose metadata is already in the requested place are left alone. Files
+/// with both front matter and a sidecar are skipped with a warning, since either
+/// direction would overwrite one of them.
+pub(crate) fn migrate_dir(
+    content_dir: &str,
+    target: MigrateTarget,
+    dry_run: bool,
+) -> Result<MigrateReport, ContentError> {
+    let mut report = MigrateReport::default();
+
+    let files = find_markdown_files(content_dir)
+        .into_iter()
+        .chain(find_section_indexes(content_dir));
+    for path in files {
+        let migrated = match target {
+            MigrateTarget::FrontMatter => fold_sidecar(&path, dry_run)?,
+            MigrateTarget::Sidecar => split_sidecar(&path, dry_run)?,
+        };
+        if migrated {
+            report.migrated += 1;
+        } else {
+            report.skipped += 1;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Moves `post.meta.toml` into front matter at the top of `post.md`.
+fn fold_sidecar(path: &Path, dry_run: bool) -> Result<bool, ContentError> {
+    let meta_path = path.with_extension("meta.toml");
+    let text = read(path)?;
+
+    if split_front_matter(&text, path)?.is_some() {
+        if meta_path.exists() {
+            warn!(
+                "migrate::skip {:?} has both front matter and {:?}",
+                path, meta_path
+            );
+        } else {
+            debug!("migrate::skip {:?} already uses front matter", path);
+        }
+        return Ok(false);
+    }
+    if !meta_path.exists() {
+        debug!("migrate::skip {:?} has no sidecar file", path);
+        return Ok(false);
+    }
+
+    let meta = read(&meta_path)?;
+    let newline = if meta.ends_with('\n') { "" } else { "\n" };
+    let folded =
+        format!("{FRONT_MATTER_DELIMITER}\n{meta}{newline}{FRONT_MATTER_DELIMITER}\n{text}");
+
+    info!("migrate::fold {:?} → {:?}", meta_path, path);
+    if !dry_run {
+        write(path, &folded)?;
+        fs::remove_file(&meta_path).map_err(|e| ContentError::Io {
+            path: meta_path.clone(),
+            source: e,
+        })?;
+    }
+    Ok(true)
+}
+
+/// Moves front matter at the top of `post.md` into `post.meta.toml`.
+fn split_sidecar(path: &Path, dry_run: bool) -> Result<bool, ContentError> {
+    let meta_path = path.with_extension("meta.toml");
+    let text = read(path)?;
+
+    let Some((front_matter, body)) = split_front_matter(&text, path)? else {
+        debug!("migrate::skip {:?} has no front matter", path);
+        return Ok(false);
+    };
+    if meta_path.exists() {
+        warn!(
+            "migrate::skip {:?} has both front matter and {:?}",
+            path, meta_path
+        );
+        return Ok(false);
+    }
+
+    info!("migrate::split {:?} → {:?}", path, meta_path);
+    if !dry_run {
+        // Sidecar first so the metadata is never only in memory
+        write(&meta_path, front_matter)?;
+        write(path, body)?;
+    }
+    Ok(true)
+}
+
+fn read(path: &Path) -> Result<String, ContentError> {
+    fs::read_to_string(path).map_err(|e| ContentError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ContentError> {
+    debug!("io::write → {:?}", path);
+    fs::write(path, contents).map_err(|e| ContentError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::MetaDefaults;
+    use crate::content::load_content;
+    use tempfile::tempdir;
+    use time::UtcOffset;
+
+    const META: &str =
+        "title = \"Post\"\ndate = \"2025-01-01T00:00:00Z\"\nauthor = \"A\"\ntags = []\n";
+    const BODY: &str = "# Post\n\nBody text.\n";
+
+    fn content_dir() -> tempfile::TempDir {
+        let temp_dir = tempdir().unwrap();
+        fs::create_dir_all(temp_dir.path().join("blog")).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_fold_moves_sidecar_into_front_matter() {
+        let dir = content_dir();
+        let md_path = dir.path().join("blog/post.md");
+        fs::write(&md_path, BODY).unwrap();
+        fs::write(md_path.with_extension("meta.toml"), META).unwrap();
+
+        let report = migrate_dir(
+            dir.path().to_str().unwrap(),
+            MigrateTarget::FrontMatter,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            report,
+            MigrateReport {
+                migrated: 1,
+                skipped: 0
+            }
+        );
+        assert!(!md_path.with_extension("meta.toml").exists());
+        assert_eq!(
+            fs::read_to_string(&md_path).unwrap(),
+            format!("+++\n{META}+++\n{BODY}")
+        );
+
+        let defaults = MetaDefaults {
+            author: "Site Author".to_string(),
+            timezone: UtcOffset::UTC,
+        };
+        let content = load_content(&md_path, &defaults).unwrap();
+        assert_eq!(content.meta.title, "Post");
+        assert_eq!(content.data, BODY);
+    }
+
+    #[test]
+    fn test_split_round_trips_fold() {
+        let dir = content_dir();
+        let md_path = dir.path().join("blog/post.md");
+        fs::write(&md_path, BODY).unwrap();
+        fs::write(md_path.with_extension("meta.toml"), META).unwrap();
+        let content_dir = dir.path().to_str().unwrap();
+
+        migrate_dir(content_dir, MigrateTarget::FrontMatter, false).unwrap();
+        let report = migrate_dir(content_dir, MigrateTarget::Sidecar, false).unwrap();
+
+        assert_eq!(report.migrated, 1);
+        assert_eq!(fs::read_to_string(&md_path).unwrap(), BODY);
+        assert_eq!(
+            fs::read_to_string(md_path.with_extension("meta.toml")).unwrap(),
+            META
+        );
+    }
+
+    #[test]
+    fn test_dry_run_changes_nothing() {
+        let dir = content_dir();
+        let md_path = dir.path().join("blog/post.md");
+        fs::write(&md_path, BODY).unwrap();
+        fs::write(md_path.with_extension("meta.toml"), META).unwrap();
+
+        let report = migrate_dir(
+            dir.path().to_str().unwrap(),
+            MigrateTarget::FrontMatter,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(report.migrated, 1);
+        assert_eq!(fs::read_to_string(&md_path).unwrap(), BODY);
+        assert!(md_path.with_extension("meta.toml").exists());
+    }
+
+    #[test]
+    fn test_files_with_both_are_skipped() {
+        let dir = content_dir();
+        let md_path = dir.path().join("blog/post.md");
+        let original = format!("+++\n{META}+++\n{BODY}");
+        fs::write(&md_path, &original).unwrap();
+        fs::write(md_path.with_extension("meta.toml"), META).unwrap();
+        let content_dir = dir.path().to_str().unwrap();
+
+        for target in [MigrateTarget::FrontMatter, MigrateTarget::Sidecar] {
+            let report = migrate_dir(content_dir, target, false).unwrap();
+            assert_eq!(report.skipped, 1);
+        }
+        assert_eq!(fs::read_to_string(&md_path).unwrap(), original);
+        assert!(md_path.with_extension("meta.toml").exists());
+    }
+}
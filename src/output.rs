@@ -73,35 +73,9 @@ pub(crate) fn copy_static_files(config: &Config) -> Result<(), StaticError> {
     let mut unchanged_count = 0usize;
 
     // Copy all files recursively, excluding root_static files
-    for entry in WalkDir::new(static_dir)
-        .into_iter()
-        .filter_map(Result::ok)
-        .filter(|e| e.file_type().is_file())
-    {
-        let source_path = entry.path();
-        let relative_path = source_path
-            .strip_prefix(static_dir)
-            .map_err(|e| StaticError::Io {
-                path: source_path.to_path_buf(),
-                source: std::io::Error::other(e),
-            })?;
-
-        // Skip files that are configured as root_static
-        let relative_path_str = relative_path.to_string_lossy();
-        if config
-            .site
-            .root_static
-            .values()
-            .any(|src| src == &*relative_path_str)
-        {
-            debug!("static::skip {:?} (root)", source_path);
-            continue;
-        }
-
-        let dest_path = output_static_dir.join(relative_path);
-
+    for (source_path, dest_path) in static_file_copies(config) {
         // Skip if file hasn't changed
-        if !should_copy_file(source_path, &dest_path) {
+        if !should_copy_file(&source_path, &dest_path) {
             debug!("static::check ✓ {:?}", source_path);
             unchanged_count += 1;
             continue;
@@ -116,7 +90,7 @@ pub(crate) fn copy_static_files(config: &Config) -> Result<(), StaticError> {
         }
 
         debug!("io::copy {:?} → {:?}", source_path, dest_path);
-        fs::copy(source_path, &dest_path).map_err(|e| StaticError::Io {
+        fs::copy(&source_path, &dest_path).map_err(|e| StaticError::Io {
             path: dest_path.clone(),
             source: e,
         })?;
@@ -144,6 +118,35 @@ pub(crate) fn copy_static_files(config: &Config) -> Result<(), StaticError> {
     Ok(())
 }
 
+/// Files of `static_dir` and where [`copy_static_files`] copies them, below
+/// `<output_dir>/static/`. `root_static` sources are left out, as they are copied
+/// to the output root instead.
+pub(crate) fn static_file_copies(config: &Config) -> Vec<(PathBuf, PathBuf)> {
+    let static_dir = Path::new(&config.site.static_dir);
+    let output_static_dir = PathBuf::from(&config.site.output_dir).join("static");
+
+    WalkDir::new(static_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|entry| {
+            let relative_path = entry.path().strip_prefix(static_dir).ok()?;
+            let relative_path_str = relative_path.to_string_lossy();
+            if config
+                .site
+                .root_static
+                .values()
+                .any(|src| src == &*relative_path_str)
+            {
+                debug!("static::skip {:?} (root)", entry.path());
+                return None;
+            }
+            let dest_path = output_static_dir.join(relative_path);
+            Some((entry.into_path(), dest_path))
+        })
+        .collect()
+}
+
 /// Copies configured root static files to the output directory root.
 /// Returns the number of files copied.
 fn copy_root_static_files(config: &Config) -> Result<usize, StaticError> {
@@ -198,6 +201,43 @@ fn copy_root_static_files(config: &Config) -> Result<usize, StaticError> {
     Ok(copied_count)
 }
 
+/// Copies a page bundle's assets (paths relative to `bundle_dir`, see
+/// [`crate::bundle::bundle_assets`]) into `dest_dir`, skipping unchanged files.
+/// Returns the number of files copied.
+pub(crate) fn copy_bundle_assets(
+    bundle_dir: &Path,
+    assets: &[String],
+    dest_dir: &Path,
+) -> Result<usize, StaticError> {
+    let mut copied_count = 0usize;
+
+    for asset in assets {
+        let source_path = bundle_dir.join(asset);
+        let dest_path = dest_dir.join(asset);
+
+        if !should_copy_file(&source_path, &dest_path) {
+            debug!("bundle::check ✓ {:?}", source_path);
+            continue;
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| StaticError::Io {
+                path: parent.to_path_buf(),
+                source: e,
+            })?;
+        }
+
+        debug!("io::copy {:?} → {:?}", source_path, dest_path);
+        fs::copy(&source_path, &dest_path).map_err(|e| StaticError::Io {
+            path: dest_path.clone(),
+            source: e,
+        })?;
+        copied_count += 1;
+    }
+
+    Ok(copied_count)
+}
+
 #[instrument(skip_all)]
 pub(crate) fn write_output_file(output_path: &Path, content: &str) -> Result<(), WriteError> {
     debug!("io::write → {:?} ({} bytes)", output_path, content.len());
@@ -209,6 +249,12 @@ pub(crate) fn write_output_file(output_path: &Path, content: &str) -> Result<(),
         })?;
     }
 
+    // Leave identical files untouched so their modification times stay stable
+    if fs::read(output_path).is_ok_and(|existing| existing == content.as_bytes()) {
+        debug!("output::unchanged {:?}", output_path);
+        return Ok(());
+    }
+
     // Write the content to the file
     fs::write(output_path, content).map_err(|e| WriteError::Io {
         path: output_path.to_path_buf(),
@@ -254,10 +300,19 @@ mod tests {
                 rss_full_content: false,
                 asset_hashing_enabled: false,
                 asset_manifest_path: None,
+                not_found_template: None,
+                timezone: None,
+                paginate_by: None,
+                page_template: None,
+                broken_links: crate::config::BrokenLinks::Warn,
+                excerpt: crate::config::ExcerptConfig::default(),
+                toc: crate::config::TocConfig::default(),
+                words_per_minute: std::num::NonZeroUsize::new(200).unwrap(),
             },
             content: HashMap::new(),
             dynamic: HashMap::new(),
             redirects: HashMap::new(),
+            taxonomies: HashMap::new(),
         }
     }
 
@@ -450,4 +505,44 @@ mod tests {
         assert!(!output_dir.join("favicon.ico").exists());
         assert!(!output_dir.join("robots.txt").exists());
     }
+
+    #[test]
+    fn test_write_output_file_skips_identical_content() {
+        let temp_dir = tempdir().unwrap();
+        let output_path = temp_dir.path().join("out/page.html");
+
+        write_output_file(&output_path, "<p>Hello</p>").unwrap();
+        let mtime_before = fs::metadata(&output_path).unwrap().modified().unwrap();
+
+        // Small delay to ensure mtime would change if file was rewritten
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        write_output_file(&output_path, "<p>Hello</p>").unwrap();
+        let mtime_after = fs::metadata(&output_path).unwrap().modified().unwrap();
+        assert_eq!(mtime_before, mtime_after);
+
+        write_output_file(&output_path, "<p>Changed</p>").unwrap();
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), "<p>Changed</p>");
+    }
+
+    #[test]
+    fn test_copy_bundle_assets_keeps_subdirectories() {
+        let temp_dir = tempdir().unwrap();
+        let bundle = temp_dir.path().join("content/blog/my-trip");
+        fs::create_dir_all(bundle.join("maps")).unwrap();
+        fs::write(bundle.join("photo1.jpg"), "jpg").unwrap();
+        fs::write(bundle.join("maps/day1.png"), "png").unwrap();
+        let dest = temp_dir.path().join("out/blog/my-trip");
+        let assets = vec!["maps/day1.png".to_string(), "photo1.jpg".to_string()];
+
+        assert_eq!(copy_bundle_assets(&bundle, &assets, &dest).unwrap(), 2);
+        assert_eq!(
+            fs::read_to_string(dest.join("maps/day1.png")).unwrap(),
+            "png"
+        );
+        assert_eq!(fs::read_to_string(dest.join("photo1.jpg")).unwrap(), "jpg");
+
+        // Unchanged assets are not copied again
+        assert_eq!(copy_bundle_assets(&bundle, &assets, &dest).unwrap(), 0);
+    }
 }
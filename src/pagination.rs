@@ -0,0 +1,175 @@
+// src/pagination.rs
+
+use serde::Serialize;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+/// Position of one index page within a paginated listing.
+///
+/// Exposed to index templates as `paginator`, together with the page's slice of
+/// `contents`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct Paginator {
+    /// Current page number, starting at 1
+    pub(crate) current: usize,
+    /// Number of pages in the listing (at least 1)
+    pub(crate) total_pages: usize,
+    /// Maximum number of items per page
+    pub(crate) per_page: usize,
+    /// Number of items across all pages
+    pub(crate) total_items: usize,
+    /// Site-relative URL of this page
+    pub(crate) url: String,
+    /// URL of the first page (the plain index)
+    pub(crate) first_url: String,
+    /// URL of the last page
+    pub(crate) last_url: String,
+    /// URL of the previous page, if any
+    pub(crate) prev_url: Option<String>,
+    /// URL of the next page, if any
+    pub(crate) next_url: Option<String>,
+}
+
+impl Paginator {
+    /// Index of the first item on this page within the full listing.
+    pub(crate) fn offset(&self) -> usize {
+        (self.current - 1) * self.per_page
+    }
+}
+
+/// Splits a listing of `total_items` into pages of `per_page` items.
+///
+/// `base` is the listing's directory below the output root (`""` for the site
+/// index, `"blog"` for a content type). An empty listing still has one page.
+///
+/// # Examples
+/// ```ignore
+/// let pages = paginate("blog", 25, NonZeroUsize::new(10).unwrap(), true);
+/// assert_eq!(pages.len(), 3);
+/// assert_eq!(pages[1].url, "/blog/page/2/");
+/// ```
+pub(crate) fn paginate(
+    base: &str,
+    total_items: usize,
+    per_page: NonZeroUsize,
+    clean_urls: bool,
+) -> Vec<Paginator> {
+    let total_pages = total_items.div_ceil(per_page.get()).max(1);
+    let url = |page| page_url(base, page, clean_urls);
+
+    (1..=total_pages)
+        .map(|current| Paginator {
+            current,
+            total_pages,
+            per_page: per_page.get(),
+            total_items,
+            url: url(current),
+            first_url: url(1),
+            last_url: url(total_pages),
+            prev_url: (current > 1).then(|| url(current - 1)),
+            next_url: (current < total_pages).then(|| url(current + 1)),
+        })
+        .collect()
+}
+
+/// Site-relative URL of page `page` of the listing at `base`.
+///
+/// Page 1 is the plain index (`/blog/`); later pages are `/blog/page/2/` with
+/// clean URLs and `/blog/page/2.html` without.
+pub(crate) fn page_url(base: &str, page: usize, clean_urls: bool) -> String {
+    let prefix = if base.is_empty() {
+        "/".to_string()
+    } else {
+        format!("/{}/", base)
+    };
+
+    match (page, clean_urls) {
+        (1, _) => prefix,
+        (_, true) => format!("{}page/{}/", prefix, page),
+        (_, false) => format!("{}page/{}.html", prefix, page),
+    }
+}
+
+/// Output path of page `page` of the listing at `base`, matching [`page_url`].
+pub(crate) fn page_output_path(
+    output_dir: &str,
+    base: &str,
+    page: usize,
+    clean_urls: bool,
+) -> PathBuf {
+    let dir = PathBuf::from(output_dir).join(base);
+
+    match (page, clean_urls) {
+        (1, _) => dir.join("index.html"),
+        (_, true) => dir.join("page").join(page.to_string()).join("index.html"),
+        (_, false) => dir.join("page").join(format!("{}.html", page)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn per_page(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_paginate_links_pages() {
+        let pages = paginate("blog", 25, per_page(10), true);
+        assert_eq!(pages.len(), 3);
+
+        assert_eq!(pages[0].url, "/blog/");
+        assert_eq!(pages[0].prev_url, None);
+        assert_eq!(pages[0].next_url.as_deref(), Some("/blog/page/2/"));
+
+        assert_eq!(pages[1].current, 2);
+        assert_eq!(pages[1].offset(), 10);
+        assert_eq!(pages[1].prev_url.as_deref(), Some("/blog/"));
+        assert_eq!(pages[1].next_url.as_deref(), Some("/blog/page/3/"));
+
+        assert_eq!(pages[2].total_pages, 3);
+        assert_eq!(pages[2].last_url, "/blog/page/3/");
+        assert_eq!(pages[2].next_url, None);
+    }
+
+    #[test]
+    fn test_paginate_empty_listing_has_one_page() {
+        let pages = paginate("blog", 0, per_page(10), false);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].total_items, 0);
+        assert_eq!(pages[0].next_url, None);
+    }
+
+    #[test]
+    fn test_paginate_exact_multiple() {
+        assert_eq!(paginate("blog", 20, per_page(10), true).len(), 2);
+    }
+
+    #[test]
+    fn test_page_url_layouts() {
+        assert_eq!(page_url("blog", 2, false), "/blog/page/2.html");
+        assert_eq!(page_url("", 1, false), "/");
+        assert_eq!(page_url("", 3, true), "/page/3/");
+    }
+
+    #[test]
+    fn test_page_output_path_layouts() {
+        assert_eq!(
+            page_output_path("output", "blog", 1, true),
+            PathBuf::from("output/blog/index.html")
+        );
+        assert_eq!(
+            page_output_path("output", "blog", 2, true),
+            PathBuf::from("output/blog/page/2/index.html")
+        );
+        assert_eq!(
+            page_output_path("output", "blog", 2, false),
+            PathBuf::from("output/blog/page/2.html")
+        );
+        assert_eq!(
+            page_output_path("output", "", 2, false),
+            PathBuf::from("output/page/2.html")
+        );
+    }
+}
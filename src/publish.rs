@@ -0,0 +1,135 @@
+// src/publish.rs
+
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::content::{ContentMeta, parse_date};
+
+/// Which content a build publishes.
+///
+/// Drafts are left out unless `include_drafts` is set. Content whose publish time
+/// (`publish_date`, or else `date`) is still ahead is left out unless
+/// `include_future` is set, so scheduled posts can be merged early and go live
+/// with the first build after their time. Content past its `expiry_date` is
+/// always left out.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Visibility {
+    /// Build drafts too (`--include-drafts`)
+    pub(crate) include_drafts: bool,
+    /// Build content scheduled for later (`--include-future`)
+    pub(crate) include_future: bool,
+    /// Time the build is made at (`--now`), or the current time when unset
+    pub(crate) now: Option<OffsetDateTime>,
+}
+
+impl Visibility {
+    /// Time publish and expiry dates are compared against.
+    ///
+    /// Without `--now` this is the time of the call, so every rebuild in watch
+    /// mode picks up content whose time has come.
+    pub(crate) fn now(&self) -> OffsetDateTime {
+        self.now.unwrap_or_else(OffsetDateTime::now_utc)
+    }
+
+    /// Checks whether content with `meta` is built at `now`.
+    pub(crate) fn includes(&self, meta: &ContentMeta, now: OffsetDateTime) -> bool {
+        if meta.draft && !self.include_drafts {
+            return false;
+        }
+        if meta.publish_date.unwrap_or(meta.date) > now && !self.include_future {
+            return false;
+        }
+        meta.expiry_date.is_none_or(|expiry| expiry > now)
+    }
+}
+
+/// Parses the `--now` option: any date form metadata accepts (see [`parse_date`]),
+/// in UTC unless it has an offset.
+pub(crate) fn parse_now(value: &str) -> Result<OffsetDateTime, String> {
+    parse_date(&toml::Value::String(value.to_string()), UtcOffset::UTC)
+        .map_err(|reason| format!("invalid --now {:?}: {}", value, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use time::macros::datetime;
+
+    fn meta(date: OffsetDateTime) -> ContentMeta {
+        ContentMeta {
+            title: "Test".to_string(),
+            date,
+            updated: None,
+            author: "Test Author".to_string(),
+            tags: vec![],
+            template: None,
+            cover: None,
+            extra: HashMap::new(),
+            extra_js: vec![],
+            draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
+        }
+    }
+
+    #[test]
+    fn test_includes_scheduled_and_expired_content() {
+        let now = datetime!(2025-03-01 12:00:00 UTC);
+        let visibility = Visibility::default();
+
+        assert!(visibility.includes(&meta(datetime!(2025-03-01 12:00:00 UTC)), now));
+        assert!(!visibility.includes(&meta(datetime!(2025-03-01 12:00:01 UTC)), now));
+
+        // An explicit publish_date wins over date
+        let scheduled = ContentMeta {
+            publish_date: Some(datetime!(2025-03-02 00:00:00 UTC)),
+            ..meta(datetime!(2025-01-01 00:00:00 UTC))
+        };
+        assert!(!visibility.includes(&scheduled, now));
+
+        let expired = ContentMeta {
+            expiry_date: Some(now),
+            ..meta(datetime!(2025-01-01 00:00:00 UTC))
+        };
+        assert!(!visibility.includes(&expired, now));
+
+        let preview = Visibility {
+            include_future: true,
+            ..Visibility::default()
+        };
+        assert!(preview.includes(&scheduled, now));
+        assert!(!preview.includes(&expired, now));
+    }
+
+    #[test]
+    fn test_includes_drafts_only_when_asked() {
+        let now = datetime!(2025-03-01 12:00:00 UTC);
+        let draft = ContentMeta {
+            draft: true,
+            ..meta(datetime!(2025-01-01 00:00:00 UTC))
+        };
+        assert!(!Visibility::default().includes(&draft, now));
+
+        let visibility = Visibility {
+            include_drafts: true,
+            ..Visibility::default()
+        };
+        assert!(visibility.includes(&draft, now));
+    }
+
+    #[test]
+    fn test_parse_now() {
+        assert_eq!(
+            parse_now("2025-03-01").unwrap(),
+            datetime!(2025-03-01 00:00:00 UTC)
+        );
+        assert_eq!(
+            parse_now("2025-03-01T06:00:00+02:00").unwrap(),
+            datetime!(2025-03-01 04:00:00 UTC)
+        );
+        assert!(parse_now("tomorrow").is_err());
+    }
+}
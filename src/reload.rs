@@ -0,0 +1,232 @@
+// src/reload.rs
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use tracing::info;
+
+use crate::utils::path_within;
+
+/// URL of the server-sent events stream browsers subscribe to
+pub(crate) const RELOAD_ENDPOINT: &str = "/__marie/reload";
+
+/// Client script injected into HTML responses by `serve --watch`.
+///
+/// Never written to disk: the server adds it while responding, so build output stays clean.
+const RELOAD_SCRIPT: &str = r#"<script>
+(() => {
+  const source = new EventSource("/__marie/reload");
+  source.onmessage = (event) => {
+    if (event.data !== "css") {
+      location.reload();
+      return;
+    }
+    for (const link of document.querySelectorAll('link[rel="stylesheet"]')) {
+      const url = new URL(link.href);
+      if (url.origin !== location.origin) continue;
+      url.searchParams.set("marie-reload", Date.now());
+      link.href = url.toString();
+    }
+  };
+})();
+</script>
+"#;
+
+/// What connected browsers should do after a rebuild.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ReloadKind {
+    /// Re-fetch stylesheets in place
+    Css,
+    /// Reload the whole page
+    Page,
+}
+
+impl ReloadKind {
+    /// Payload sent in the SSE `data:` field
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ReloadKind::Css => "css",
+            ReloadKind::Page => "page",
+        }
+    }
+}
+
+/// Fan-out point between the watch loop and every open reload stream.
+#[derive(Debug, Default)]
+pub(crate) struct ReloadHub {
+    clients: Mutex<Vec<Sender<ReloadKind>>>,
+}
+
+impl ReloadHub {
+    /// Registers a browser connection; the receiver yields one message per rebuild.
+    pub(crate) fn subscribe(&self) -> Receiver<ReloadKind> {
+        let (sender, receiver) = channel();
+        self.clients
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(sender);
+        receiver
+    }
+
+    /// Sends `kind` to all connected browsers, dropping the ones that went away.
+    /// Returns the number of browsers notified.
+    pub(crate) fn notify(&self, kind: ReloadKind) -> usize {
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients.retain(|client| client.send(kind).is_ok());
+        info!(
+            "reload::notify {} ({} clients)",
+            kind.as_str(),
+            clients.len()
+        );
+        clients.len()
+    }
+}
+
+/// Decides whether a set of changed paths can be applied as a stylesheet swap.
+///
+/// Only CSS files inside `static_dir` qualify. With asset hashing enabled the stylesheet
+/// URLs themselves change, so the page has to be reloaded to pick up the new HTML.
+pub(crate) fn classify_changes(
+    paths: &[PathBuf],
+    static_dir: &Path,
+    asset_hashing_enabled: bool,
+) -> ReloadKind {
+    if asset_hashing_enabled || paths.is_empty() {
+        return ReloadKind::Page;
+    }
+
+    let is_css = |path: &Path| {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("css"))
+    };
+
+    if paths
+        .iter()
+        .all(|p| path_within(p, static_dir) && is_css(p))
+    {
+        ReloadKind::Css
+    } else {
+        ReloadKind::Page
+    }
+}
+
+/// Inserts the reload script before `</body>`, or appends it when there is no body tag.
+pub(crate) fn inject_reload_script(html: &[u8]) -> Vec<u8> {
+    let position = html
+        .windows(b"</body>".len())
+        .rposition(|window| window.eq_ignore_ascii_case(b"</body>"))
+        .unwrap_or(html.len());
+
+    let mut injected = Vec::with_capacity(html.len() + RELOAD_SCRIPT.len());
+    injected.extend_from_slice(&html[..position]);
+    injected.extend_from_slice(RELOAD_SCRIPT.as_bytes());
+    injected.extend_from_slice(&html[position..]);
+    injected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_classify_css_in_static_dir() {
+        let paths = vec![PathBuf::from("static/css/style.css")];
+        assert_eq!(
+            classify_changes(&paths, Path::new("static"), false),
+            ReloadKind::Css
+        );
+    }
+
+    #[test]
+    fn test_classify_absolute_css_path() {
+        let temp_dir = tempdir().unwrap();
+        let static_dir = temp_dir.path().join("static");
+        fs::create_dir_all(&static_dir).unwrap();
+        let paths = vec![static_dir.canonicalize().unwrap().join("main.CSS")];
+
+        assert_eq!(
+            classify_changes(&paths, &static_dir, false),
+            ReloadKind::Css
+        );
+    }
+
+    #[test]
+    fn test_classify_mixed_changes_reload_page() {
+        let paths = vec![
+            PathBuf::from("static/css/style.css"),
+            PathBuf::from("content/blog/post.md"),
+        ];
+        assert_eq!(
+            classify_changes(&paths, Path::new("static"), false),
+            ReloadKind::Page
+        );
+    }
+
+    #[test]
+    fn test_classify_css_outside_static_reloads_page() {
+        let paths = vec![PathBuf::from("templates/inline.css")];
+        assert_eq!(
+            classify_changes(&paths, Path::new("static"), false),
+            ReloadKind::Page
+        );
+    }
+
+    #[test]
+    fn test_classify_static_js_reloads_page() {
+        let paths = vec![PathBuf::from("static/js/app.js")];
+        assert_eq!(
+            classify_changes(&paths, Path::new("static"), false),
+            ReloadKind::Page
+        );
+    }
+
+    #[test]
+    fn test_classify_css_with_asset_hashing_reloads_page() {
+        let paths = vec![PathBuf::from("static/css/style.css")];
+        assert_eq!(
+            classify_changes(&paths, Path::new("static"), true),
+            ReloadKind::Page
+        );
+    }
+
+    #[test]
+    fn test_inject_before_closing_body() {
+        let html = b"<html><body><p>Hi</p></BODY></html>";
+        let injected = String::from_utf8(inject_reload_script(html)).unwrap();
+
+        assert!(injected.starts_with("<html><body><p>Hi</p><script>"));
+        assert!(injected.ends_with("</script>\n</BODY></html>"));
+        assert!(injected.contains(RELOAD_ENDPOINT));
+    }
+
+    #[test]
+    fn test_inject_without_body_appends() {
+        let injected = String::from_utf8(inject_reload_script(b"<p>fragment</p>")).unwrap();
+
+        assert!(injected.starts_with("<p>fragment</p><script>"));
+        assert!(injected.ends_with("</script>\n"));
+    }
+
+    #[test]
+    fn test_hub_notifies_subscribers() {
+        let hub = ReloadHub::default();
+        let first = hub.subscribe();
+        let second = hub.subscribe();
+
+        assert_eq!(hub.notify(ReloadKind::Css), 2);
+        assert_eq!(first.recv().unwrap(), ReloadKind::Css);
+        assert_eq!(second.recv().unwrap(), ReloadKind::Css);
+    }
+
+    #[test]
+    fn test_hub_drops_disconnected_subscribers() {
+        let hub = ReloadHub::default();
+        let kept = hub.subscribe();
+        drop(hub.subscribe());
+
+        assert_eq!(hub.notify(ReloadKind::Page), 1);
+        assert_eq!(kept.recv().unwrap(), ReloadKind::Page);
+    }
+}
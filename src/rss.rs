@@ -5,7 +5,6 @@ use time::OffsetDateTime;
 
 use crate::LoadedContent;
 use crate::config::Config;
-use crate::content::get_excerpt_html;
 
 /// Generates an RSS 2.0 feed string for the site.
 ///
@@ -58,11 +57,11 @@ pub(crate) fn generate_rss(config: &Config, loaded_contents: &[LoadedContent]) -
     // Filter and sort content items
     let mut items: Vec<&LoadedContent> = loaded_contents
         .iter()
-        .filter(|lc| should_include_in_rss(config, &lc.content_type))
+        .filter(|lc| should_include_in_rss(config, &lc.section))
         .collect();
 
     // Sort by date descending (newest first)
-    items.sort_by(|a, b| b.content.meta.date.cmp(&a.content.meta.date));
+    items.sort_by_key(|lc| std::cmp::Reverse(lc.content.meta.date));
 
     // Channel pubDate from most recent item
     if let Some(newest) = items.first() {
@@ -90,16 +89,15 @@ pub(crate) fn generate_rss(config: &Config, loaded_contents: &[LoadedContent]) -
     xml
 }
 
-/// Checks if a content type should be included in the RSS feed.
+/// Checks if a section should be included in the RSS feed.
 ///
 /// Returns true if:
-/// - The content type is not in config (include by default)
-/// - The content type's rss_include is None (include by default)
-/// - The content type's rss_include is Some(true)
-fn should_include_in_rss(config: &Config, content_type: &str) -> bool {
+/// - Neither the section nor a parent section is in config (include by default)
+/// - The section's rss_include is None (include by default)
+/// - The section's rss_include is Some(true)
+fn should_include_in_rss(config: &Config, section: &str) -> bool {
     config
-        .content
-        .get(content_type)
+        .section_config(section)
         .map(|ct| ct.rss_include.unwrap_or(true))
         .unwrap_or(true)
 }
@@ -144,15 +142,10 @@ fn format_item(config: &Config, content: &LoadedContent, base_url: &str) -> Stri
     ));
 
     // Description (excerpt)
-    let excerpt = get_excerpt_html(
-        &content.content.data,
-        "## Context",
-        config.site.allow_dangerous_html,
-    );
-    if !excerpt.is_empty() {
+    if !content.excerpt.is_empty() {
         item.push_str(&format!(
             "      <description>{}</description>\n",
-            xml_escape(&excerpt)
+            xml_escape(&content.excerpt)
         ));
     }
 
@@ -176,6 +169,14 @@ fn format_item(config: &Config, content: &LoadedContent, base_url: &str) -> Stri
         format_rfc2822(&content.content.meta.date)
     ));
 
+    // Last revision, when the content has an `updated` date
+    if let Some(updated) = &content.content.meta.updated {
+        item.push_str(&format!(
+            "      <atom:updated>{}</atom:updated>\n",
+            format_rfc3339(updated)
+        ));
+    }
+
     item.push_str("    </item>\n");
     item
 }
@@ -188,6 +189,14 @@ fn format_rfc2822(date: &OffsetDateTime) -> String {
     date.format(&Rfc2822).unwrap_or_else(|_| String::new())
 }
 
+/// Formats a date in RFC 3339 format for Atom elements.
+///
+/// Example: "2024-01-15T10:30:00Z"
+fn format_rfc3339(date: &OffsetDateTime) -> String {
+    use time::format_description::well_known::Rfc3339;
+    date.format(&Rfc3339).unwrap_or_else(|_| String::new())
+}
+
 /// Converts a file path to a URL path.
 ///
 /// Handles platform-specific path separators and ensures forward slashes.
@@ -233,6 +242,7 @@ mod tests {
     use crate::content::{Content, ContentMeta};
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use time::macros::datetime;
 
     fn create_test_config() -> Config {
         let mut content = HashMap::new();
@@ -244,6 +254,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: None,
                 rss_include: None, // Default: include
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -269,10 +283,19 @@ mod tests {
                 rss_full_content: false,
                 asset_hashing_enabled: false,
                 asset_manifest_path: None,
+                not_found_template: None,
+                timezone: None,
+                paginate_by: None,
+                page_template: None,
+                broken_links: crate::config::BrokenLinks::Warn,
+                excerpt: crate::config::ExcerptConfig::default(),
+                toc: crate::config::TocConfig::default(),
+                words_per_minute: std::num::NonZeroUsize::new(200).unwrap(),
             },
             content,
             dynamic: HashMap::new(),
             redirects: HashMap::new(),
+            taxonomies: HashMap::new(),
         }
     }
 
@@ -282,6 +305,7 @@ mod tests {
         ContentMeta {
             title: title.to_string(),
             date,
+            updated: None,
             author: author.to_string(),
             tags: vec![],
             template: None,
@@ -289,6 +313,11 @@ mod tests {
             extra: std::collections::HashMap::new(),
             extra_js: vec![],
             draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
         }
     }
 
@@ -306,7 +335,10 @@ mod tests {
                 data: markdown.to_string(),
             },
             html: "<h1>Test</h1>".to_string(),
+            excerpt: String::new(),
             content_type: content_type.to_string(),
+            section: content_type.to_string(),
+            assets: vec![],
             output_path: PathBuf::from(format!("output/{}/{}.html", content_type, filename)),
         }
     }
@@ -397,6 +429,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: None,
                 rss_include: Some(false), // Exclude from RSS
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -428,13 +464,15 @@ mod tests {
     #[test]
     fn test_generate_rss_with_excerpt() {
         let config = create_test_config();
-        let contents = vec![create_test_loaded_content(
+        let mut post = create_test_loaded_content(
             "test",
             "Test Post",
             "2024-01-15T10:00:00+00:00",
             "posts",
             "# Test\n\n## Context\n\nThis is my excerpt content.\n\n## Other Section\n\nMore content.",
-        )];
+        );
+        post.excerpt = "<p>This is my excerpt content.</p>".to_string();
+        let contents = vec![post];
 
         let rss = generate_rss(&config, &contents);
 
@@ -495,6 +533,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: None,
                 rss_include: Some(false),
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -596,6 +638,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rss_item_updated() {
+        let config = create_test_config();
+        let mut revised = create_test_loaded_content(
+            "revised",
+            "Revised Post",
+            "2024-01-01T10:00:00+00:00",
+            "posts",
+            "# Revised",
+        );
+        revised.content.meta.updated = Some(datetime!(2024-03-05 08:00:00 UTC));
+        let contents = vec![
+            revised,
+            create_test_loaded_content(
+                "untouched",
+                "Untouched Post",
+                "2024-02-01T10:00:00+00:00",
+                "posts",
+                "# Untouched",
+            ),
+        ];
+
+        let rss = generate_rss(&config, &contents);
+
+        // Only the revised item carries it, and pubDate stays the publish date
+        assert_eq!(rss.matches("<atom:updated>").count(), 1);
+        assert!(rss.contains("<atom:updated>2024-03-05T08:00:00Z</atom:updated>"));
+        assert!(rss.contains("<pubDate>Mon, 01 Jan 2024 10:00:00 +0000</pubDate>"));
+    }
+
     #[test]
     fn test_rss_channel_no_pubdate_when_empty() {
         let config = create_test_config();
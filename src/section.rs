@@ -0,0 +1,381 @@
+// src/section.rs
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::LoadedContent;
+use crate::config::Config;
+use crate::content::SectionIndex;
+use crate::utils::serialize_toml_map;
+
+/// Every configured section and the content directly in it.
+///
+/// A section is a directory below `content_dir` (e.g., "docs/guides"). It is part
+/// of the tree when it, or a parent section, has a `[content.*]` table, and every
+/// section in the tree gets an index page.
+#[derive(Debug)]
+pub(crate) struct SectionTree<'a> {
+    /// Pages directly in each section, keyed by section path
+    sections: BTreeMap<String, Vec<&'a LoadedContent>>,
+    /// Intro content from each section's `_index.md`, keyed by section path
+    /// (`""` for `content/_index.md`)
+    indexes: HashMap<String, SectionIndex>,
+    /// Number of loaded pages, sections or not
+    page_count: usize,
+}
+
+/// Section summary for templates.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct SectionLink {
+    /// Last path component (e.g., "guides")
+    pub(crate) name: String,
+    /// Title from the section's `_index.md`, or else its name
+    pub(crate) title: String,
+    /// Path below `content_dir` (e.g., "docs/guides")
+    pub(crate) path: String,
+    /// Site-relative URL of the section index (e.g., "/docs/guides/")
+    pub(crate) url: String,
+    /// Number of pages in the section and its subsections
+    pub(crate) count: usize,
+}
+
+/// A section and its place in the tree, exposed to templates as `section`.
+#[derive(Debug, Serialize)]
+pub(crate) struct SectionInfo {
+    #[serde(flatten)]
+    pub(crate) link: SectionLink,
+    /// Parent sections, outermost first
+    pub(crate) ancestors: Vec<SectionLink>,
+    /// Parent sections followed by the section itself
+    pub(crate) breadcrumbs: Vec<SectionLink>,
+    /// Direct subsections sorted by path
+    pub(crate) children: Vec<SectionLink>,
+    /// Rendered body of the section's `_index.md` (empty without one)
+    pub(crate) content: String,
+    /// Custom fields from the [extra] table of the section's `_index.md`
+    #[serde(serialize_with = "serialize_toml_map")]
+    pub(crate) extra: HashMap<String, toml::Value>,
+}
+
+impl<'a> SectionTree<'a> {
+    /// Groups the loaded content into the sections of the tree.
+    ///
+    /// Content outside any configured section (e.g., directly in `content_dir`) is
+    /// left out. `indexes` holds the loaded `_index.md` files by section path.
+    pub(crate) fn collect(
+        config: &Config,
+        loaded_contents: &'a [LoadedContent],
+        indexes: HashMap<String, SectionIndex>,
+    ) -> Self {
+        let mut tree = Self {
+            sections: BTreeMap::new(),
+            indexes,
+            page_count: loaded_contents.len(),
+        };
+
+        for path in config.content.keys() {
+            tree.insert(config, path);
+        }
+        for loaded in loaded_contents {
+            if config.section_config(&loaded.section).is_some() {
+                tree.insert(config, &loaded.section);
+                tree.sections
+                    .get_mut(&loaded.section)
+                    .expect("section was just inserted")
+                    .push(loaded);
+            }
+        }
+
+        tree
+    }
+
+    /// Adds a section and every configured parent section that is not yet known.
+    fn insert(&mut self, config: &Config, path: &str) {
+        let mut path = path;
+        while !self.sections.contains_key(path) {
+            self.sections.insert(path.to_string(), vec![]);
+            match path.rsplit_once('/') {
+                Some((parent, _)) if config.section_config(parent).is_some() => path = parent,
+                _ => break,
+            }
+        }
+    }
+
+    /// Section paths in sorted order (parents before their subsections).
+    pub(crate) fn paths(&self) -> impl Iterator<Item = &str> {
+        self.sections.keys().map(String::as_str)
+    }
+
+    pub(crate) fn contains(&self, path: &str) -> bool {
+        self.sections.contains_key(path)
+    }
+
+    /// Pages in the section and all of its subsections.
+    pub(crate) fn pages(&self, path: &str) -> Vec<&'a LoadedContent> {
+        self.sections
+            .iter()
+            .filter(|(key, _)| is_within(key, path))
+            .flat_map(|(_, pages)| pages.iter().copied())
+            .collect()
+    }
+
+    fn link(&self, path: &str) -> SectionLink {
+        let name = path.rsplit('/').next().unwrap_or(path).to_string();
+        SectionLink {
+            title: self
+                .indexes
+                .get(path)
+                .and_then(|index| index.title.clone())
+                .unwrap_or_else(|| name.clone()),
+            name,
+            path: path.to_string(),
+            url: format!("/{}/", path),
+            count: self.pages(path).len(),
+        }
+    }
+
+    /// Template-facing view of the section at `path`.
+    pub(crate) fn info(&self, path: &str) -> SectionInfo {
+        let mut ancestors = vec![];
+        let mut current = path;
+        while let Some((parent, _)) = current.rsplit_once('/') {
+            if !self.contains(parent) {
+                break;
+            }
+            ancestors.push(self.link(parent));
+            current = parent;
+        }
+        ancestors.reverse();
+
+        let link = self.link(path);
+        let mut breadcrumbs = ancestors.clone();
+        breadcrumbs.push(link.clone());
+
+        let children = self
+            .paths()
+            .filter(|key| {
+                key.rsplit_once('/')
+                    .is_some_and(|(parent, _)| parent == path)
+            })
+            .map(|key| self.link(key))
+            .collect();
+
+        let index = self.indexes.get(path);
+        SectionInfo {
+            link,
+            ancestors,
+            breadcrumbs,
+            children,
+            content: index.map(|index| index.html.clone()).unwrap_or_default(),
+            extra: index.map(|index| index.extra.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Template-facing view of the content root, passed to the site index.
+    ///
+    /// Its title, content and extra come from `content/_index.md`, the title
+    /// defaulting to `site_title`. `count` covers every page and `children` are
+    /// the top-level sections.
+    pub(crate) fn root_info(&self, site_title: &str) -> SectionInfo {
+        let index = self.indexes.get("");
+        let link = SectionLink {
+            name: String::new(),
+            title: index
+                .and_then(|index| index.title.clone())
+                .unwrap_or_else(|| site_title.to_string()),
+            path: String::new(),
+            url: "/".to_string(),
+            count: self.page_count,
+        };
+
+        let children = self
+            .paths()
+            .filter(|key| {
+                key.rsplit_once('/')
+                    .is_none_or(|(parent, _)| !self.contains(parent))
+            })
+            .map(|key| self.link(key))
+            .collect();
+
+        SectionInfo {
+            breadcrumbs: vec![link.clone()],
+            link,
+            ancestors: vec![],
+            children,
+            content: index.map(|index| index.html.clone()).unwrap_or_default(),
+            extra: index.map(|index| index.extra.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Checks whether section `path` is `section` or one of its subsections.
+fn is_within(path: &str, section: &str) -> bool {
+    path.strip_prefix(section)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Content, ContentMeta};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use time::macros::datetime;
+
+    fn create_test_config() -> Config {
+        Config::from_str(
+            r#"
+[site]
+title = "Test Site"
+tagline = "A test tagline"
+domain = "example.com"
+author = "Test Author"
+output_dir = "output"
+content_dir = "content"
+template_dir = "templates"
+static_dir = "static"
+site_index_template = "index.html"
+
+[content.docs]
+index_template = "docs_index.html"
+content_template = "doc.html"
+"#,
+        )
+        .unwrap()
+    }
+
+    fn create_test_loaded_content(section: &str, filename: &str) -> LoadedContent {
+        LoadedContent {
+            path: PathBuf::from(format!("content/{}/{}.md", section, filename)),
+            content: Content {
+                meta: ContentMeta {
+                    title: filename.to_string(),
+                    date: datetime!(2024-01-15 10:00:00 UTC),
+                    updated: None,
+                    author: "Test Author".to_string(),
+                    tags: vec![],
+                    template: None,
+                    cover: None,
+                    extra: HashMap::new(),
+                    extra_js: vec![],
+                    draft: false,
+                    publish_date: None,
+                    expiry_date: None,
+                    weight: None,
+                    summary: None,
+                    toc: false,
+                },
+                data: String::new(),
+            },
+            html: String::new(),
+            excerpt: String::new(),
+            content_type: section.split('/').next().unwrap().to_string(),
+            section: section.to_string(),
+            assets: vec![],
+            output_path: PathBuf::from(format!("output/{}/{}.html", section, filename)),
+        }
+    }
+
+    #[test]
+    fn test_collect_adds_intermediate_sections() {
+        let config = create_test_config();
+        let contents = vec![
+            create_test_loaded_content("docs", "intro"),
+            create_test_loaded_content("docs/guides/advanced", "tuning"),
+            create_test_loaded_content("misc", "notes"),
+        ];
+
+        let tree = SectionTree::collect(&config, &contents, HashMap::new());
+        let paths: Vec<_> = tree.paths().collect();
+        assert_eq!(paths, vec!["docs", "docs/guides", "docs/guides/advanced"]);
+
+        assert_eq!(tree.pages("docs").len(), 2);
+        assert_eq!(tree.pages("docs/guides").len(), 1);
+        assert!(tree.pages("misc").is_empty());
+    }
+
+    #[test]
+    fn test_info_links_ancestors_and_children() {
+        let config = create_test_config();
+        let contents = vec![
+            create_test_loaded_content("docs/guides", "install"),
+            create_test_loaded_content("docs/guides/advanced", "tuning"),
+            create_test_loaded_content("docs/guidesextra", "other"),
+        ];
+        let tree = SectionTree::collect(&config, &contents, HashMap::new());
+
+        let info = tree.info("docs/guides");
+        assert_eq!(info.link.name, "guides");
+        assert_eq!(info.link.url, "/docs/guides/");
+        assert_eq!(info.link.count, 2);
+
+        let ancestors: Vec<_> = info.ancestors.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(ancestors, vec!["docs"]);
+        let breadcrumbs: Vec<_> = info.breadcrumbs.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(breadcrumbs, vec!["docs", "guides"]);
+        let children: Vec<_> = info.children.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(children, vec!["docs/guides/advanced"]);
+
+        assert_eq!(info.link.title, "guides");
+        assert!(info.content.is_empty());
+
+        let top = tree.info("docs");
+        assert!(top.ancestors.is_empty());
+        assert_eq!(top.link.count, 3);
+        assert_eq!(top.children.len(), 2);
+    }
+
+    #[test]
+    fn test_info_uses_section_index_content() {
+        let config = create_test_config();
+        let contents = vec![create_test_loaded_content("docs/guides", "install")];
+        let indexes = HashMap::from([(
+            "docs/guides".to_string(),
+            SectionIndex {
+                title: Some("User Guides".to_string()),
+                html: "<p>Start here.</p>".to_string(),
+                extra: HashMap::from([("icon".to_string(), toml::Value::from("book"))]),
+            },
+        )]);
+        let tree = SectionTree::collect(&config, &contents, indexes);
+
+        let info = tree.info("docs/guides");
+        assert_eq!(info.link.title, "User Guides");
+        assert_eq!(info.content, "<p>Start here.</p>");
+        assert_eq!(info.extra["icon"].as_str(), Some("book"));
+
+        // Parents link to the section by its title
+        assert_eq!(tree.info("docs").children[0].title, "User Guides");
+    }
+
+    #[test]
+    fn test_root_info_uses_root_index() {
+        let config = create_test_config();
+        let contents = vec![
+            create_test_loaded_content("docs/guides", "install"),
+            create_test_loaded_content("misc", "notes"),
+        ];
+        let tree = SectionTree::collect(&config, &contents, HashMap::new());
+
+        let root = tree.root_info("Test Site");
+        assert_eq!(root.link.title, "Test Site");
+        assert_eq!(root.link.url, "/");
+        assert_eq!(root.link.count, 2);
+        let children: Vec<_> = root.children.iter().map(|s| s.path.as_str()).collect();
+        assert_eq!(children, vec!["docs"]);
+        assert!(root.content.is_empty());
+
+        let indexes = HashMap::from([(
+            String::new(),
+            SectionIndex {
+                title: Some("Home".to_string()),
+                html: "<p>Welcome.</p>".to_string(),
+                extra: HashMap::new(),
+            },
+        )]);
+        let tree = SectionTree::collect(&config, &contents, indexes);
+        let root = tree.root_info("Test Site");
+        assert_eq!(root.link.title, "Home");
+        assert_eq!(root.content, "<p>Welcome.</p>");
+    }
+}
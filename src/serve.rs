@@ -0,0 +1,654 @@
+// src/serve.rs
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Component, Path, PathBuf},
+    sync::{Arc, mpsc::RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::error::RunError;
+use crate::publish::Visibility;
+use crate::reload::{RELOAD_ENDPOINT, ReloadHub, inject_reload_script};
+use crate::utils::percent_decode;
+use crate::watch;
+
+/// Interval between SSE comments that keep idle reload streams open
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Settings needed to answer requests, shared by every connection thread.
+#[derive(Clone, Debug)]
+pub(crate) struct ServeOptions {
+    /// Directory the site was built into (`site.output_dir`)
+    pub(crate) root: PathBuf,
+    /// Whether `/slug/` URLs are expected (mirrors `site.clean_urls`)
+    pub(crate) clean_urls: bool,
+    /// Live reload hub; set only when serving with `--watch`
+    pub(crate) reload: Option<Arc<ReloadHub>>,
+}
+
+impl ServeOptions {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self {
+            root: PathBuf::from(&config.site.output_dir),
+            clean_urls: config.site.clean_urls,
+            reload: None,
+        }
+    }
+}
+
+/// Outcome of mapping a request path onto the output directory.
+#[derive(Debug, PartialEq)]
+enum Resolved {
+    /// Serve this file with 200 OK
+    File(PathBuf),
+    /// Send a 301 adding the trailing slash (directory requested without one)
+    Redirect,
+    /// Nothing matches; serve the 404 page
+    NotFound,
+}
+
+/// Serve the built site over local HTTP until the process is stopped.
+///
+/// With `watch` enabled the site is rebuilt on changes (see [`watch::watch`]) and
+/// connected browsers reload automatically.
+pub(crate) fn serve(
+    config_file: &str,
+    host: &str,
+    port: u16,
+    watch: bool,
+    visibility: Visibility,
+) -> Result<(), RunError> {
+    let config = Config::load_from_file(config_file)?;
+    let mut options = ServeOptions::from_config(&config);
+
+    if !watch && !options.root.exists() {
+        warn!(
+            "serve::missing {} does not exist yet, run `marie-ssg build` first",
+            options.root.display()
+        );
+    }
+
+    let listener = TcpListener::bind((host, port))
+        .map_err(|e| RunError::IoError(format!("Failed to bind {}:{}: {}", host, port, e)))?;
+
+    info!(
+        "serve::start http://{}:{}/ ← {}",
+        host,
+        port,
+        options.root.display()
+    );
+
+    if !watch {
+        info!("serve::info press Ctrl+C to stop");
+        run_server(listener, options);
+        return Ok(());
+    }
+
+    // Serve from a background thread; the watch loop owns Ctrl+C handling and
+    // returning from it ends the process
+    let hub = Arc::new(ReloadHub::default());
+    options.reload = Some(Arc::clone(&hub));
+    info!("serve::reload live reload enabled");
+    thread::spawn(move || run_server(listener, options));
+
+    watch::watch(config_file, visibility, Some(&hub))
+}
+
+/// Accepts connections and answers each one on its own thread.
+pub(crate) fn run_server(listener: TcpListener, options: ServeOptions) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let options = options.clone();
+                thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &options) {
+                        debug!("serve::connection {}", e);
+                    }
+                });
+            }
+            Err(e) => warn!("serve::accept {}", e),
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, options: &ServeOptions) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Drain headers; nothing in them changes how a static file is served
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header != "\r\n" && header != "\n" {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or("/");
+
+    if method != "GET" && method != "HEAD" {
+        return write_response(
+            stream,
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            &[("Allow", "GET, HEAD")],
+            b"Method Not Allowed",
+            false,
+        );
+    }
+    let head_only = method == "HEAD";
+
+    let url_path = decode_url_path(target);
+
+    if let Some(hub) = &options.reload
+        && url_path == RELOAD_ENDPOINT
+    {
+        return stream_reload_events(stream, hub);
+    }
+
+    match resolve_request_path(&options.root, &url_path, options.clean_urls) {
+        Resolved::File(path) => match fs::read(&path) {
+            Ok(body) => {
+                info!("serve::200 {}", url_path);
+                let content_type = mime_type(&path);
+                let body = with_reload_script(options, content_type, body);
+                write_response(stream, "200 OK", content_type, &[], &body, head_only)
+            }
+            Err(e) => {
+                warn!("serve::500 {} ({})", url_path, e);
+                write_response(
+                    stream,
+                    "500 Internal Server Error",
+                    "text/plain; charset=utf-8",
+                    &[],
+                    b"Internal Server Error",
+                    head_only,
+                )
+            }
+        },
+        Resolved::Redirect => {
+            let location = trailing_slash_location(target);
+            info!("serve::301 {} → {}", url_path, location);
+            write_response(
+                stream,
+                "301 Moved Permanently",
+                "text/plain; charset=utf-8",
+                &[("Location", &location)],
+                b"Moved Permanently",
+                head_only,
+            )
+        }
+        Resolved::NotFound => {
+            info!("serve::404 {}", url_path);
+            let (content_type, body) = match fs::read(options.root.join("404.html")) {
+                Ok(body) => ("text/html; charset=utf-8", body),
+                Err(_) => ("text/plain; charset=utf-8", b"Not Found".to_vec()),
+            };
+            let body = with_reload_script(options, content_type, body);
+            write_response(stream, "404 Not Found", content_type, &[], &body, head_only)
+        }
+    }
+}
+
+/// Adds the live reload script to HTML responses when serving with `--watch`.
+fn with_reload_script(options: &ServeOptions, content_type: &str, body: Vec<u8>) -> Vec<u8> {
+    if options.reload.is_some() && content_type.starts_with("text/html") {
+        inject_reload_script(&body)
+    } else {
+        body
+    }
+}
+
+/// Holds a server-sent events stream open and forwards one message per rebuild.
+fn stream_reload_events(mut stream: TcpStream, hub: &ReloadHub) -> std::io::Result<()> {
+    let receiver = hub.subscribe();
+    debug!("serve::reload client connected");
+
+    stream.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n",
+    )?;
+    stream.flush()?;
+
+    loop {
+        match receiver.recv_timeout(KEEP_ALIVE_INTERVAL) {
+            Ok(kind) => write!(stream, "data: {}\n\n", kind.as_str())?,
+            Err(RecvTimeoutError::Timeout) => stream.write_all(b": keep-alive\n\n")?,
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        stream.flush()?;
+    }
+}
+
+fn write_response(
+    mut stream: TcpStream,
+    status: &str,
+    content_type: &str,
+    extra_headers: &[(&str, &str)],
+    body: &[u8],
+    head_only: bool,
+) -> std::io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    for (name, value) in extra_headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    if !head_only {
+        stream.write_all(body)?;
+    }
+    stream.flush()
+}
+
+/// Maps a decoded URL path to a file in the output directory.
+///
+/// Resolution order:
+/// 1. An existing file at the exact path
+/// 2. `index.html` inside a directory (redirecting to add the trailing slash if missing)
+/// 3. `<path>.html` when `clean_urls` is disabled (`/about` → `about.html`)
+///
+/// Paths containing `..` never resolve, so requests cannot escape the output directory.
+fn resolve_request_path(root: &Path, url_path: &str, clean_urls: bool) -> Resolved {
+    let relative = url_path.trim_start_matches('/');
+    let relative_path = Path::new(relative);
+
+    if relative_path
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Resolved::NotFound;
+    }
+
+    let candidate = root.join(relative_path);
+
+    if candidate.is_file() && !url_path.ends_with('/') {
+        return Resolved::File(candidate);
+    }
+
+    if candidate.is_dir() {
+        let index = candidate.join("index.html");
+        if index.is_file() {
+            if url_path.ends_with('/') {
+                return Resolved::File(index);
+            }
+            return Resolved::Redirect;
+        }
+    }
+
+    if !clean_urls && !relative.is_empty() && !url_path.ends_with('/') {
+        let html = root.join(format!("{}.html", relative));
+        if html.is_file() {
+            return Resolved::File(html);
+        }
+    }
+
+    Resolved::NotFound
+}
+
+/// `Location` of the redirect adding a trailing slash to a request target.
+///
+/// Built from the raw target rather than the decoded path, so escapes stay encoded
+/// and the query string is kept (`/blog?page=2` → `/blog/?page=2`). Bytes that
+/// may not appear in a header value are percent-encoded.
+fn trailing_slash_location(target: &str) -> String {
+    let target = target.split('#').next().unwrap_or_default();
+    let (path, query) = target.split_at(target.find('?').unwrap_or(target.len()));
+
+    let mut location = String::with_capacity(target.len() + 2);
+    if !path.starts_with('/') {
+        location.push('/');
+    }
+    for byte in format!("{}/{}", path, query).bytes() {
+        if byte.is_ascii_graphic() {
+            location.push(byte as char);
+        } else {
+            location.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    location
+}
+
+/// Strips query string and fragment, then percent-decodes the path.
+///
+/// Invalid escapes are kept verbatim; invalid UTF-8 is replaced lossily.
+fn decode_url_path(target: &str) -> String {
+    let path = target.split(['?', '#']).next().unwrap_or("/");
+
+    let decoded = percent_decode(path);
+    if decoded.starts_with('/') {
+        decoded
+    } else {
+        format!("/{}", decoded)
+    }
+}
+
+/// Returns the Content-Type for a file based on its extension.
+fn mime_type(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" | "map" => "application/json",
+        "xml" => "application/xml",
+        "txt" => "text/plain; charset=utf-8",
+        "md" => "text/markdown; charset=utf-8",
+        "csv" => "text/csv; charset=utf-8",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reload::ReloadKind;
+    use std::io::Read;
+    use std::net::SocketAddr;
+    use tempfile::tempdir;
+
+    /// Creates an output directory shaped like a real build
+    fn create_site(clean_urls: bool) -> tempfile::TempDir {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("articles/my-post")).unwrap();
+        fs::create_dir_all(root.join("static")).unwrap();
+        fs::write(root.join("index.html"), "<h1>Home</h1>").unwrap();
+        fs::write(root.join("articles/index.html"), "<h1>Articles</h1>").unwrap();
+        fs::write(root.join("static/style.css"), "body {}").unwrap();
+        if clean_urls {
+            fs::write(root.join("articles/my-post/index.html"), "<h1>Post</h1>").unwrap();
+        } else {
+            fs::write(root.join("articles/my-post.html"), "<h1>Post</h1>").unwrap();
+        }
+        temp_dir
+    }
+
+    #[test]
+    fn test_resolve_root_index() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/", true),
+            Resolved::File(site.path().join("index.html"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_clean_url_directory() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/my-post/", true),
+            Resolved::File(site.path().join("articles/my-post/index.html"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_clean_url_redirects_missing_slash() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/my-post", true),
+            Resolved::Redirect
+        );
+    }
+
+    #[test]
+    fn test_resolve_html_extension_when_clean_urls_disabled() {
+        let site = create_site(false);
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/my-post", false),
+            Resolved::File(site.path().join("articles/my-post.html"))
+        );
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/my-post.html", false),
+            Resolved::File(site.path().join("articles/my-post.html"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_no_html_fallback_when_clean_urls_enabled() {
+        let site = create_site(false);
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/my-post", true),
+            Resolved::NotFound
+        );
+    }
+
+    #[test]
+    fn test_resolve_static_file() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/static/style.css", true),
+            Resolved::File(site.path().join("static/style.css"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_rejects_parent_traversal() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/../etc/passwd", true),
+            Resolved::NotFound
+        );
+        assert_eq!(
+            resolve_request_path(site.path(), "/articles/../../secret", true),
+            Resolved::NotFound
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_file() {
+        let site = create_site(true);
+        assert_eq!(
+            resolve_request_path(site.path(), "/nope/", true),
+            Resolved::NotFound
+        );
+    }
+
+    #[test]
+    fn test_trailing_slash_location() {
+        assert_eq!(trailing_slash_location("/blog"), "/blog/");
+        assert_eq!(trailing_slash_location("/blog?page=2#top"), "/blog/?page=2");
+        assert_eq!(trailing_slash_location("/caf%C3%A9"), "/caf%C3%A9/");
+        assert_eq!(trailing_slash_location("/café\u{7f}"), "/caf%C3%A9%7F/");
+    }
+
+    #[test]
+    fn test_decode_url_path() {
+        assert_eq!(decode_url_path("/"), "/");
+        assert_eq!(decode_url_path("/a%20b/"), "/a b/");
+        assert_eq!(decode_url_path("/post/?utm=x#top"), "/post/");
+        assert_eq!(decode_url_path("/%2e%2e/secret"), "/../secret");
+        assert_eq!(decode_url_path("/100%"), "/100%");
+        assert_eq!(decode_url_path("/caf%C3%A9"), "/café");
+    }
+
+    #[test]
+    fn test_mime_type() {
+        assert_eq!(mime_type(Path::new("a.html")), "text/html; charset=utf-8");
+        assert_eq!(mime_type(Path::new("a.CSS")), "text/css; charset=utf-8");
+        assert_eq!(mime_type(Path::new("feed.xml")), "application/xml");
+        assert_eq!(mime_type(Path::new("logo.svg")), "image/svg+xml");
+        assert_eq!(mime_type(Path::new("font.woff2")), "font/woff2");
+        assert_eq!(mime_type(Path::new("blob")), "application/octet-stream");
+    }
+
+    /// Starts a server for `site` on an ephemeral port
+    fn spawn_server(site: &Path, clean_urls: bool, reload: Option<Arc<ReloadHub>>) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let options = ServeOptions {
+            root: site.to_path_buf(),
+            clean_urls,
+            reload,
+        };
+        thread::spawn(move || run_server(listener, options));
+        addr
+    }
+
+    /// Sends a raw request and reads the response until the server closes the connection
+    fn send(addr: SocketAddr, raw: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(raw.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    fn request(site: &Path, clean_urls: bool, raw: &str) -> String {
+        send(spawn_server(site, clean_urls, None), raw)
+    }
+
+    #[test]
+    fn test_server_serves_file_with_mime_type() {
+        let site = create_site(true);
+        let response = request(site.path(), true, "GET /static/style.css HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("Content-Type: text/css; charset=utf-8"));
+        assert!(response.ends_with("body {}"));
+    }
+
+    #[test]
+    fn test_server_custom_404_page() {
+        let site = create_site(true);
+        fs::write(site.path().join("404.html"), "<h1>Lost</h1>").unwrap();
+
+        let response = request(site.path(), true, "GET /missing/ HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+        assert!(response.contains("Content-Type: text/html"));
+        assert!(response.ends_with("<h1>Lost</h1>"));
+    }
+
+    #[test]
+    fn test_server_plain_404_without_page() {
+        let site = create_site(true);
+        let response = request(site.path(), true, "GET /missing/ HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+        assert!(response.ends_with("Not Found"));
+    }
+
+    #[test]
+    fn test_server_redirect_adds_trailing_slash() {
+        let site = create_site(true);
+        let response = request(site.path(), true, "GET /articles/my-post HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 301 Moved Permanently"));
+        assert!(response.contains("Location: /articles/my-post/"));
+    }
+
+    #[test]
+    fn test_server_head_has_no_body() {
+        let site = create_site(true);
+        let response = request(site.path(), true, "HEAD / HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("Content-Length: 13"));
+        assert!(response.ends_with("\r\n\r\n"));
+    }
+
+    #[test]
+    fn test_server_rejects_other_methods() {
+        let site = create_site(true);
+        let response = request(site.path(), true, "POST / HTTP/1.1\r\n\r\n");
+
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed"));
+    }
+
+    #[test]
+    fn test_server_injects_reload_script_when_watching() {
+        let site = create_site(true);
+        let addr = spawn_server(site.path(), true, Some(Arc::new(ReloadHub::default())));
+
+        let html = send(addr, "GET / HTTP/1.1\r\n\r\n");
+        assert!(html.contains(RELOAD_ENDPOINT));
+        assert!(html.contains("<h1>Home</h1><script>"));
+
+        let css = send(addr, "GET /static/style.css HTTP/1.1\r\n\r\n");
+        assert!(!css.contains(RELOAD_ENDPOINT));
+        assert!(css.ends_with("body {}"));
+    }
+
+    #[test]
+    fn test_server_no_reload_without_watch() {
+        let site = create_site(true);
+
+        let html = request(site.path(), true, "GET / HTTP/1.1\r\n\r\n");
+        assert!(!html.contains(RELOAD_ENDPOINT));
+
+        let endpoint = request(
+            site.path(),
+            true,
+            &format!("GET {} HTTP/1.1\r\n\r\n", RELOAD_ENDPOINT),
+        );
+        assert!(endpoint.starts_with("HTTP/1.1 404 Not Found"));
+    }
+
+    #[test]
+    fn test_reload_stream_delivers_events() {
+        let site = create_site(true);
+        let hub = Arc::new(ReloadHub::default());
+        let addr = spawn_server(site.path(), true, Some(Arc::clone(&hub)));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(format!("GET {} HTTP/1.1\r\n\r\n", RELOAD_ENDPOINT).as_bytes())
+            .unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut status = String::new();
+        reader.read_line(&mut status).unwrap();
+        assert!(status.starts_with("HTTP/1.1 200 OK"));
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+            line.clear();
+        }
+
+        // The subscription happens on the connection thread; retry until it is registered
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while hub.notify(ReloadKind::Css) == 0 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "client never subscribed"
+            );
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let mut event = String::new();
+        reader.read_line(&mut event).unwrap();
+        assert_eq!(event, "data: css\n");
+    }
+}
@@ -1,16 +1,22 @@
 // src/sitemap.rs
 
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use time::OffsetDateTime;
 use time::macros::format_description;
 
 use crate::LoadedContent;
 use crate::config::Config;
+use crate::pagination::{page_url, paginate};
+use crate::section::SectionTree;
+use crate::taxonomy::collect_taxonomies;
 
 /// Generates a sitemap.xml string following the sitemap protocol.
 ///
-/// The sitemap includes all content pages and index pages with their
-/// full URLs based on the configured domain.
+/// The sitemap includes all content pages, index pages and taxonomy pages
+/// with their full URLs based on the configured domain. A page's `<lastmod>` is
+/// its `updated` date, or else its `date`; listings take the newest of their pages.
 ///
 /// # Arguments
 /// * `config` - The site configuration containing the domain
@@ -35,13 +41,44 @@ pub(crate) fn generate_sitemap(config: &Config, loaded_contents: &[LoadedContent
 
     let base_url = format!("https://{}", config.site.domain);
 
-    // Add site index (homepage)
-    xml.push_str(&format_url_entry(&base_url, "/", None));
+    // Add site index (homepage) and its later pages
+    let lastmod = last_modified(loaded_contents);
+    for path in index_page_urls(config, "", config.site.paginate_by, loaded_contents.len()) {
+        xml.push_str(&format_url_entry(&base_url, &path, lastmod.as_ref()));
+    }
+
+    // Add section index pages
+    let tree = SectionTree::collect(config, loaded_contents, HashMap::new());
+    for section in tree.paths() {
+        let paginate_by = config.section_config(section).and_then(|ct| ct.paginate_by);
+        let pages = tree.pages(section);
+        let lastmod = last_modified(pages.iter().copied());
+        for path in index_page_urls(config, section, paginate_by, pages.len()) {
+            xml.push_str(&format_url_entry(&base_url, &path, lastmod.as_ref()));
+        }
+    }
 
-    // Add content type index pages
-    for content_type in config.content.keys() {
-        let path = format!("/{}/", content_type);
-        xml.push_str(&format_url_entry(&base_url, &path, None));
+    // Add taxonomy term listings and term pages
+    for taxonomy in collect_taxonomies(config, loaded_contents) {
+        let lastmod = last_modified(
+            taxonomy
+                .terms
+                .iter()
+                .flat_map(|term| term.pages.iter().copied()),
+        );
+        xml.push_str(&format_url_entry(
+            &base_url,
+            &format!("/{}/", taxonomy.name),
+            lastmod.as_ref(),
+        ));
+        for term in &taxonomy.terms {
+            let lastmod = last_modified(term.pages.iter().copied());
+            xml.push_str(&format_url_entry(
+                &base_url,
+                &term.item.url,
+                lastmod.as_ref(),
+            ));
+        }
     }
 
     // Add all content pages
@@ -67,9 +104,9 @@ pub(crate) fn generate_sitemap(config: &Config, loaded_contents: &[LoadedContent
             format!("/{}", raw_path)
         };
 
-        let lastmod = Some(&content.content.meta.date);
+        let lastmod = content.content.meta.last_modified();
 
-        xml.push_str(&format_url_entry(&base_url, &path, lastmod));
+        xml.push_str(&format_url_entry(&base_url, &path, Some(&lastmod)));
     }
 
     // Close urlset
@@ -78,6 +115,32 @@ pub(crate) fn generate_sitemap(config: &Config, loaded_contents: &[LoadedContent
     xml
 }
 
+/// Newest modification time of the listed pages (see [`ContentMeta::last_modified`]).
+///
+/// [`ContentMeta::last_modified`]: crate::content::ContentMeta::last_modified
+fn last_modified<'a>(pages: impl IntoIterator<Item = &'a LoadedContent>) -> Option<OffsetDateTime> {
+    pages
+        .into_iter()
+        .map(|lc| lc.content.meta.last_modified())
+        .max()
+}
+
+/// URLs of every page of an index listing `count` items (just the index when not paginated).
+fn index_page_urls(
+    config: &Config,
+    base: &str,
+    paginate_by: Option<NonZeroUsize>,
+    count: usize,
+) -> Vec<String> {
+    match paginate_by {
+        Some(per_page) => paginate(base, count, per_page, config.site.clean_urls)
+            .into_iter()
+            .map(|page| page.url)
+            .collect(),
+        None => vec![page_url(base, 1, config.site.clean_urls)],
+    }
+}
+
 /// Formats a single URL entry for the sitemap.
 fn format_url_entry(base_url: &str, path: &str, lastmod: Option<&OffsetDateTime>) -> String {
     let mut entry = String::new();
@@ -107,10 +170,11 @@ fn path_to_url(path: &Path) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, ContentTypeConfig, SiteConfig};
+    use crate::config::{Config, ContentTypeConfig, SiteConfig, TaxonomyConfig};
     use crate::content::{Content, ContentMeta};
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use time::macros::datetime;
 
     fn create_test_config() -> Config {
         let mut content = HashMap::new();
@@ -122,6 +186,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: None,
                 rss_include: None,
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -147,10 +215,19 @@ mod tests {
                 rss_full_content: false,
                 asset_hashing_enabled: false,
                 asset_manifest_path: None,
+                not_found_template: None,
+                timezone: None,
+                paginate_by: None,
+                page_template: None,
+                broken_links: crate::config::BrokenLinks::Warn,
+                excerpt: crate::config::ExcerptConfig::default(),
+                toc: crate::config::TocConfig::default(),
+                words_per_minute: std::num::NonZeroUsize::new(200).unwrap(),
             },
             content,
             dynamic: HashMap::new(),
             redirects: HashMap::new(),
+            taxonomies: HashMap::new(),
         }
     }
 
@@ -160,6 +237,7 @@ mod tests {
         ContentMeta {
             title: title.to_string(),
             date,
+            updated: None,
             author: "Test Author".to_string(),
             tags: vec![],
             template: None,
@@ -167,6 +245,11 @@ mod tests {
             extra: std::collections::HashMap::new(),
             extra_js: vec![],
             draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
         }
     }
 
@@ -183,7 +266,10 @@ mod tests {
                 data: "# Test".to_string(),
             },
             html: "<h1>Test</h1>".to_string(),
+            excerpt: String::new(),
             content_type: content_type.to_string(),
+            section: content_type.to_string(),
+            assets: vec![],
             output_path: PathBuf::from(format!("output/{}/{}.html", content_type, filename)),
         }
     }
@@ -246,6 +332,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: None,
                 rss_include: None,
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -265,6 +355,87 @@ mod tests {
         assert!(sitemap.contains("<loc>https://example.com/pages/about.html</loc>"));
     }
 
+    #[test]
+    fn test_generate_sitemap_with_taxonomies() {
+        let mut config = create_test_config();
+        config.taxonomies.insert(
+            "tags".to_string(),
+            TaxonomyConfig {
+                index_template: "tags.html".to_string(),
+                term_template: "tag.html".to_string(),
+            },
+        );
+
+        let mut older =
+            create_test_loaded_content("old", "Old", "2024-01-15T10:00:00+00:00", "posts");
+        older.content.meta.tags = vec!["Rust Lang".to_string()];
+        let mut newer =
+            create_test_loaded_content("new", "New", "2024-03-01T10:00:00+00:00", "posts");
+        newer.content.meta.tags = vec!["Rust Lang".to_string()];
+
+        let sitemap = generate_sitemap(&config, &[older, newer]);
+
+        assert!(sitemap.contains("<loc>https://example.com/tags/</loc>"));
+        assert!(sitemap.contains(
+            "<loc>https://example.com/tags/rust-lang/</loc>\n    <lastmod>2024-03-01</lastmod>"
+        ));
+    }
+
+    #[test]
+    fn test_generate_sitemap_with_pagination() {
+        let mut config = create_test_config();
+        config.site.paginate_by = NonZeroUsize::new(2);
+        config.content.get_mut("posts").unwrap().paginate_by = NonZeroUsize::new(2);
+
+        let contents: Vec<_> = (1..=3)
+            .map(|i| {
+                create_test_loaded_content(
+                    &format!("post-{}", i),
+                    "Post",
+                    "2024-01-15T10:00:00+00:00",
+                    "posts",
+                )
+            })
+            .collect();
+
+        let sitemap = generate_sitemap(&config, &contents);
+        assert!(sitemap.contains("<loc>https://example.com/page/2.html</loc>"));
+        assert!(sitemap.contains("<loc>https://example.com/posts/page/2.html</loc>"));
+        assert!(!sitemap.contains("/posts/page/3.html"));
+
+        config.site.clean_urls = true;
+        let sitemap = generate_sitemap(&config, &contents);
+        assert!(sitemap.contains("<loc>https://example.com/posts/page/2/</loc>"));
+    }
+
+    #[test]
+    fn test_generate_sitemap_lastmod_prefers_updated() {
+        let config = create_test_config();
+        let mut revised =
+            create_test_loaded_content("revised", "Revised", "2024-01-15T10:00:00+00:00", "posts");
+        revised.content.meta.updated = Some(datetime!(2024-03-05 08:00:00 UTC));
+        let contents = vec![
+            revised,
+            create_test_loaded_content("newer", "Newer", "2024-02-20T12:00:00+00:00", "posts"),
+        ];
+
+        let sitemap = generate_sitemap(&config, &contents);
+
+        assert!(sitemap.contains(
+            "<loc>https://example.com/posts/revised.html</loc>\n    <lastmod>2024-03-05</lastmod>"
+        ));
+        assert!(!sitemap.contains("<lastmod>2024-01-15</lastmod>"));
+        // Index pages take the newest modification of their pages
+        assert!(
+            sitemap.contains("<loc>https://example.com/</loc>\n    <lastmod>2024-03-05</lastmod>")
+        );
+        assert!(
+            sitemap.contains(
+                "<loc>https://example.com/posts/</loc>\n    <lastmod>2024-03-05</lastmod>"
+            )
+        );
+    }
+
     #[test]
     fn test_format_url_entry_without_lastmod() {
         let entry = format_url_entry("https://example.com", "/about/", None);
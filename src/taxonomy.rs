@@ -0,0 +1,257 @@
+// src/taxonomy.rs
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tracing::warn;
+
+use crate::LoadedContent;
+use crate::config::Config;
+use crate::content::ContentMeta;
+use crate::utils::slugify;
+
+/// A taxonomy (e.g., tags) with every term used by the loaded content.
+#[derive(Debug)]
+pub(crate) struct Taxonomy<'a> {
+    /// Taxonomy name, also the first URL segment (e.g., "tags")
+    pub(crate) name: String,
+    /// Terms sorted by slug
+    pub(crate) terms: Vec<Term<'a>>,
+}
+
+/// A single taxonomy term and the content filed under it.
+#[derive(Debug)]
+pub(crate) struct Term<'a> {
+    /// Template-facing summary of the term
+    pub(crate) item: TermItem,
+    /// Content filed under this term
+    pub(crate) pages: Vec<&'a LoadedContent>,
+}
+
+/// Taxonomy term ready for template rendering.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct TermItem {
+    /// Term as written in the metadata (first spelling seen)
+    pub(crate) name: String,
+    /// URL-friendly term, see [`slugify`]
+    pub(crate) slug: String,
+    /// Site-relative URL of the term page (e.g., "/tags/rust/")
+    pub(crate) url: String,
+    /// Number of pages filed under the term
+    pub(crate) count: usize,
+}
+
+/// Groups the loaded content by term for every configured taxonomy.
+///
+/// The `tags` taxonomy reads `meta.tags`; any other taxonomy reads a string or
+/// array of strings from `meta.extra.<name>`. Terms that slugify to the same
+/// value (e.g., "Rust" and "rust") are merged.
+///
+/// # Returns
+/// Taxonomies sorted by name, each with its terms sorted by slug
+pub(crate) fn collect_taxonomies<'a>(
+    config: &Config,
+    loaded_contents: &'a [LoadedContent],
+) -> Vec<Taxonomy<'a>> {
+    let mut names: Vec<&String> = config.taxonomies.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let mut terms: BTreeMap<String, Term<'a>> = BTreeMap::new();
+
+            for loaded in loaded_contents {
+                for value in term_values(&loaded.content.meta, name) {
+                    let slug = slugify(&value);
+                    if slug.is_empty() {
+                        warn!(
+                            "taxonomy::skip {} term {:?} in {} has no URL-safe characters",
+                            name,
+                            value,
+                            loaded.path.display()
+                        );
+                        continue;
+                    }
+
+                    let term = terms.entry(slug.clone()).or_insert_with(|| Term {
+                        item: TermItem {
+                            name: value,
+                            url: format!("/{}/{}/", name, slug),
+                            slug,
+                            count: 0,
+                        },
+                        pages: vec![],
+                    });
+                    // A page listing the same term twice is filed once
+                    if !term.pages.iter().any(|p| std::ptr::eq(*p, loaded)) {
+                        term.pages.push(loaded);
+                        term.item.count += 1;
+                    }
+                }
+            }
+
+            Taxonomy {
+                name: name.clone(),
+                terms: terms.into_values().collect(),
+            }
+        })
+        .collect()
+}
+
+/// Terms a page lists for the taxonomy `name`.
+fn term_values(meta: &ContentMeta, name: &str) -> Vec<String> {
+    if name == "tags" {
+        return meta.tags.clone();
+    }
+
+    match meta.extra.get(name) {
+        Some(toml::Value::String(term)) => vec![term.clone()],
+        Some(toml::Value::Array(terms)) => terms
+            .iter()
+            .filter_map(|term| term.as_str().map(str::to_string))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Output path of a taxonomy's term listing: `<output_dir>/<name>/index.html`.
+pub(crate) fn index_output_path(output_dir: &str, name: &str) -> PathBuf {
+    PathBuf::from(output_dir).join(name).join("index.html")
+}
+
+/// Output path of a term page: `<output_dir>/<name>/<slug>/index.html`.
+pub(crate) fn term_output_path(output_dir: &str, name: &str, slug: &str) -> PathBuf {
+    PathBuf::from(output_dir)
+        .join(name)
+        .join(slug)
+        .join("index.html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TaxonomyConfig;
+    use crate::content::Content;
+    use std::collections::HashMap;
+    use time::macros::datetime;
+
+    fn create_test_config(names: &[&str]) -> Config {
+        let mut config = Config::from_str(
+            r#"
+[site]
+title = "Test Site"
+tagline = "A test tagline"
+domain = "example.com"
+author = "Test Author"
+output_dir = "output"
+content_dir = "content"
+template_dir = "templates"
+static_dir = "static"
+site_index_template = "index.html"
+"#,
+        )
+        .unwrap();
+        for name in names {
+            config.taxonomies.insert(
+                name.to_string(),
+                TaxonomyConfig {
+                    index_template: format!("{}.html", name),
+                    term_template: "term.html".to_string(),
+                },
+            );
+        }
+        config
+    }
+
+    fn create_test_loaded_content(
+        filename: &str,
+        tags: &[&str],
+        extra: HashMap<String, toml::Value>,
+    ) -> LoadedContent {
+        LoadedContent {
+            path: PathBuf::from(format!("content/blog/{}.md", filename)),
+            content: Content {
+                meta: ContentMeta {
+                    title: filename.to_string(),
+                    date: datetime!(2024-01-15 10:00:00 UTC),
+                    updated: None,
+                    author: "Test Author".to_string(),
+                    tags: tags.iter().map(|t| t.to_string()).collect(),
+                    template: None,
+                    cover: None,
+                    extra,
+                    extra_js: vec![],
+                    draft: false,
+                    publish_date: None,
+                    expiry_date: None,
+                    weight: None,
+                    summary: None,
+                    toc: false,
+                },
+                data: String::new(),
+            },
+            html: String::new(),
+            excerpt: String::new(),
+            content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
+            output_path: PathBuf::from(format!("output/blog/{}.html", filename)),
+        }
+    }
+
+    #[test]
+    fn test_collect_taxonomies_groups_pages_by_slug() {
+        let config = create_test_config(&["tags"]);
+        let contents = vec![
+            create_test_loaded_content("one", &["Rust", "Web Dev"], HashMap::new()),
+            create_test_loaded_content("two", &["rust", "rust"], HashMap::new()),
+            create_test_loaded_content("three", &["???"], HashMap::new()),
+        ];
+
+        let taxonomies = collect_taxonomies(&config, &contents);
+        assert_eq!(taxonomies.len(), 1);
+        assert_eq!(taxonomies[0].name, "tags");
+
+        let terms = &taxonomies[0].terms;
+        let slugs: Vec<_> = terms.iter().map(|t| t.item.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["rust", "web-dev"]);
+
+        assert_eq!(terms[0].item.name, "Rust");
+        assert_eq!(terms[0].item.url, "/tags/rust/");
+        assert_eq!(terms[0].item.count, 2);
+        assert_eq!(terms[0].pages.len(), 2);
+        assert_eq!(terms[1].item.count, 1);
+    }
+
+    #[test]
+    fn test_collect_taxonomies_reads_extra_for_other_names() {
+        let config = create_test_config(&["series"]);
+        let extra = HashMap::from([(
+            "series".to_string(),
+            toml::Value::String("Getting Started".to_string()),
+        )]);
+        let contents = vec![
+            create_test_loaded_content("one", &["rust"], extra),
+            create_test_loaded_content("two", &["rust"], HashMap::new()),
+        ];
+
+        let taxonomies = collect_taxonomies(&config, &contents);
+        let terms = &taxonomies[0].terms;
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].item.url, "/series/getting-started/");
+        assert_eq!(terms[0].pages[0].path, PathBuf::from("content/blog/one.md"));
+    }
+
+    #[test]
+    fn test_taxonomy_output_paths() {
+        assert_eq!(
+            index_output_path("output", "tags"),
+            PathBuf::from("output/tags/index.html")
+        );
+        assert_eq!(
+            term_output_path("output", "tags", "rust"),
+            PathBuf::from("output/tags/rust/index.html")
+        );
+    }
+}
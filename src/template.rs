@@ -2,14 +2,21 @@
 
 use minijinja::{Environment, State, Value, context, path_loader};
 use minijinja_contrib::add_to_environment;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
 use time::macros::format_description;
 use tracing::instrument;
 
 use crate::{
     asset_hash::AssetManifest,
-    config::Config,
-    content::{ContentItem, ContentMeta, get_excerpt_html},
+    config::{Config, ListingOrder},
+    content::{ContentItem, ContentMeta, compare_meta},
+    pagination::Paginator,
+    taxonomy::{Taxonomy, Term, TermItem},
+    toc::extract_toc,
+    utils::{count_words, html_to_text},
 };
 
 /// Format a date as "Month Day, Year" (e.g., "January 15, 2024")
@@ -94,7 +101,7 @@ pub(crate) fn create_environment_with_manifest(
 /// Build a ContentItem from LoadedContent for template rendering.
 ///
 /// This helper extracts the common logic for building template-ready content items,
-/// computing the filename, excerpt, and formatted date.
+/// computing the filename, excerpt, formatted date and reading time.
 fn build_content_item(lc: &crate::LoadedContent, config: &Config) -> ContentItem {
     let raw_filename = lc
         .output_path
@@ -114,22 +121,27 @@ fn build_content_item(lc: &crate::LoadedContent, config: &Config) -> ContentItem
         raw_filename
     };
 
-    let excerpt = get_excerpt_html(
-        &lc.content.data,
-        "## Context",
-        config.site.allow_dangerous_html,
-    );
+    let word_count = count_words(&lc.content.data);
 
     ContentItem {
         html: lc.html.clone(),
         meta: lc.content.meta.clone(),
         formatted_date: format_date_long(&lc.content.meta.date),
+        formatted_updated: lc.content.meta.updated.as_ref().map(format_date_long),
         filename,
         content_type: lc.content_type.clone(),
-        excerpt,
+        section: lc.section.clone(),
+        excerpt_text: html_to_text(&lc.excerpt),
+        excerpt: lc.excerpt.clone(),
+        word_count,
+        reading_time_minutes: word_count.div_ceil(config.site.words_per_minute.get()),
     }
 }
 
+/// Render an index listing.
+///
+/// `section` describes the listed section (see [`crate::section::SectionInfo`]);
+/// it is undefined for the site index and the 404 page.
 #[instrument(skip_all)]
 pub(crate) fn render_index_from_loaded(
     env: &Environment,
@@ -137,30 +149,220 @@ pub(crate) fn render_index_from_loaded(
     index_template_name: &str,
     loaded: Vec<&crate::LoadedContent>,
     all_content: Vec<&crate::LoadedContent>,
+    order: &ListingOrder,
+    section: &Value,
 ) -> Result<String, minijinja::Error> {
     let tmpl = env.get_template(index_template_name)?;
 
-    let mut contents: Vec<ContentItem> = loaded
-        .iter()
-        .map(|lc| build_content_item(lc, config))
-        .collect();
-    contents.sort_by(|a, b| b.meta.date.cmp(&a.meta.date));
+    let context = context! {
+        config => config,
+        section => section,
+        contents => build_content_items(&loaded, order, config),
+        all_content => build_content_items(&all_content, &ListingOrder::default(), config),
+    };
+
+    tmpl.render(context)
+}
+
+/// Paginator as seen by index templates: page position plus the page's items.
+#[derive(Serialize)]
+struct PaginatorContext<'a> {
+    #[serde(flatten)]
+    page: &'a Paginator,
+    contents: &'a [ContentItem],
+}
 
-    let mut all_contents: Vec<ContentItem> = all_content
+/// Render one page of a paginated index.
+///
+/// `sorted` is the full listing, already in listing order (see [`sort_listing`]);
+/// `contents` and `paginator.contents` hold only the items on `page`.
+#[instrument(skip_all)]
+pub(crate) fn render_index_page(
+    env: &Environment,
+    config: &Config,
+    index_template_name: &str,
+    page: &Paginator,
+    sorted: &[&crate::LoadedContent],
+    all_content: Vec<&crate::LoadedContent>,
+    section: &Value,
+) -> Result<String, minijinja::Error> {
+    let tmpl = env.get_template(index_template_name)?;
+
+    let start = page.offset().min(sorted.len());
+    let end = (start + page.per_page).min(sorted.len());
+    let contents: Vec<ContentItem> = sorted[start..end]
         .iter()
         .map(|lc| build_content_item(lc, config))
         .collect();
-    all_contents.sort_by(|a, b| b.meta.date.cmp(&a.meta.date));
 
     let context = context! {
         config => config,
+        section => section,
+        paginator => PaginatorContext {
+            page,
+            contents: &contents,
+        },
         contents => contents,
-        all_content => all_contents,
+        all_content => build_content_items(&all_content, &ListingOrder::default(), config),
+    };
+
+    tmpl.render(context)
+}
+
+/// Order a listing (see [`compare_meta`]).
+pub(crate) fn sort_listing<'a>(
+    loaded: &[&'a crate::LoadedContent],
+    order: &ListingOrder,
+) -> Vec<&'a crate::LoadedContent> {
+    let mut sorted = loaded.to_vec();
+    sorted.sort_by(|a, b| compare_meta(&a.content.meta, &b.content.meta, order));
+    sorted
+}
+
+/// Build ContentItems for a listing in `order`.
+fn build_content_items(
+    loaded: &[&crate::LoadedContent],
+    order: &ListingOrder,
+    config: &Config,
+) -> Vec<ContentItem> {
+    sort_listing(loaded, order)
+        .into_iter()
+        .map(|lc| build_content_item(lc, config))
+        .collect()
+}
+
+/// Render the term listing of a taxonomy (e.g., /tags/).
+///
+/// Templates receive `taxonomy` (the name), `terms` (name, slug, url and count of
+/// each term) and `all_content`.
+#[instrument(skip_all)]
+pub(crate) fn render_taxonomy_index(
+    env: &Environment,
+    config: &Config,
+    template_name: &str,
+    taxonomy: &Taxonomy,
+    all_content: Vec<&crate::LoadedContent>,
+) -> Result<String, minijinja::Error> {
+    let tmpl = env.get_template(template_name)?;
+
+    let terms: Vec<&TermItem> = taxonomy.terms.iter().map(|term| &term.item).collect();
+    let context = context! {
+        config => config,
+        taxonomy => taxonomy.name,
+        terms => terms,
+        all_content => build_content_items(&all_content, &ListingOrder::default(), config),
+    };
+
+    tmpl.render(context)
+}
+
+/// Render the page of a single taxonomy term (e.g., /tags/rust/).
+///
+/// Templates receive `taxonomy`, `term` and the term's pages as `contents`, newest
+/// first, like a content type index.
+#[instrument(skip_all)]
+pub(crate) fn render_taxonomy_term(
+    env: &Environment,
+    config: &Config,
+    template_name: &str,
+    taxonomy: &Taxonomy,
+    term: &Term,
+    all_content: Vec<&crate::LoadedContent>,
+) -> Result<String, minijinja::Error> {
+    let tmpl = env.get_template(template_name)?;
+
+    let context = context! {
+        config => config,
+        taxonomy => taxonomy.name,
+        term => term.item,
+        contents => build_content_items(&term.pages, &ListingOrder::default(), config),
+        all_content => build_content_items(&all_content, &ListingOrder::default(), config),
     };
 
     tmpl.render(context)
 }
 
+/// The pages of one section in listing order, shared by every page render in it.
+///
+/// Items are converted to template values once, so handing `siblings` to each page
+/// of a large section stays cheap.
+pub(crate) struct Section {
+    items: Vec<Value>,
+    siblings: Value,
+    positions: HashMap<PathBuf, usize>,
+    info: Value,
+}
+
+impl Section {
+    /// `info` is the section's place in the tree (see [`crate::section::SectionInfo`]),
+    /// or undefined for content outside any configured section.
+    pub(crate) fn new(
+        loaded: &[&crate::LoadedContent],
+        order: &ListingOrder,
+        config: &Config,
+        info: Value,
+    ) -> Self {
+        let sorted = sort_listing(loaded, order);
+        let items: Vec<Value> = sorted
+            .iter()
+            .map(|lc| Value::from_serialize(build_content_item(lc, config)))
+            .collect();
+        let positions = sorted
+            .iter()
+            .enumerate()
+            .map(|(i, lc)| (lc.path.clone(), i))
+            .collect();
+
+        Self {
+            siblings: Value::from(items.clone()),
+            items,
+            positions,
+            info,
+        }
+    }
+
+    /// Navigation context for the page loaded from `path`.
+    pub(crate) fn nav(&self, path: &Path) -> PageNav {
+        let Some(&i) = self.positions.get(path) else {
+            return PageNav {
+                section: self.info.clone(),
+                ..PageNav::default()
+            };
+        };
+        let at = |i: Option<usize>| {
+            i.and_then(|i| self.items.get(i))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        PageNav {
+            page: at(Some(i)),
+            prev: at(i.checked_sub(1)),
+            next: at(Some(i + 1)),
+            siblings: self.siblings.clone(),
+            section: self.info.clone(),
+        }
+    }
+}
+
+/// Content template variables that depend on the other pages of a content type
+pub(crate) const SECTION_VARIABLES: [&str; 4] = ["prev", "next", "siblings", "section"];
+
+/// Where a page sits within its section, exposed to content templates.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PageNav {
+    /// The page's own ContentItem (URL, formatted date, excerpt)
+    pub(crate) page: Value,
+    /// Item listed before the page (newer, in the default newest-first order)
+    pub(crate) prev: Value,
+    /// Item listed after the page (older, in the default newest-first order)
+    pub(crate) next: Value,
+    /// Every item of the section in listing order, including the page
+    pub(crate) siblings: Value,
+    /// The section's place in the tree (ancestors, breadcrumbs, children)
+    pub(crate) section: Value,
+}
+
 #[instrument(skip_all)]
 pub(crate) fn render_html(
     env: &Environment,
@@ -168,13 +370,22 @@ pub(crate) fn render_html(
     meta: &ContentMeta,
     config: &Config,
     content_template: &str,
+    nav: &PageNav,
 ) -> Result<String, minijinja::Error> {
     let tmpl = env.get_template(content_template)?;
 
     let context = context! {
         content => html,
         meta => meta,
-        config => config
+        config => config,
+        toc => extract_toc(html, &config.site.toc),
+        word_count => nav.page.get_attr("word_count").unwrap_or_default(),
+        reading_time_minutes => nav.page.get_attr("reading_time_minutes").unwrap_or_default(),
+        page => nav.page,
+        prev => nav.prev,
+        next => nav.next,
+        siblings => nav.siblings,
+        section => nav.section,
     };
 
     tmpl.render(context)
@@ -220,10 +431,19 @@ mod tests {
                 rss_full_content: false,
                 asset_hashing_enabled: false,
                 asset_manifest_path: None,
+                not_found_template: None,
+                timezone: None,
+                paginate_by: None,
+                page_template: None,
+                broken_links: crate::config::BrokenLinks::Warn,
+                excerpt: crate::config::ExcerptConfig::default(),
+                toc: crate::config::TocConfig::default(),
+                words_per_minute: std::num::NonZeroUsize::new(200).unwrap(),
             },
             content: HashMap::new(),
             dynamic: HashMap::new(),
             redirects: HashMap::new(),
+            taxonomies: HashMap::new(),
         }
     }
 
@@ -232,6 +452,7 @@ mod tests {
         ContentMeta {
             title: "Test Article".to_string(),
             date: datetime!(2024-01-15 10:00:00 -5),
+            updated: None,
             author: "Test Author".to_string(),
             tags: vec!["rust".to_string(), "testing".to_string()],
             template: None,
@@ -239,6 +460,11 @@ mod tests {
             extra: std::collections::HashMap::new(),
             extra_js: vec![],
             draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
         }
     }
 
@@ -264,7 +490,7 @@ mod tests {
         // Test rendering
         let meta = create_test_meta();
         let html = "<p>Test content</p>";
-        let result = render_html(&env, html, &meta, &config, "test.html");
+        let result = render_html(&env, html, &meta, &config, "test.html", &PageNav::default());
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -294,7 +520,14 @@ mod tests {
         let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
 
         let meta = create_test_meta();
-        let result = render_html(&env, "<p>Body</p>", &meta, &config, "full.html");
+        let result = render_html(
+            &env,
+            "<p>Body</p>",
+            &meta,
+            &config,
+            "full.html",
+            &PageNav::default(),
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -303,6 +536,62 @@ mod tests {
         assert!(rendered.contains("rust, testing"));
     }
 
+    #[test]
+    fn test_render_html_with_typed_extra_and_dynamic() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("typed.html"),
+            r#"{% if meta.extra.featured %}FEATURED{% endif %}
+{% if meta.extra.hidden %}HIDDEN{% endif %}
+rating={{ meta.extra.rating + 1 }}
+authors={{ meta.extra.coauthors | join("+") }}
+gallery={{ meta.extra.gallery.images | length }}
+updated={{ meta.extra.updated }}
+posts_per_page={{ config.dynamic.posts_per_page * 2 }}
+legacy={{ config.dynamic.twitter_handle }}"#,
+        )
+        .unwrap();
+
+        let mut env = Environment::new();
+        env.set_loader(path_loader(temp_dir.path()));
+        add_to_environment(&mut env);
+        let mut config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
+        config.dynamic = toml::from_str(
+            r#"
+posts_per_page = 10
+twitter_handle = "@user"
+"#,
+        )
+        .unwrap();
+
+        let mut meta = create_test_meta();
+        meta.extra = toml::from_str(
+            r#"
+featured = true
+hidden = false
+rating = 4
+coauthors = ["Ada", "Grace"]
+updated = 2024-02-01T10:00:00Z
+
+[gallery]
+images = ["a.png", "b.png", "c.png"]
+"#,
+        )
+        .unwrap();
+
+        let rendered =
+            render_html(&env, "", &meta, &config, "typed.html", &PageNav::default()).unwrap();
+
+        assert!(rendered.contains("FEATURED"));
+        assert!(!rendered.contains("HIDDEN"));
+        assert!(rendered.contains("rating=5"));
+        assert!(rendered.contains("authors=Ada+Grace"));
+        assert!(rendered.contains("gallery=3"));
+        assert!(rendered.contains("updated=2024-02-01T10:00:00Z"));
+        assert!(rendered.contains("posts_per_page=20"));
+        assert!(rendered.contains("legacy=@user"));
+    }
+
     #[test]
     fn test_datetimeformat_filter_available() {
         let temp_dir = TempDir::new().unwrap();
@@ -317,7 +606,14 @@ mod tests {
         let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
 
         let meta = create_test_meta();
-        let result = render_html(&env, "<p>Body</p>", &meta, &config, "date.html");
+        let result = render_html(
+            &env,
+            "<p>Body</p>",
+            &meta,
+            &config,
+            "date.html",
+            &PageNav::default(),
+        );
 
         let rendered = result.expect("datetimeformat filter should render");
         assert!(rendered.contains("Jan 15 2024"));
@@ -332,7 +628,14 @@ mod tests {
         let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
 
         let meta = create_test_meta();
-        let result = render_html(&env, "<p>Test</p>", &meta, &config, "nonexistent.html");
+        let result = render_html(
+            &env,
+            "<p>Test</p>",
+            &meta,
+            &config,
+            "nonexistent.html",
+            &PageNav::default(),
+        );
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("nonexistent.html"));
@@ -353,7 +656,15 @@ mod tests {
         env.set_loader(path_loader(temp_dir.path()));
         let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
 
-        let result = render_index_from_loaded(&env, &config, "index.html", vec![], vec![]);
+        let result = render_index_from_loaded(
+            &env,
+            &config,
+            "index.html",
+            vec![],
+            vec![],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -390,12 +701,22 @@ mod tests {
                 data: "# Test".to_string(),
             },
             html: "<h1>Test</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/test.html"),
         };
 
-        let result =
-            render_index_from_loaded(&env, &config, "index.html", vec![&loaded], vec![&loaded]);
+        let result = render_index_from_loaded(
+            &env,
+            &config,
+            "index.html",
+            vec![&loaded],
+            vec![&loaded],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -439,7 +760,10 @@ mod tests {
                 data: "# Old".to_string(),
             },
             html: "<h1>Old</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/old.html"),
         };
 
@@ -450,7 +774,10 @@ mod tests {
                 data: "# New".to_string(),
             },
             html: "<h1>New</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/new.html"),
         };
 
@@ -461,7 +788,10 @@ mod tests {
                 data: "# Mid".to_string(),
             },
             html: "<h1>Mid</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/mid.html"),
         };
 
@@ -472,6 +802,8 @@ mod tests {
             "index.html",
             vec![&loaded_old, &loaded_new, &loaded_mid],
             vec![&loaded_old, &loaded_new, &loaded_mid],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
         );
 
         assert!(result.is_ok());
@@ -502,12 +834,22 @@ mod tests {
                 data: "# Title\n\n## Context\n\nThis is the excerpt.".to_string(),
             },
             html: "<h1>Title</h1>".to_string(),
+            excerpt: "<p>This is the excerpt.</p>".to_string(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/test.html"),
         };
 
-        let result =
-            render_index_from_loaded(&env, &config, "index.html", vec![&loaded], vec![&loaded]);
+        let result = render_index_from_loaded(
+            &env,
+            &config,
+            "index.html",
+            vec![&loaded],
+            vec![&loaded],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -541,12 +883,22 @@ mod tests {
                 data: "# Test".to_string(),
             },
             html: "<h1>Test</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "articles".to_string(),
+            section: "articles".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/articles/2024-01-15-test.html"),
         };
 
-        let result =
-            render_index_from_loaded(&env, &config, "index.html", vec![&loaded], vec![&loaded]);
+        let result = render_index_from_loaded(
+            &env,
+            &config,
+            "index.html",
+            vec![&loaded],
+            vec![&loaded],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -590,12 +942,22 @@ mod tests {
                 data: "# Test".to_string(),
             },
             html: "<h1>Test</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "articles".to_string(),
+            section: "articles".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/articles/test.html"),
         };
 
-        let result =
-            render_index_from_loaded(&env, &config, "index.html", vec![&loaded], vec![&loaded]);
+        let result = render_index_from_loaded(
+            &env,
+            &config,
+            "index.html",
+            vec![&loaded],
+            vec![&loaded],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
+        );
 
         assert!(result.is_ok());
         let rendered = result.unwrap();
@@ -644,7 +1006,10 @@ mod tests {
                 data: "# First".to_string(),
             },
             html: "<h1>First</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/first.html"),
         };
 
@@ -655,7 +1020,10 @@ mod tests {
                 data: "# Second".to_string(),
             },
             html: "<h1>Second</h1>".to_string(),
+            excerpt: String::new(),
             content_type: "blog".to_string(),
+            section: "blog".to_string(),
+            assets: vec![],
             output_path: PathBuf::from("output/blog/second.html"),
         };
 
@@ -666,6 +1034,8 @@ mod tests {
             "index.html",
             vec![&loaded1],
             vec![&loaded1, &loaded2],
+            &ListingOrder::default(),
+            &Value::UNDEFINED,
         );
 
         assert!(result.is_ok());
@@ -675,4 +1045,199 @@ mod tests {
         assert!(rendered.contains("First Post"));
         assert!(rendered.contains("Second Post"));
     }
+
+    #[test]
+    fn test_render_index_page_slices_contents() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("index.html"),
+            r#"{{ paginator.current }}/{{ paginator.total_pages }} prev={{ paginator.prev_url | url }} next={{ paginator.next_url | url }}{% for item in paginator.contents %}|{{ item.meta.title }}{% endfor %} all={{ contents | length }}/{{ all_content | length }}"#,
+        )
+        .unwrap();
+
+        let mut env = Environment::new();
+        env.set_loader(path_loader(temp_dir.path()));
+        configure_environment(&mut env);
+        let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
+
+        let loaded: Vec<LoadedContent> = (1..=5)
+            .map(|day| {
+                let mut meta = create_test_meta();
+                meta.title = format!("Day {}", day);
+                meta.date = meta.date.replace_day(day).unwrap();
+                LoadedContent {
+                    path: PathBuf::from(format!("{}.md", day)),
+                    content: crate::content::Content {
+                        meta,
+                        data: String::new(),
+                    },
+                    html: String::new(),
+                    excerpt: String::new(),
+                    content_type: "blog".to_string(),
+                    section: "blog".to_string(),
+                    assets: vec![],
+                    output_path: PathBuf::from(format!("output/blog/{}.html", day)),
+                }
+            })
+            .collect();
+        let pages = crate::pagination::paginate(
+            "blog",
+            loaded.len(),
+            std::num::NonZeroUsize::new(2).unwrap(),
+            true,
+        );
+
+        let rendered = render_index_page(
+            &env,
+            &config,
+            "index.html",
+            &pages[1],
+            &sort_listing(&loaded.iter().collect::<Vec<_>>(), &ListingOrder::default()),
+            loaded.iter().collect(),
+            &Value::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "2/3 prev=/blog/ next=/blog/page/3/|Day 3|Day 2 all=2/5"
+        );
+    }
+
+    #[test]
+    fn test_render_html_with_section_nav() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("post.html"),
+            r#"{{ page.formatted_date }} prev={{ prev.meta.title }} next={{ next.meta.title }} siblings={% for item in siblings %}{{ item.filename | url }};{% endfor %}"#,
+        )
+        .unwrap();
+
+        let mut env = Environment::new();
+        env.set_loader(path_loader(temp_dir.path()));
+        configure_environment(&mut env);
+        let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
+
+        let loaded: Vec<LoadedContent> = (1..=3)
+            .map(|day| {
+                let mut meta = create_test_meta();
+                meta.title = format!("Day {}", day);
+                meta.date = meta.date.replace_day(day).unwrap();
+                LoadedContent {
+                    path: PathBuf::from(format!("{}.md", day)),
+                    content: crate::content::Content {
+                        meta,
+                        data: String::new(),
+                    },
+                    html: String::new(),
+                    excerpt: String::new(),
+                    content_type: "blog".to_string(),
+                    section: "blog".to_string(),
+                    assets: vec![],
+                    output_path: PathBuf::from(format!("output/blog/{}.html", day)),
+                }
+            })
+            .collect();
+        let section = Section::new(
+            &loaded.iter().collect::<Vec<_>>(),
+            &ListingOrder::default(),
+            &config,
+            Value::UNDEFINED,
+        );
+
+        let middle = &loaded[1];
+        let rendered = render_html(
+            &env,
+            "",
+            &middle.content.meta,
+            &config,
+            "post.html",
+            &section.nav(&middle.path),
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "January 02, 2024 prev=Day 3 next=Day 1 siblings=blog/3.html;blog/2.html;blog/1.html;"
+        );
+
+        // Newest page has no prev, oldest has no next
+        assert!(section.nav(&loaded[2].path).prev.is_undefined());
+        assert!(section.nav(&loaded[0].path).next.is_undefined());
+        assert!(section.nav(Path::new("missing.md")).page.is_undefined());
+    }
+
+    #[test]
+    fn test_render_taxonomy_pages() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("tags.html"),
+            r#"{% for term in terms %}<a href="{{ term.url | url }}">{{ term.name }} ({{ term.count }})</a>{% endfor %}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("tag.html"),
+            r#"{{ taxonomy }}:{{ term.name }}{% for item in contents %}|{{ item.meta.title }}{% endfor %}"#,
+        )
+        .unwrap();
+
+        let mut env = Environment::new();
+        env.set_loader(path_loader(temp_dir.path()));
+        configure_environment(&mut env);
+        let config = create_test_config(temp_dir.path().to_str().unwrap(), "output");
+
+        let mut older = create_test_meta();
+        older.title = "Older".to_string();
+        let mut newer = create_test_meta();
+        newer.title = "Newer".to_string();
+        newer.date = datetime!(2024-03-01 10:00:00 UTC);
+        let loaded: Vec<LoadedContent> = [older, newer]
+            .into_iter()
+            .map(|meta| LoadedContent {
+                path: PathBuf::from(format!("{}.md", meta.title)),
+                output_path: PathBuf::from(format!("output/blog/{}.html", meta.title)),
+                content: crate::content::Content {
+                    meta,
+                    data: String::new(),
+                },
+                html: String::new(),
+                excerpt: String::new(),
+                content_type: "blog".to_string(),
+                section: "blog".to_string(),
+                assets: vec![],
+            })
+            .collect();
+        let term = Term {
+            item: TermItem {
+                name: "Rust".to_string(),
+                slug: "rust".to_string(),
+                url: "/tags/rust/".to_string(),
+                count: 2,
+            },
+            pages: loaded.iter().collect(),
+        };
+        let taxonomy = Taxonomy {
+            name: "tags".to_string(),
+            terms: vec![term],
+        };
+
+        let index = render_taxonomy_index(
+            &env,
+            &config,
+            "tags.html",
+            &taxonomy,
+            loaded.iter().collect(),
+        )
+        .unwrap();
+        assert_eq!(index, r#"<a href="/tags/rust/">Rust (2)</a>"#);
+
+        let term_page = render_taxonomy_term(
+            &env,
+            &config,
+            "tag.html",
+            &taxonomy,
+            &taxonomy.terms[0],
+            loaded.iter().collect(),
+        )
+        .unwrap();
+        assert_eq!(term_page, "tags:Rust|Newer|Older");
+    }
 }
@@ -0,0 +1,361 @@
+// src/template_deps.rs
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Template tags that pull in another template by name
+const REFERENCE_TAGS: [&str; 4] = ["extends", "include", "import", "from"];
+
+/// A template file and the templates it references directly
+#[derive(Debug)]
+struct TemplateNode {
+    source: Vec<u8>,
+    references: Vec<String>,
+    /// References a template through an expression that cannot be resolved statically
+    dynamic: bool,
+    /// Identifiers appearing inside `{{ }}` and `{% %}` blocks
+    identifiers: BTreeSet<String>,
+}
+
+/// Dependency graph of the templates in `template_dir`.
+///
+/// Names match the minijinja path loader: paths relative to the template directory with
+/// `/` separators. Edges come from `{% extends %}`, `{% include %}`, `{% import %}` and
+/// `{% from ... import %}` tags with string literal names.
+#[derive(Debug, Default)]
+pub(crate) struct TemplateGraph {
+    templates: BTreeMap<String, TemplateNode>,
+}
+
+impl TemplateGraph {
+    /// Reads every template under `template_dir` and records its references.
+    pub(crate) fn load(template_dir: &str) -> Self {
+        let root = Path::new(template_dir);
+        let mut templates = BTreeMap::new();
+
+        for entry in WalkDir::new(root)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let Ok(relative) = entry.path().strip_prefix(root) else {
+                continue;
+            };
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let source = fs::read(entry.path()).unwrap_or_default();
+            let text = String::from_utf8_lossy(&source);
+            let (references, dynamic) = find_references(&text);
+            let identifiers = find_identifiers(&text);
+
+            templates.insert(
+                name,
+                TemplateNode {
+                    source,
+                    references,
+                    dynamic,
+                    identifiers,
+                },
+            );
+        }
+
+        Self { templates }
+    }
+
+    /// All templates `name` depends on, including itself.
+    ///
+    /// If any template in the chain references another through a variable, every
+    /// template is returned since the real dependency is only known at render time.
+    pub(crate) fn dependencies(&self, name: &str) -> BTreeSet<String> {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![name.to_string()];
+
+        while let Some(current) = stack.pop() {
+            if !seen.insert(current.clone()) {
+                continue;
+            }
+            if let Some(node) = self.templates.get(&current) {
+                if node.dynamic {
+                    return self.templates.keys().cloned().collect();
+                }
+                stack.extend(node.references.iter().cloned());
+            }
+        }
+
+        seen
+    }
+
+    /// Hash of the names and sources of every template `name` depends on.
+    ///
+    /// Changes when, and only when, a template in the dependency chain changes
+    /// (or one is added or removed).
+    pub(crate) fn hash(&self, name: &str) -> String {
+        let mut hasher = blake3::Hasher::new();
+        for dependency in self.dependencies(name) {
+            hasher.update(dependency.as_bytes());
+            match self.templates.get(&dependency) {
+                Some(node) => hasher.update(&node.source),
+                None => hasher.update(b"\0missing"),
+            };
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Whether `name` or any template it depends on mentions one of `variables`.
+    ///
+    /// Conservative: a missing template, or a matching attribute or string, counts
+    /// as a use.
+    pub(crate) fn uses_any(&self, name: &str, variables: &[&str]) -> bool {
+        self.dependencies(name)
+            .iter()
+            .any(|dependency| match self.templates.get(dependency) {
+                Some(node) => variables.iter().any(|v| node.identifiers.contains(*v)),
+                None => true,
+            })
+    }
+}
+
+/// Collects the identifiers used inside a template's expression and statement blocks.
+fn find_identifiers(source: &str) -> BTreeSet<String> {
+    let mut identifiers = BTreeSet::new();
+    let mut rest = source;
+
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let close = match after.chars().next() {
+            Some('{') => "}}",
+            Some('%') => "%}",
+            _ => {
+                rest = after;
+                continue;
+            }
+        };
+        let Some(end) = after[1..].find(close) else {
+            break;
+        };
+        let block = &after[1..end + 1];
+        rest = &after[end + 1 + close.len()..];
+
+        identifiers.extend(
+            block
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .filter(|word| !word.is_empty())
+                .map(str::to_string),
+        );
+    }
+
+    identifiers
+}
+
+/// Extracts the template names referenced by a template's tags.
+///
+/// Returns the names and whether any reference used a non-literal expression.
+fn find_references(source: &str) -> (Vec<String>, bool) {
+    let mut references = Vec::new();
+    let mut dynamic = false;
+    let mut rest = source;
+
+    while let Some(start) = rest.find("{%") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("%}") else {
+            break;
+        };
+        let tag = after[..end].trim_matches(|c: char| c == '-' || c == '+' || c.is_whitespace());
+        rest = &after[end + 2..];
+
+        let mut words = tag.splitn(2, char::is_whitespace);
+        let keyword = words.next().unwrap_or_default();
+        if !REFERENCE_TAGS.contains(&keyword) {
+            continue;
+        }
+        let mut arguments = words.next().unwrap_or_default();
+
+        // `import "x" as y` / `from "x" import y`: only the part before the binding names
+        if keyword == "import" {
+            arguments = arguments.split(" as ").next().unwrap_or_default();
+        } else if keyword == "from" {
+            arguments = arguments.split(" import ").next().unwrap_or_default();
+        }
+
+        let names = string_literals(arguments);
+        if names.is_empty() {
+            dynamic = true;
+        }
+        references.extend(names);
+    }
+
+    (references, dynamic)
+}
+
+/// Collects the contents of single- or double-quoted string literals.
+fn string_literals(expression: &str) -> Vec<String> {
+    let mut literals = Vec::new();
+    let mut chars = expression.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '"' && c != '\'' {
+            continue;
+        }
+        let mut literal = String::new();
+        for inner in chars.by_ref() {
+            if inner == c {
+                break;
+            }
+            literal.push(inner);
+        }
+        literals.push(literal);
+    }
+
+    literals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_templates(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let temp_dir = tempdir().unwrap();
+        for (name, source) in files {
+            let path = temp_dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+        temp_dir
+    }
+
+    fn set(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_find_references_tags() {
+        let source = r#"
+{% extends "base.html" %}
+{%- include 'partials/nav.html' -%}
+{% import "macros.html" as m %}
+{% from "forms.html" import input, "unused" %}
+{% include ["a.html", "b.html"] ignore missing %}
+{% if x %}{{ "not-a-template.html" }}{% endif %}
+"#;
+        let (references, dynamic) = find_references(source);
+
+        assert_eq!(
+            references,
+            vec![
+                "base.html",
+                "partials/nav.html",
+                "macros.html",
+                "forms.html",
+                "a.html",
+                "b.html"
+            ]
+        );
+        assert!(!dynamic);
+    }
+
+    #[test]
+    fn test_find_references_dynamic() {
+        let (references, dynamic) = find_references("{% extends layout %}");
+        assert!(references.is_empty());
+        assert!(dynamic);
+    }
+
+    #[test]
+    fn test_dependencies_follow_chains() {
+        let dir = write_templates(&[
+            (
+                "base.html",
+                "{% include 'partials/nav.html' %}{% block body %}{% endblock %}",
+            ),
+            ("partials/nav.html", "<nav></nav>"),
+            ("blog.html", "{% extends 'base.html' %}"),
+            ("page.html", "<p>standalone</p>"),
+        ]);
+        let graph = TemplateGraph::load(dir.path().to_str().unwrap());
+
+        assert_eq!(
+            graph.dependencies("blog.html"),
+            set(&["blog.html", "base.html", "partials/nav.html"])
+        );
+        assert_eq!(graph.dependencies("page.html"), set(&["page.html"]));
+    }
+
+    #[test]
+    fn test_dependencies_handle_cycles() {
+        let dir = write_templates(&[
+            ("a.html", "{% include 'b.html' %}"),
+            ("b.html", "{% include 'a.html' %}"),
+        ]);
+        let graph = TemplateGraph::load(dir.path().to_str().unwrap());
+
+        assert_eq!(graph.dependencies("a.html"), set(&["a.html", "b.html"]));
+    }
+
+    #[test]
+    fn test_dynamic_reference_depends_on_everything() {
+        let dir = write_templates(&[
+            ("blog.html", "{% extends layout %}"),
+            ("base.html", ""),
+            ("other.html", ""),
+        ]);
+        let graph = TemplateGraph::load(dir.path().to_str().unwrap());
+
+        assert_eq!(
+            graph.dependencies("blog.html"),
+            set(&["base.html", "blog.html", "other.html"])
+        );
+    }
+
+    #[test]
+    fn test_hash_changes_only_with_dependencies() {
+        let dir = write_templates(&[
+            ("base.html", "base"),
+            ("blog.html", "{% extends 'base.html' %}"),
+            ("page.html", "page"),
+        ]);
+        let template_dir = dir.path().to_str().unwrap();
+        let before = TemplateGraph::load(template_dir);
+
+        fs::write(dir.path().join("base.html"), "base v2").unwrap();
+        let after = TemplateGraph::load(template_dir);
+
+        assert_ne!(before.hash("blog.html"), after.hash("blog.html"));
+        assert_eq!(before.hash("page.html"), after.hash("page.html"));
+    }
+
+    #[test]
+    fn test_hash_tracks_missing_template_being_added() {
+        let dir = write_templates(&[("blog.html", "{% include 'nav.html' ignore missing %}")]);
+        let template_dir = dir.path().to_str().unwrap();
+        let before = TemplateGraph::load(template_dir);
+
+        fs::write(dir.path().join("nav.html"), "<nav></nav>").unwrap();
+        let after = TemplateGraph::load(template_dir);
+
+        assert_ne!(before.hash("blog.html"), after.hash("blog.html"));
+    }
+
+    #[test]
+    fn test_uses_any_follows_dependencies() {
+        let dir = write_templates(&[
+            (
+                "base.html",
+                "{% if prev %}<a href=\"{{ prev.filename }}\">next page</a>{% endif %}",
+            ),
+            ("blog.html", "{% extends 'base.html' %}"),
+            ("page.html", "<p>next</p>{{ meta.title }}"),
+        ]);
+        let graph = TemplateGraph::load(dir.path().to_str().unwrap());
+
+        assert!(graph.uses_any("blog.html", &["prev", "next"]));
+        // Words outside template blocks do not count
+        assert!(!graph.uses_any("page.html", &["prev", "next"]));
+        assert!(graph.uses_any("missing.html", &["prev"]));
+    }
+}
@@ -0,0 +1,233 @@
+// src/toc.rs
+
+use serde::Serialize;
+
+use crate::config::TocConfig;
+use crate::utils::html_to_text;
+
+/// Markdown paragraph replaced by the rendered table of contents
+pub(crate) const TOC_MARKER: &str = "[[toc]]";
+
+/// A heading in a page's table of contents, exposed to templates as `toc`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct TocEntry {
+    /// Heading level (1-6)
+    pub(crate) level: u8,
+    /// Heading text without markup (e.g., "cargo run")
+    pub(crate) text: String,
+    /// The heading's `id`, for `#fragment` links
+    pub(crate) id: String,
+    /// Deeper headings up to the next heading of this level or above
+    pub(crate) children: Vec<TocEntry>,
+}
+
+/// Builds the nested table of contents of rendered page HTML.
+///
+/// Reads the headings that carry an `id` (added during conversion, see
+/// [`crate::utils::add_header_ids`]) and keeps those within
+/// `min_level..=max_level`. A heading becomes a child of the closest preceding
+/// heading of a higher level, so skipped levels (an `h4` right under an `h2`)
+/// still nest.
+pub(crate) fn extract_toc(html: &str, config: &TocConfig) -> Vec<TocEntry> {
+    let mut toc = vec![];
+    for entry in headings(html) {
+        if (config.min_level..=config.max_level).contains(&entry.level) {
+            insert(&mut toc, entry);
+        }
+    }
+    toc
+}
+
+/// Adds an entry below the last sibling while that one is of a higher level.
+fn insert(siblings: &mut Vec<TocEntry>, entry: TocEntry) {
+    match siblings.last_mut() {
+        Some(last) if last.level < entry.level => insert(&mut last.children, entry),
+        _ => siblings.push(entry),
+    }
+}
+
+/// The `<hN id="...">` headings of `html`, in document order and without children.
+fn headings(html: &str) -> Vec<TocEntry> {
+    let mut entries = vec![];
+    let mut remaining = html;
+
+    while let Some(start) = remaining.find("<h") {
+        remaining = &remaining[start + 2..];
+        let Some(level @ b'1'..=b'6') = remaining.bytes().next() else {
+            continue;
+        };
+        let Some(attrs) = remaining[1..].strip_prefix(" id=\"") else {
+            continue;
+        };
+        let Some(id_end) = attrs.find('"') else {
+            break;
+        };
+        let close_tag = format!("</h{}>", level as char);
+        let (Some(open_end), Some(close)) = (attrs.find('>'), attrs.find(&close_tag)) else {
+            break;
+        };
+        if open_end < close {
+            entries.push(TocEntry {
+                level: level - b'0',
+                text: html_to_text(&attrs[open_end + 1..close]),
+                id: attrs[..id_end].to_string(),
+                children: vec![],
+            });
+        }
+        remaining = &attrs[close..];
+    }
+
+    entries
+}
+
+/// Renders a table of contents as nested lists of links to the headings, or
+/// nothing when it is empty.
+///
+/// ```html
+/// <nav class="toc"><ul><li><a href="#setup">Setup</a><ul>...</ul></li></ul></nav>
+/// ```
+pub(crate) fn render_toc(toc: &[TocEntry]) -> String {
+    if toc.is_empty() {
+        return String::new();
+    }
+    format!("<nav class=\"toc\">{}</nav>", render_list(toc))
+}
+
+fn render_list(entries: &[TocEntry]) -> String {
+    let items: String = entries
+        .iter()
+        .map(|entry| {
+            let children = if entry.children.is_empty() {
+                String::new()
+            } else {
+                render_list(&entry.children)
+            };
+            format!(
+                "<li><a href=\"#{}\">{}</a>{}</li>",
+                entry.id,
+                escape_html(&entry.text),
+                children
+            )
+        })
+        .collect();
+    format!("<ul>{}</ul>", items)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Puts the rendered table of contents into the page body.
+///
+/// It replaces a [`TOC_MARKER`] paragraph. Without a marker it goes at the top of
+/// the page, after a leading `<h1>`, when `always` is set (the page's `toc`
+/// metadata field); otherwise the HTML is returned unchanged.
+pub(crate) fn insert_toc(html: &str, always: bool, config: &TocConfig) -> String {
+    let marker = format!("<p>{}</p>", TOC_MARKER);
+    let has_marker = html.contains(&marker);
+    if !has_marker && !always {
+        return html.to_string();
+    }
+
+    let rendered = render_toc(&extract_toc(html, config));
+    if has_marker {
+        return html.replace(&marker, &rendered);
+    }
+
+    let title_end = html
+        .trim_start()
+        .starts_with("<h1")
+        .then(|| html.find("</h1>"))
+        .flatten()
+        .map_or(0, |end| end + "</h1>".len());
+    format!("{}{}{}", &html[..title_end], rendered, &html[title_end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = concat!(
+        "<h1 id=\"guide\">Guide</h1>\n",
+        "<p>Intro.</p>\n",
+        "<h2 id=\"setup\"><a href=\"#setup\">Setup</a></h2>\n",
+        "<h3 id=\"cargo-run\"><code>cargo</code> run</h3>\n",
+        "<h4 id=\"flags\">Flags</h4>\n",
+        "<h2 id=\"q-amp-a\">Q &amp; A</h2>\n",
+        "<h4 id=\"deep\">Deep</h4>\n",
+    );
+
+    fn entry(level: u8, text: &str, id: &str, children: Vec<TocEntry>) -> TocEntry {
+        TocEntry {
+            level,
+            text: text.to_string(),
+            id: id.to_string(),
+            children,
+        }
+    }
+
+    #[test]
+    fn test_extract_toc_nests_headings_within_levels() {
+        let toc = extract_toc(HTML, &TocConfig::default());
+        assert_eq!(
+            toc,
+            vec![
+                entry(
+                    2,
+                    "Setup",
+                    "setup",
+                    vec![entry(3, "cargo run", "cargo-run", vec![])]
+                ),
+                entry(2, "Q & A", "q-amp-a", vec![]),
+            ]
+        );
+
+        // Skipped levels nest under the closest higher heading
+        let config = TocConfig {
+            min_level: 1,
+            max_level: 6,
+        };
+        let toc = extract_toc(HTML, &config);
+        assert_eq!(toc.len(), 1);
+        assert_eq!(
+            toc[0].children[1].children,
+            vec![entry(4, "Deep", "deep", vec![])]
+        );
+    }
+
+    #[test]
+    fn test_render_toc() {
+        let toc = vec![
+            entry(2, "Setup", "setup", vec![entry(3, "Run", "run", vec![])]),
+            entry(2, "Q & A", "q-amp-a", vec![]),
+        ];
+        assert_eq!(
+            render_toc(&toc),
+            "<nav class=\"toc\"><ul><li><a href=\"#setup\">Setup</a><ul><li><a href=\"#run\">Run</a>\
+             </li></ul></li><li><a href=\"#q-amp-a\">Q &amp; A</a></li></ul></nav>"
+        );
+        assert_eq!(render_toc(&[]), "");
+    }
+
+    #[test]
+    fn test_insert_toc() {
+        let config = TocConfig::default();
+        let html = "<h1 id=\"title\">Title</h1>\n<p>[[toc]]</p>\n<h2 id=\"a\">A</h2>";
+        let expected = "<h1 id=\"title\">Title</h1>\n\
+                        <nav class=\"toc\"><ul><li><a href=\"#a\">A</a></li></ul></nav>\n\
+                        <h2 id=\"a\">A</h2>";
+        assert_eq!(insert_toc(html, false, &config), expected);
+
+        // `toc = true` puts it after the title, otherwise nothing changes
+        let html = "<h1 id=\"title\">Title</h1>\n<h2 id=\"a\">A</h2>";
+        assert_eq!(
+            insert_toc(html, true, &config),
+            "<h1 id=\"title\">Title</h1><nav class=\"toc\"><ul><li><a href=\"#a\">A</a></li></ul></nav>\n\
+             <h2 id=\"a\">A</h2>"
+        );
+        assert_eq!(insert_toc(html, false, &config), html);
+        assert!(insert_toc("<h2 id=\"a\">A</h2>", true, &config).starts_with("<nav"));
+    }
+}
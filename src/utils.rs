@@ -1,11 +1,15 @@
 // src/utils.rs
 
+use serde::Serializer;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
 use walkdir::WalkDir;
 
+use crate::bundle::bundle_dir;
 use crate::config::Config;
+use crate::content::ContentMeta;
 
 /// Converts text to a URL-friendly slug for use in HTML IDs and URL fragments.
 ///
@@ -94,24 +98,41 @@ pub(crate) fn extract_stem_from_filename(filename: &str) -> String {
         .or_else(|| filename.strip_suffix(".html"))
         .unwrap_or(filename);
 
-    // Check for date prefix pattern: YYYY-MM-DD-
-    // Date prefix is exactly 11 characters: 4 digits + hyphen + 2 digits + hyphen + 2 digits + hyphen
-    if name.len() > 11 {
-        let potential_date = &name[..11];
-        // Check if it matches YYYY-MM-DD- pattern
-        if potential_date.len() == 11
-            && potential_date.chars().nth(4) == Some('-')
-            && potential_date.chars().nth(7) == Some('-')
-            && potential_date.chars().nth(10) == Some('-')
-            && potential_date[..4].chars().all(|c| c.is_ascii_digit())
-            && potential_date[5..7].chars().all(|c| c.is_ascii_digit())
-            && potential_date[8..10].chars().all(|c| c.is_ascii_digit())
-        {
-            return name[11..].to_string();
-        }
+    match extract_date_from_filename(name) {
+        Some(date) => name[date.len() + 1..].to_string(),
+        None => name.to_string(),
     }
+}
 
-    name.to_string()
+/// Extracts the `YYYY-MM-DD` date prefix from a filename, if it has one.
+///
+/// The prefix must be followed by a hyphen and a non-empty name, matching the
+/// prefixes [`extract_stem_from_filename`] strips.
+///
+/// # Examples
+/// ```
+/// use your_crate::extract_date_from_filename;
+///
+/// assert_eq!(extract_date_from_filename("2025-12-29-my-article.md"), Some("2025-12-29"));
+/// assert_eq!(extract_date_from_filename("my-article.md"), None);
+/// ```
+pub(crate) fn extract_date_from_filename(filename: &str) -> Option<&str> {
+    // Date prefix is exactly 11 characters: 4 digits + hyphen + 2 digits + hyphen + 2 digits + hyphen
+    let potential_date = filename.get(..11).filter(|_| filename.len() > 11)?;
+    let bytes = potential_date.as_bytes();
+    let is_digits = |range: std::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_digit);
+
+    if bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes[10] == b'-'
+        && is_digits(0..4)
+        && is_digits(5..7)
+        && is_digits(8..10)
+    {
+        Some(&potential_date[..10])
+    } else {
+        None
+    }
 }
 
 /// Strips HTML tags from a string, returning only the text content.
@@ -140,6 +161,215 @@ fn strip_html_tags(html: &str) -> String {
     result
 }
 
+/// Converts an HTML fragment to plain text: tags are dropped, the common entities
+/// decoded and runs of whitespace collapsed to single spaces.
+///
+/// # Examples
+/// ```
+/// use your_crate::html_to_text;
+///
+/// assert_eq!(html_to_text("<p>Fish &amp; <em>chips</em></p>\n<p>Tea</p>"), "Fish & chips Tea");
+/// ```
+pub(crate) fn html_to_text(html: &str) -> String {
+    let text = strip_html_tags(html)
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Counts the words a reader reads in a markdown body.
+///
+/// Fenced code blocks and HTML tags are skipped, as are tokens without a letter or
+/// digit (list markers, `#`, `|`). Chinese and Japanese text has no spaces between
+/// words, so each Han, Hiragana or Katakana character counts as a word.
+///
+/// # Examples
+/// ```
+/// use your_crate::count_words;
+///
+/// assert_eq!(count_words("## Hello *world*\n\n```\nlet skipped = 1;\n```\n"), 2);
+/// assert_eq!(count_words("Rust 语言"), 3);
+/// ```
+pub(crate) fn count_words(markdown: &str) -> usize {
+    lines_outside_fences(markdown)
+        .map(|(_, line)| count_text_words(&strip_html_tags(line)))
+        .sum()
+}
+
+/// Byte range of the first line of a markdown body that holds only `marker`,
+/// with its line ending.
+///
+/// Lines in fenced code blocks never match, so code samples can show the marker.
+///
+/// # Examples
+/// ```
+/// use your_crate::find_marker_line;
+///
+/// assert_eq!(find_marker_line("Intro\n<!-- more -->\nRest", "<!-- more -->"), Some(6..20));
+/// assert_eq!(find_marker_line("```\n<!-- more -->\n```\n", "<!-- more -->"), None);
+/// ```
+pub(crate) fn find_marker_line(markdown: &str, marker: &str) -> Option<Range<usize>> {
+    lines_outside_fences(markdown)
+        .find(|(_, line)| line.trim() == marker)
+        .map(|(start, line)| start..start + line.len())
+}
+
+/// Lines of a markdown body (with their line endings) and their byte offsets,
+/// leaving out fenced code blocks and their fences.
+fn lines_outside_fences(markdown: &str) -> impl Iterator<Item = (usize, &str)> {
+    // Fence character and length of the open code block
+    let mut fence: Option<(char, usize)> = None;
+    let mut offset = 0;
+
+    markdown.split_inclusive('\n').filter_map(move |line| {
+        let start = offset;
+        offset += line.len();
+
+        let trimmed = line.trim_start();
+        let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+        let run = marker.map_or(0, |c| trimmed.chars().take_while(|&x| x == c).count());
+
+        match (fence, marker) {
+            (None, Some(c)) if run >= 3 => fence = Some((c, run)),
+            (Some((open, len)), Some(c))
+                if c == open && run >= len && trimmed[run..].trim().is_empty() =>
+            {
+                fence = None
+            }
+            (Some(_), _) => {}
+            (None, _) => return Some((start, line)),
+        }
+        None
+    })
+}
+
+/// Counts the words of plain text: runs of letters and digits, plus one per CJK
+/// character.
+pub(crate) fn count_text_words(text: &str) -> usize {
+    let mut words = 0;
+    let mut in_word = false;
+    for c in text.chars() {
+        if is_cjk(c) {
+            words += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if c.is_whitespace() {
+            in_word = false;
+        }
+    }
+    words
+}
+
+/// Han ideographs and Japanese kana, written without spaces between words.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'     // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}'   // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}'   // CJK Compatibility Ideographs
+        | '\u{FF66}'..='\u{FF9D}'   // Halfwidth Katakana
+        | '\u{20000}'..='\u{2FA1F}' // Extensions B-F, Compatibility Supplement
+    )
+}
+
+/// Elements without a closing tag
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "meta", "source"];
+
+/// Shortens an HTML fragment to its first `max_words` words, appending "…" when
+/// anything is cut.
+///
+/// Words are counted as in [`count_text_words`], so each CJK character is one.
+/// Cuts only between words, never inside a tag or character reference, and closes
+/// the elements still open at the cut so the result stays well-formed.
+///
+/// # Examples
+/// ```
+/// use your_crate::truncate_html;
+///
+/// assert_eq!(truncate_html("<p>One <em>two three</em> four</p>", 2), "<p>One <em>two…</em></p>");
+/// ```
+pub(crate) fn truncate_html(html: &str, max_words: usize) -> String {
+    let mut result = String::with_capacity(html.len().min(max_words * 16));
+    let mut open: Vec<&str> = vec![];
+    // Output length and open elements right after the last kept word
+    let mut cut: Option<(usize, Vec<&str>)> = None;
+    let mut words = 0;
+    let mut in_word = false;
+    let mut remaining = html;
+
+    while let Some(c) = remaining.chars().next() {
+        if c == '<' {
+            let end = remaining.find('>').map_or(remaining.len(), |i| i + 1);
+            let tag = &remaining[..end];
+            if tag.starts_with("</") {
+                open.pop();
+            } else if !tag.starts_with("<!") && !tag.ends_with("/>") {
+                let name = tag[1..]
+                    .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+                    .next()
+                    .unwrap_or_default();
+                if !VOID_ELEMENTS.contains(&name) {
+                    open.push(name);
+                }
+            }
+            result.push_str(tag);
+            remaining = &remaining[end..];
+            continue;
+        }
+
+        // A character reference is one character, and `&amp;` is no word
+        let len = match c {
+            '&' => remaining
+                .find(';')
+                .filter(|&end| {
+                    end <= 10
+                        && remaining[1..end]
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '#')
+                })
+                .map_or(1, |end| end + 1),
+            _ => c.len_utf8(),
+        };
+        let token = &remaining[..len];
+
+        if c.is_whitespace() || token == "&nbsp;" {
+            if in_word && words == max_words {
+                cut = Some((result.len(), open.clone()));
+            }
+            in_word = false;
+        } else if is_cjk(c) || (c.is_alphanumeric() && !in_word) {
+            if words == max_words {
+                let (len, open) = cut.take().unwrap_or_else(|| (result.len(), open.clone()));
+                result.truncate(len);
+                result.push('…');
+                for name in open.iter().rev() {
+                    result.push_str(&format!("</{}>", name));
+                }
+                return result;
+            }
+            words += 1;
+            // Each CJK character is a word of its own
+            in_word = !is_cjk(c);
+        }
+        result.push_str(token);
+        if is_cjk(c) && words == max_words {
+            cut = Some((result.len(), open.clone()));
+        }
+        remaining = &remaining[len..];
+    }
+
+    result
+}
+
 /// Adds anchor links to HTML headers (h1-h6) for URL fragment navigation.
 ///
 /// Transforms headers like `<h2>My Section</h2>` into:
@@ -153,6 +383,20 @@ fn strip_html_tags(html: &str) -> String {
 /// # Returns
 /// The HTML with anchor links added to all headers
 pub(crate) fn add_header_anchors(html: &str) -> String {
+    add_header_slugs(html, true)
+}
+
+/// Adds `id` attributes to HTML headers (h1-h6) without visible anchor links.
+///
+/// Uses the same slugs as [`add_header_anchors`], so tables of contents and
+/// `#fragment` links work whether or not `header_uri_fragment` is enabled.
+pub(crate) fn add_header_ids(html: &str) -> String {
+    add_header_slugs(html, false)
+}
+
+/// Gives every header a unique slug `id`, wrapping its content in a link to
+/// itself when `links` is set.
+fn add_header_slugs(html: &str, links: bool) -> String {
     let mut result = String::with_capacity(html.len() + html.len() / 4);
     let mut slug_counts: HashMap<String, usize> = HashMap::new();
     let mut remaining = html;
@@ -191,11 +435,18 @@ pub(crate) fn add_header_anchors(html: &str) -> String {
                     };
                     *slug_counts.entry(base_slug).or_insert(0) += 1;
 
-                    // Build the new header with anchor
-                    result.push_str(&format!(
-                        "<h{} id=\"{}\"><a href=\"#{}\">{}</a></h{}>",
-                        level, slug, slug, content, level
-                    ));
+                    // Build the new header with its id (and anchor)
+                    if links {
+                        result.push_str(&format!(
+                            "<h{} id=\"{}\"><a href=\"#{}\">{}</a></h{}>",
+                            level, slug, slug, content, level
+                        ));
+                    } else {
+                        result.push_str(&format!(
+                            "<h{} id=\"{}\">{}</h{}>",
+                            level, slug, content, level
+                        ));
+                    }
 
                     remaining = &remaining[close_pos + close_tag.len()..];
                     continue;
@@ -215,6 +466,76 @@ pub(crate) fn add_header_anchors(html: &str) -> String {
     result
 }
 
+/// Rewrites the values of `href` and `src` attributes in HTML.
+///
+/// `rewrite` receives each attribute value and returns its replacement, or `None`
+/// to keep it. Only double-quoted values, as produced by the markdown renderer, are
+/// seen.
+///
+/// # Examples
+/// ```
+/// use your_crate::rewrite_link_attributes;
+///
+/// let html = r#"<a href="old.html">Old</a>"#;
+/// let result = rewrite_link_attributes(html, |href| (href == "old.html").then(|| "new.html".into()));
+/// assert_eq!(result, r#"<a href="new.html">Old</a>"#);
+/// ```
+pub(crate) fn rewrite_link_attributes(
+    html: &str,
+    mut rewrite: impl FnMut(&str) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut remaining = html;
+
+    while let Some(start) = find_link_attribute(remaining) {
+        result.push_str(&remaining[..start]);
+        remaining = &remaining[start..];
+
+        let Some(end) = remaining.find('"') else {
+            break;
+        };
+        match rewrite(&remaining[..end]) {
+            Some(value) => result.push_str(&value),
+            None => result.push_str(&remaining[..end]),
+        }
+        remaining = &remaining[end..];
+    }
+
+    result.push_str(remaining);
+    result
+}
+
+/// Position just after the opening quote of the next `src="` or `href="` attribute.
+fn find_link_attribute(html: &str) -> Option<usize> {
+    [" src=\"", " href=\""]
+        .iter()
+        .filter_map(|attr| html.find(attr).map(|pos| pos + attr.len()))
+        .min()
+}
+
+/// Percent-decodes a URL path (`my%20post.md` → `my post.md`).
+///
+/// Invalid escapes are kept verbatim; invalid UTF-8 is replaced lossily.
+pub(crate) fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && let Ok(hex) = std::str::from_utf8(&bytes[i + 1..i + 3])
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
 /// Extracts the content type from a file path relative to the content directory.
 ///
 /// The content type is determined by the first directory component after stripping
@@ -242,16 +563,51 @@ pub(crate) fn add_header_anchors(html: &str) -> String {
 /// - If the file is not under the content directory, returns "page"
 /// - If the file is directly in the content directory (no subdirectory), returns "page"
 /// - The content directory prefix is stripped case-sensitively
+/// - A page bundle counts as a file named after its directory (see [`bundle_dir`])
 #[rustfmt::skip]
 pub(crate) fn get_content_type(file: &Path, content_dir: &str) -> String {
+    let file = bundle_dir(file, content_dir).unwrap_or(file);
     file.strip_prefix(content_dir)                              // removes src/content
         .ok()                                                   // Convert Result to Option
-        .and_then(|rel_path| rel_path.components().next())      // gets the next dir (projects)
+        .and_then(Path::parent)                                 // drops the file name
+        .and_then(|rel_dir| rel_dir.components().next())        // gets the next dir (projects)
         .and_then(|comp| comp.as_os_str().to_str())             // converts  that to &str
         .unwrap_or("page")                                      // or gets "page"
         .to_string()                                            // as  string
 }
 
+/// Extracts the section of a content file: its directory relative to the content
+/// directory, with `/` separators (e.g., "docs/guides").
+///
+/// Files directly in the content directory (or outside it) belong to the root
+/// section `""`. A page bundle belongs to the section holding its directory (see
+/// [`bundle_dir`]).
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use your_crate::get_section;
+///
+/// let file = PathBuf::from("content/docs/guides/install.md");
+/// assert_eq!(get_section(&file, "content"), "docs/guides");
+///
+/// let bundle = PathBuf::from("content/blog/my-trip/index.md");
+/// assert_eq!(get_section(&bundle, "content"), "blog");
+/// ```
+pub(crate) fn get_section(file: &Path, content_dir: &str) -> String {
+    let file = bundle_dir(file, content_dir).unwrap_or(file);
+    file.strip_prefix(content_dir)
+        .ok()
+        .and_then(Path::parent)
+        .map(|dir| {
+            dir.components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default()
+}
+
 /// Recursively finds all markdown files in the specified content directory.
 ///
 /// This function traverses the directory tree starting from `content_dir` and
@@ -288,6 +644,7 @@ pub(crate) fn get_content_type(file: &Path, content_dir: &str) -> String {
 /// - The function returns an empty vector if the directory doesn't exist or
 ///   contains no markdown files
 /// - Hidden files and directories (starting with `.`) are included in the search
+/// - Section index files ([`SECTION_INDEX_FILE`]) are skipped, see [`find_section_indexes`]
 pub(crate) fn find_markdown_files(content_dir: &str) -> Vec<PathBuf> {
     let mut markdown_files = Vec::new();
 
@@ -298,6 +655,7 @@ pub(crate) fn find_markdown_files(content_dir: &str) -> Vec<PathBuf> {
         if entry.file_type().is_file()
             && let Some(ext) = path.extension()
             && (ext == "md" || ext == "markdown")
+            && entry.file_name() != SECTION_INDEX_FILE
         {
             markdown_files.push(path.to_path_buf());
         }
@@ -306,6 +664,20 @@ pub(crate) fn find_markdown_files(content_dir: &str) -> Vec<PathBuf> {
     markdown_files
 }
 
+/// File holding a section's intro content and metadata (e.g., `content/blog/_index.md`)
+pub(crate) const SECTION_INDEX_FILE: &str = "_index.md";
+
+/// Recursively finds the section index files ([`SECTION_INDEX_FILE`]) in the
+/// content directory.
+pub(crate) fn find_section_indexes(content_dir: &str) -> Vec<PathBuf> {
+    WalkDir::new(content_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file() && entry.file_name() == SECTION_INDEX_FILE)
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
 /// Resolves a URL pattern by replacing placeholders with actual values.
 ///
 /// Supports the following placeholders:
@@ -351,12 +723,12 @@ pub(crate) fn resolve_url_pattern(pattern: &str, filename: &str, date: &OffsetDa
 
 /// Builds the output file path from a resolved URL pattern.
 ///
-/// Combines the output directory, content type, and resolved pattern to create
+/// Combines the output directory, section, and resolved pattern to create
 /// the final output path. When `clean_urls` is enabled, appends `/index.html`;
 /// otherwise appends `.html`.
 ///
 /// # Arguments
-/// * `content_type` - The content type directory (e.g., "articles", "blog")
+/// * `section` - The section directory (e.g., "articles", "docs/guides")
 /// * `resolved_pattern` - The resolved URL pattern (e.g., "2025-12-12/my-article")
 /// * `output_dir` - The output directory (e.g., "dist")
 /// * `clean_urls` - Whether to use clean URL structure
@@ -375,13 +747,13 @@ pub(crate) fn resolve_url_pattern(pattern: &str, filename: &str, date: &OffsetDa
 /// assert_eq!(path, PathBuf::from("dist/articles/2025-12-12/my-article/index.html"));
 /// ```
 pub(crate) fn build_output_path(
-    content_type: &str,
+    section: &str,
     resolved_pattern: &str,
     output_dir: &str,
     clean_urls: bool,
 ) -> PathBuf {
     let base = PathBuf::from(output_dir)
-        .join(content_type)
+        .join(section)
         .join(resolved_pattern);
 
     if clean_urls {
@@ -391,18 +763,20 @@ pub(crate) fn build_output_path(
     }
 }
 
-/// Retrieves the template path for a specific content type from the configuration.
+/// Retrieves the template path for a specific section from the configuration.
 ///
-/// This function looks up the configured template for a given content type in the
-/// site configuration. If the content type is not configured or doesn't exist,
-/// it falls back to the default template.
+/// This function looks up the configured template for a given section in the
+/// site configuration, falling back to the nearest configured parent section
+/// (see [`Config::section_config`]). Root-level pages (section `""`) use
+/// `site.page_template`. If no template is configured, it falls back to the
+/// default template.
 ///
 /// # Arguments
 /// * `config` - Reference to the site configuration containing content type definitions
-/// * `content_type` - The content type to look up (e.g., "blog", "projects", "page")
+/// * `section` - The section to look up (e.g., "blog", "docs/guides")
 ///
 /// # Returns
-/// A string containing the template file path for the specified content type.
+/// A string containing the template file path for the specified section.
 /// Returns "default.html" if the content type is not found in the configuration.
 ///
 /// # Examples
@@ -450,15 +824,59 @@ pub(crate) fn build_output_path(
 /// - Content type lookup is case-sensitive
 /// - The fallback template "default.html" should exist in the template directory
 /// - This function only returns the template path; it does not validate if the template file exists
-pub(crate) fn get_content_type_template(config: &Config, content_type: &str) -> String {
-    config
-        .content
-        .get(content_type)
-        .map(|ct| ct.content_template.as_str())
+pub(crate) fn get_content_type_template(config: &Config, section: &str) -> String {
+    let template = match config.section_config(section) {
+        Some(ct) => Some(&ct.content_template),
+        None if section.is_empty() => config.site.page_template.as_ref(),
+        None => None,
+    };
+
+    template
+        .map(String::as_str)
         .unwrap_or("default.html") // fallback template
         .to_string()
 }
 
+/// Returns the template used to render a single page.
+///
+/// A `template` set in the page's metadata takes precedence over the section's
+/// `content_template` (see [`get_content_type_template`]).
+pub(crate) fn get_page_template(config: &Config, meta: &ContentMeta, section: &str) -> String {
+    meta.template
+        .clone()
+        .unwrap_or_else(|| get_content_type_template(config, section))
+}
+
+/// Serializes a table of TOML values with their native types for templates.
+///
+/// Datetimes become RFC 3339 strings; serialized as-is they would show up in
+/// templates as an internal wrapper table.
+pub(crate) fn serialize_toml_map<S: Serializer>(
+    map: &HashMap<String, toml::Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    fn plain(value: &toml::Value) -> toml::Value {
+        match value {
+            toml::Value::Datetime(datetime) => toml::Value::String(datetime.to_string()),
+            toml::Value::Array(items) => toml::Value::Array(items.iter().map(plain).collect()),
+            toml::Value::Table(table) => {
+                toml::Value::Table(table.iter().map(|(k, v)| (k.clone(), plain(v))).collect())
+            }
+            other => other.clone(),
+        }
+    }
+
+    serializer.collect_map(map.iter().map(|(key, value)| (key, plain(value))))
+}
+
+/// Checks whether `path` lies inside `dir`.
+///
+/// Watch backends report paths either as configured (relative) or absolute and
+/// canonicalized, so both forms of `dir` are checked.
+pub(crate) fn path_within(path: &Path, dir: &Path) -> bool {
+    path.starts_with(dir) || dir.canonicalize().is_ok_and(|d| path.starts_with(d))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::config::ContentTypeConfig;
@@ -480,6 +898,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: Some("default".to_string()),
                 rss_include: None,
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
         content_types.insert(
@@ -490,6 +912,10 @@ mod tests {
                 url_pattern: None,
                 output_naming: Some("default".to_string()),
                 rss_include: None,
+                paginate_by: None,
+                sort_by: None,
+                sort_order: None,
+                excerpt: None,
             },
         );
 
@@ -515,10 +941,19 @@ mod tests {
                 rss_full_content: false,
                 asset_hashing_enabled: false,
                 asset_manifest_path: None,
+                not_found_template: None,
+                timezone: None,
+                paginate_by: None,
+                page_template: None,
+                broken_links: crate::config::BrokenLinks::Warn,
+                excerpt: crate::config::ExcerptConfig::default(),
+                toc: crate::config::TocConfig::default(),
+                words_per_minute: std::num::NonZeroUsize::new(200).unwrap(),
             },
             content: content_types,
             dynamic: HashMap::new(),
             redirects: HashMap::new(),
+            taxonomies: HashMap::new(),
         }
     }
 
@@ -554,6 +989,49 @@ mod tests {
         assert_eq!(result, "default.html");
     }
 
+    fn create_test_meta(template: Option<&str>) -> ContentMeta {
+        ContentMeta {
+            title: "Test".to_string(),
+            date: time::macros::datetime!(2024-01-15 10:00:00 UTC),
+            updated: None,
+            author: "Test Author".to_string(),
+            tags: vec![],
+            template: template.map(str::to_string),
+            cover: None,
+            extra: HashMap::new(),
+            extra_js: vec![],
+            draft: false,
+            publish_date: None,
+            expiry_date: None,
+            weight: None,
+            summary: None,
+            toc: false,
+        }
+    }
+
+    #[test]
+    fn test_get_page_template_prefers_meta_override() {
+        let config = create_test_config();
+        let meta = create_test_meta(Some("landing.html"));
+
+        assert_eq!(
+            get_page_template(&config, &meta, "projects"),
+            "landing.html"
+        );
+    }
+
+    #[test]
+    fn test_get_page_template_falls_back_to_content_type() {
+        let config = create_test_config();
+        let meta = create_test_meta(None);
+
+        assert_eq!(
+            get_page_template(&config, &meta, "projects"),
+            "project.html"
+        );
+        assert_eq!(get_page_template(&config, &meta, "unknown"), "default.html");
+    }
+
     #[test]
     fn test_get_content_type_nested_directory() {
         // Test file in nested directory structure
@@ -562,6 +1040,57 @@ mod tests {
         assert_eq!(result, "blog");
     }
 
+    #[test]
+    fn test_get_section_keeps_nested_directories() {
+        let input = PathBuf::from("src/content/blog/tech/rust/post.md");
+        assert_eq!(get_section(&input, "src/content"), "blog/tech/rust");
+
+        let input = PathBuf::from("src/content/about.md");
+        assert_eq!(get_section(&input, "src/content"), "");
+
+        let input = PathBuf::from("different/path/file.md");
+        assert_eq!(get_section(&input, "src/content"), "");
+    }
+
+    #[test]
+    fn test_get_content_type_root_file_is_page() {
+        let input = PathBuf::from("src/content/about.md");
+        assert_eq!(get_content_type(&input, "src/content"), "page");
+    }
+
+    #[test]
+    fn test_page_bundle_takes_section_of_its_directory() {
+        let input = PathBuf::from("src/content/blog/my-trip/index.md");
+        assert_eq!(get_section(&input, "src/content"), "blog");
+        assert_eq!(get_content_type(&input, "src/content"), "blog");
+
+        let input = PathBuf::from("src/content/about/index.md");
+        assert_eq!(get_section(&input, "src/content"), "");
+        assert_eq!(get_content_type(&input, "src/content"), "page");
+    }
+
+    #[test]
+    fn test_get_content_type_template_for_root_pages() {
+        let mut config = create_test_config();
+        assert_eq!(get_content_type_template(&config, ""), "default.html");
+
+        config.site.page_template = Some("standalone.html".to_string());
+        assert_eq!(get_content_type_template(&config, ""), "standalone.html");
+        assert_eq!(
+            get_content_type_template(&config, "unknown"),
+            "default.html"
+        );
+    }
+
+    #[test]
+    fn test_get_content_type_template_inherits_from_parent_section() {
+        let config = create_test_config();
+        assert_eq!(
+            get_content_type_template(&config, "projects/rust/cli"),
+            "project.html"
+        );
+    }
+
     #[test]
     fn test_find_markdown_files() {
         // Create temporary directory structure
@@ -640,6 +1169,24 @@ mod tests {
         assert_eq!(result.len(), 0);
     }
 
+    #[test]
+    fn test_find_markdown_files_skips_section_indexes() {
+        let temp_dir = tempdir().unwrap();
+        let content_dir = temp_dir.path();
+        fs::create_dir(content_dir.join("blog")).unwrap();
+        fs::write(content_dir.join("blog/_index.md"), "# Blog").unwrap();
+        fs::write(content_dir.join("blog/post.md"), "# Post").unwrap();
+
+        let content_dir = content_dir.to_str().unwrap();
+        let posts = find_markdown_files(content_dir);
+        assert_eq!(posts.len(), 1);
+        assert!(posts[0].ends_with("post.md"));
+
+        let indexes = find_section_indexes(content_dir);
+        assert_eq!(indexes.len(), 1);
+        assert!(indexes[0].ends_with("blog/_index.md"));
+    }
+
     #[test]
     fn test_slugify_basic() {
         assert_eq!(slugify("Hello World"), "hello-world");
@@ -679,6 +1226,97 @@ mod tests {
         assert_eq!(slugify("---"), "");
     }
 
+    #[test]
+    fn test_count_words_skips_code_and_html() {
+        let markdown = "# Getting started\n\n\
+            - Install **Rust** and [the tools](https://example.com/tools).\n\
+            - Don't skip <span class=\"note\">this</span> step!\n\n\
+            ````markdown\n```\nstill code\n```\n````\n\n\
+            ~~~\nmore code\n~~~\n\n\
+            | A | B |\n|---|---|\n\n\
+            Done.\n";
+        // 2 + 5 + 4 + 2 + 1, the link target and the table rule count nothing
+        assert_eq!(count_words(markdown), 14);
+        assert_eq!(count_words(""), 0);
+    }
+
+    #[test]
+    fn test_count_words_counts_cjk_characters() {
+        assert_eq!(count_words("静的サイト"), 5);
+        assert_eq!(count_words("Rust是一种语言 and more"), 8);
+        assert_eq!(count_words("한국어 문장"), 2);
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<p>Fish &amp; <em>chips</em> &lt;3</p>\n<p>Tea &quot;now&quot;</p>"),
+            "Fish & chips <3 Tea \"now\""
+        );
+        assert_eq!(html_to_text(""), "");
+    }
+
+    #[test]
+    fn test_truncate_html_closes_open_tags() {
+        let html = "<p>One <a href=\"/x\">two <em>three</em></a> four</p>\n<p>five</p>";
+        assert_eq!(truncate_html(html, 2), "<p>One <a href=\"/x\">two…</a></p>");
+        assert_eq!(
+            truncate_html(html, 3),
+            "<p>One <a href=\"/x\">two <em>three</em></a>…</p>"
+        );
+
+        // Void elements stay unclosed, short input is unchanged
+        assert_eq!(
+            truncate_html("<p>a<br />b <img src=\"x.png\"> c d</p>", 2),
+            "<p>a<br />b <img src=\"x.png\"> c…</p>"
+        );
+        assert_eq!(truncate_html(html, 10), html);
+    }
+
+    #[test]
+    fn test_truncate_html_counts_like_count_words() {
+        // Each CJK character is a word
+        assert_eq!(
+            truncate_html("<p>静的<em>サイト</em>生成</p>", 3),
+            "<p>静的<em>サ…</em></p>"
+        );
+        assert_eq!(
+            truncate_html("<p>Rust 语言很好</p>", 3),
+            "<p>Rust 语言…</p>"
+        );
+
+        // Punctuation and character references are no words and are never split
+        let html = "<p>Fish &amp; chips — tea</p>";
+        assert_eq!(count_text_words(&html_to_text(html)), 3);
+        assert_eq!(truncate_html(html, 2), "<p>Fish &amp; chips…</p>");
+        assert_eq!(truncate_html(html, 3), html);
+    }
+
+    #[test]
+    fn test_rewrite_link_attributes() {
+        let html = r##"<p><a href="a.md">A</a> <img src="b.png" alt="a.md" /> <a href="#top">Top</a></p>"##;
+        let mut seen = vec![];
+        let result = rewrite_link_attributes(html, |value| {
+            seen.push(value.to_string());
+            value
+                .strip_suffix(".md")
+                .map(|stem| format!("/{}.html", stem))
+        });
+
+        assert_eq!(
+            result,
+            r##"<p><a href="/a.html">A</a> <img src="b.png" alt="a.md" /> <a href="#top">Top</a></p>"##
+        );
+        assert_eq!(seen, vec!["a.md", "b.png", "#top"]);
+
+        // An unterminated attribute is left as is
+        let broken = r#"<a href="a.md"#;
+        assert_eq!(
+            rewrite_link_attributes(broken, |_| Some("x".into())),
+            broken
+        );
+    }
+
     #[test]
     fn test_add_header_anchors_basic() {
         let html = "<h1>Hello World</h1>";
@@ -689,6 +1327,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_header_ids_without_links() {
+        let html = "<h2>Setup</h2><p>x</p><h2>Setup</h2><h3><code>cargo</code> run</h3>";
+        assert_eq!(
+            add_header_ids(html),
+            "<h2 id=\"setup\">Setup</h2><p>x</p><h2 id=\"setup-1\">Setup</h2>\
+             <h3 id=\"cargo-run\"><code>cargo</code> run</h3>"
+        );
+    }
+
     #[test]
     fn test_add_header_anchors_all_levels() {
         let html = "<h1>H1</h1><h2>H2</h2><h3>H3</h3><h4>H4</h4><h5>H5</h5><h6>H6</h6>";
@@ -763,6 +1411,19 @@ mod tests {
         assert_eq!(extract_stem_from_filename("hello-world.md"), "hello-world");
     }
 
+    #[test]
+    fn test_extract_date_from_filename() {
+        assert_eq!(
+            extract_date_from_filename("2025-12-29-my-article.md"),
+            Some("2025-12-29")
+        );
+        assert_eq!(extract_date_from_filename("my-article.md"), None);
+        assert_eq!(extract_date_from_filename("2025-12-29.md"), None);
+        assert_eq!(extract_date_from_filename("2025-12-29-"), None);
+        assert_eq!(extract_date_from_filename("2025_12_29-article.md"), None);
+        assert_eq!(extract_date_from_filename("ünïcödé-name.md"), None);
+    }
+
     #[test]
     fn test_extract_stem_from_filename_different_extensions() {
         assert_eq!(
@@ -1,61 +1,201 @@
 // src/watch.rs
 
-use crate::build::{build_fresh, get_paths_to_watch};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info};
+
+use crate::build::{build_fresh, build_pages, get_paths_to_watch};
 use crate::config::Config;
 use crate::error::RunError;
-use tracing::{debug, error, info};
+use crate::output::copy_static_files;
+use crate::publish::Visibility;
+use crate::reload::{ReloadHub, classify_changes};
+use crate::utils::path_within;
 
-/// Watch for file changes and rebuild automatically (macOS only)
-#[cfg(target_os = "macos")]
-pub(crate) fn watch(config_file: &str, include_drafts: bool) -> Result<(), RunError> {
-    use std::sync::mpsc::channel;
-    use std::thread;
-    use std::time::{Duration, Instant};
+/// Minimum time between two rebuilds triggered by file system events
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(500);
+
+/// Messages delivered to the watch loop by the platform backend and signal handler.
+#[derive(Debug)]
+pub(crate) enum WatchEvent {
+    /// One or more watched paths changed on disk
+    Changed(Vec<PathBuf>),
+    /// The process was asked to stop (Ctrl+C / SIGTERM)
+    Shutdown,
+}
+
+/// The work a batch of changed paths requires.
+#[derive(Debug, PartialEq)]
+enum Rebuild {
+    /// Only `static_dir` changed: copy static files, pages are untouched
+    Static,
+    /// Content or templates changed: rebuild pages (the build cache limits this to the
+    /// affected pages) and reuse the current asset manifest
+    Pages,
+    /// The config changed, or static files changed while asset hashing is enabled
+    /// (hashed URLs in every page may change): hash assets and rebuild everything
+    Full,
+}
 
+impl Rebuild {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Rebuild::Static => "static",
+            Rebuild::Pages => "pages",
+            Rebuild::Full => "full",
+        }
+    }
+}
+
+/// Watch for file changes and rebuild automatically (macOS and Linux)
+///
+/// When `reload` is set, browsers connected to it are notified after each successful rebuild.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+pub(crate) fn watch(
+    config_file: &str,
+    visibility: Visibility,
+    reload: Option<&ReloadHub>,
+) -> Result<(), RunError> {
     // Load config to get directories to watch
-    let config = Config::load_from_file(config_file)?;
+    let mut config = Config::load_from_file(config_file)?;
 
     let paths_to_watch = get_paths_to_watch(config_file, &config);
 
     info!("watch::start {:?}", paths_to_watch);
     info!("watch::info press Ctrl+C to stop");
-    if include_drafts {
+    if visibility.include_drafts {
         info!("watch::drafts including draft content");
     }
+    if visibility.include_future {
+        info!("watch::future including content scheduled for later");
+    }
 
     // Initial build (use fresh environment from the start)
-    if let Err(e) = build_fresh(config_file, include_drafts) {
-        error!("Initial build failed: {:?}", e);
-    }
+    let mut manifest = match build_fresh(config_file, visibility) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            error!("Initial build failed: {:?}", e);
+            None
+        }
+    };
 
     let (sender, receiver) = channel();
 
-    let _watcher_thread = thread::spawn(move || {
-        let fsevent = fsevent::FsEvent::new(paths_to_watch);
-        fsevent.observe(sender);
+    spawn_shutdown_handler(sender.clone())
+        .map_err(|e| RunError::IoError(format!("Failed to install signal handler: {}", e)))?;
+    backend::spawn(paths_to_watch, sender)
+        .map_err(|e| RunError::IoError(format!("Failed to start file watcher: {}", e)))?;
+
+    run_watch_loop(receiver, Instant::now(), |paths| {
+        // Without a manifest from a previous build, hashed asset URLs cannot be reused
+        let rebuild = if config.site.asset_hashing_enabled && manifest.is_none() {
+            Rebuild::Full
+        } else {
+            classify_rebuild(paths, config_file, &config)
+        };
+        info!("watch::rebuild {}", rebuild.as_str());
+
+        let result = match rebuild {
+            Rebuild::Static => copy_static_files(&config).map_err(RunError::from),
+            Rebuild::Pages => build_pages(config_file, visibility, manifest.as_ref()),
+            Rebuild::Full => build_fresh(config_file, visibility).and_then(|new_manifest| {
+                manifest = new_manifest;
+                config = Config::load_from_file(config_file)?;
+                Ok(())
+            }),
+        };
+
+        match result {
+            Ok(()) => {
+                if let Some(hub) = reload {
+                    hub.notify(classify_changes(
+                        paths,
+                        Path::new(&config.site.static_dir),
+                        config.site.asset_hashing_enabled,
+                    ));
+                }
+            }
+            Err(e) => error!("Build failed: {:?}", e),
+        }
     });
 
-    // Debounce: track last build time
-    let mut last_build = Instant::now();
-    let debounce_duration = Duration::from_millis(500);
+    info!("watch::stop ✓");
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+pub(crate) fn watch(
+    _config_file: &str,
+    _visibility: Visibility,
+    _reload: Option<&ReloadHub>,
+) -> Result<(), RunError> {
+    eprintln!("Watch mode is only supported on macOS and Linux");
+    std::process::exit(1);
+}
+
+/// Decides how much of the site a batch of changed paths invalidates.
+fn classify_rebuild(paths: &[PathBuf], config_file: &str, config: &Config) -> Rebuild {
+    let config_path = Path::new(config_file);
+    if paths.iter().any(|p| is_same_path(p, config_path)) {
+        return Rebuild::Full;
+    }
+
+    let static_dir = Path::new(&config.site.static_dir);
+    let static_changed = paths.iter().any(|p| path_within(p, static_dir));
+    let pages_changed = paths.iter().any(|p| !path_within(p, static_dir));
+
+    match (static_changed, pages_changed) {
+        (true, _) if config.site.asset_hashing_enabled => Rebuild::Full,
+        (true, false) => Rebuild::Static,
+        // Page builds copy static files too, so mixed batches need nothing extra
+        _ => Rebuild::Pages,
+    }
+}
+
+/// Compares paths that may be relative or absolute (and may no longer exist).
+fn is_same_path(a: &Path, b: &Path) -> bool {
+    if a == b {
+        return true;
+    }
+    if let (Ok(a), Ok(b)) = (a.canonicalize(), b.canonicalize()) {
+        return a == b;
+    }
+    matches!(
+        (std::path::absolute(a), std::path::absolute(b)),
+        (Ok(a), Ok(b)) if a == b
+    )
+}
 
+/// Receives watch events and invokes `rebuild` for each change outside the debounce window.
+///
+/// `last_build` is the time of the initial build. Returns when a `Shutdown` event
+/// arrives or every sender has been dropped.
+fn run_watch_loop<F>(receiver: Receiver<WatchEvent>, mut last_build: Instant, mut rebuild: F)
+where
+    F: FnMut(&[PathBuf]),
+{
     loop {
         match receiver.recv() {
-            Ok(events) => {
+            Ok(WatchEvent::Changed(paths)) => {
                 // Check debounce
-                if last_build.elapsed() < debounce_duration {
+                if last_build.elapsed() < DEBOUNCE_DURATION {
                     debug!("watch::debounce skipping rebuild");
                     continue;
                 }
 
-                // Log event_id at INFO, full details at DEBUG
-                info!("watch::change event_id: {}", events.event_id);
-                debug!("watch::change {:?}", events);
+                // Log the first path at INFO, full details at DEBUG
+                if let Some(first) = paths.first() {
+                    info!("watch::change {} ({} paths)", first.display(), paths.len());
+                }
+                debug!("watch::change {:?}", paths);
                 last_build = Instant::now();
 
-                if let Err(e) = build_fresh(config_file, include_drafts) {
-                    error!("Build failed: {:?}", e);
-                }
+                rebuild(&paths);
+            }
+            Ok(WatchEvent::Shutdown) => {
+                info!("watch::shutdown signal received");
+                break;
             }
             Err(e) => {
                 error!("Watch error: {:?}", e);
@@ -63,12 +203,520 @@ pub(crate) fn watch(config_file: &str, include_drafts: bool) -> Result<(), RunEr
             }
         }
     }
+}
+
+/// Forwards SIGINT/SIGTERM to the watch loop so it can stop cleanly.
+#[cfg(unix)]
+fn spawn_shutdown_handler(sender: Sender<WatchEvent>) -> std::io::Result<()> {
+    use signal_hook::consts::{SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let mut signals = Signals::new([SIGINT, SIGTERM])?;
+    std::thread::spawn(move || {
+        if signals.forever().next().is_some() {
+            let _ = sender.send(WatchEvent::Shutdown);
+        }
+    });
+    Ok(())
+}
 
+#[cfg(not(unix))]
+fn spawn_shutdown_handler(_sender: Sender<WatchEvent>) -> std::io::Result<()> {
     Ok(())
 }
 
-#[cfg(not(target_os = "macos"))]
-pub(crate) fn watch(_config_file: &str, _include_drafts: bool) -> Result<(), RunError> {
-    eprintln!("Watch mode is only supported on macOS");
-    std::process::exit(1);
+/// fsevent backend: FSEvents streams are recursive and report one path per event.
+#[cfg(target_os = "macos")]
+mod backend {
+    use std::path::PathBuf;
+    use std::sync::mpsc::{Sender, channel};
+    use std::thread;
+
+    use super::WatchEvent;
+
+    pub(super) fn spawn(paths: Vec<String>, sender: Sender<WatchEvent>) -> std::io::Result<()> {
+        let (fs_sender, fs_receiver) = channel::<fsevent::Event>();
+
+        thread::spawn(move || {
+            let fsevent = fsevent::FsEvent::new(paths);
+            fsevent.observe(fs_sender);
+        });
+
+        thread::spawn(move || {
+            for event in fs_receiver {
+                let changed = WatchEvent::Changed(vec![PathBuf::from(event.path)]);
+                if sender.send(changed).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// inotify backend: watches are per directory, so the tree is walked up front and
+/// newly created subdirectories are added as they appear.
+#[cfg(target_os = "linux")]
+mod backend {
+    use inotify::{EventMask, Events, Inotify, WatchDescriptor, WatchMask};
+    use std::collections::{HashMap, HashSet};
+    use std::ffi::OsString;
+    use std::io::ErrorKind;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::Sender;
+    use std::thread;
+    use std::time::Duration;
+    use tracing::{debug, error, warn};
+    use walkdir::WalkDir;
+
+    use super::WatchEvent;
+
+    /// Quiet period that ends a burst of events belonging to one change
+    const SETTLE_DURATION: Duration = Duration::from_millis(50);
+
+    /// A watched directory and how its events are interpreted
+    struct WatchedDir {
+        path: PathBuf,
+        /// Whether new subdirectories should be watched too
+        recursive: bool,
+        /// When set, only events for these entry names are reported
+        /// (used for single files such as the config file)
+        names: Option<HashSet<OsString>>,
+    }
+
+    struct Watcher {
+        inotify: Inotify,
+        dirs: HashMap<WatchDescriptor, WatchedDir>,
+    }
+
+    /// Completed writes, creations, deletions and both halves of a rename.
+    /// `CLOSE_WRITE` is used instead of `MODIFY` so a rebuild never reads a half-written file,
+    /// and `MOVED_TO` catches editors that save atomically via a temp file + rename.
+    fn watch_mask() -> WatchMask {
+        WatchMask::CLOSE_WRITE
+            | WatchMask::CREATE
+            | WatchMask::DELETE
+            | WatchMask::MOVED_FROM
+            | WatchMask::MOVED_TO
+    }
+
+    impl Watcher {
+        fn add_dir(&mut self, path: &Path, recursive: bool, name: Option<OsString>) {
+            let wd = match self.inotify.watches().add(path, watch_mask()) {
+                Ok(wd) => wd,
+                Err(e) => {
+                    warn!("watch::skip {:?}: {}", path, e);
+                    return;
+                }
+            };
+            debug!("watch::add {:?}", path);
+
+            // inotify hands out one descriptor per inode, so merge with any existing entry:
+            // a recursive watch reports everything, a file watch only its names.
+            let entry = self.dirs.entry(wd).or_insert_with(|| WatchedDir {
+                path: path.to_path_buf(),
+                recursive,
+                names: Some(HashSet::new()),
+            });
+            entry.recursive |= recursive;
+            match (recursive, name) {
+                (true, _) => entry.names = None,
+                (false, Some(name)) => {
+                    if let Some(names) = entry.names.as_mut() {
+                        names.insert(name);
+                    }
+                }
+                (false, None) => {}
+            }
+        }
+
+        fn add_tree(&mut self, root: &Path) {
+            for entry in WalkDir::new(root)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|e| e.file_type().is_dir())
+            {
+                self.add_dir(entry.path(), true, None);
+            }
+        }
+
+        fn add_path(&mut self, path: &Path) {
+            if path.is_dir() {
+                self.add_tree(path);
+            } else if let Some(name) = path.file_name() {
+                // Watch the parent so atomic saves (which replace the inode) keep being seen
+                let parent = match path.parent() {
+                    Some(p) if !p.as_os_str().is_empty() => p,
+                    _ => Path::new("."),
+                };
+                self.add_dir(parent, false, Some(name.to_os_string()));
+            } else {
+                warn!("watch::skip {:?}: not a file or directory", path);
+            }
+        }
+
+        /// Translates raw events into changed paths, updating the watch set as directories
+        /// appear or disappear.
+        fn handle_events(&mut self, events: Events<'_>, changed: &mut Vec<PathBuf>) -> usize {
+            let mut count = 0;
+            let mut new_dirs = Vec::new();
+            let mut removed = Vec::new();
+
+            for event in events {
+                count += 1;
+
+                if event.mask.contains(EventMask::Q_OVERFLOW) {
+                    // Events were dropped; report every directory so a rebuild still happens
+                    changed.extend(self.dirs.values().map(|d| d.path.clone()));
+                    continue;
+                }
+
+                let Some(dir) = self.dirs.get(&event.wd) else {
+                    continue;
+                };
+
+                if event.mask.contains(EventMask::IGNORED) {
+                    // Watch was removed by the kernel (directory deleted or moved away)
+                    removed.push(event.wd.clone());
+                    continue;
+                }
+
+                if let (Some(names), Some(name)) = (&dir.names, event.name)
+                    && !names.contains(name)
+                {
+                    continue;
+                }
+
+                let path = match event.name {
+                    Some(name) => dir.path.join(name),
+                    None => dir.path.clone(),
+                };
+
+                if dir.recursive
+                    && event.mask.contains(EventMask::ISDIR)
+                    && event
+                        .mask
+                        .intersects(EventMask::CREATE | EventMask::MOVED_TO)
+                {
+                    new_dirs.push(path.clone());
+                }
+
+                changed.push(path);
+            }
+
+            for wd in removed {
+                self.dirs.remove(&wd);
+            }
+
+            // Files may land in a new directory before its watch exists;
+            // the rebuild picks them up because the directory itself is reported.
+            for dir in new_dirs {
+                self.add_tree(&dir);
+            }
+
+            count
+        }
+
+        /// Blocks on inotify and sends one `Changed` message per burst of events.
+        ///
+        /// A single save produces several events (temp file created, written, renamed),
+        /// so reading continues until the queue has been quiet for `SETTLE_DURATION`.
+        fn run(mut self, sender: Sender<WatchEvent>) {
+            let mut buffer = [0u8; 4096];
+
+            loop {
+                let mut changed = Vec::new();
+
+                match self.inotify.read_events_blocking(&mut buffer) {
+                    Ok(events) => {
+                        self.handle_events(events, &mut changed);
+                    }
+                    Err(e) => {
+                        error!("Watch error: {:?}", e);
+                        return;
+                    }
+                }
+
+                loop {
+                    thread::sleep(SETTLE_DURATION);
+                    match self.inotify.read_events(&mut buffer) {
+                        Ok(events) => {
+                            if self.handle_events(events, &mut changed) == 0 {
+                                break;
+                            }
+                        }
+                        Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                        Err(e) => {
+                            error!("Watch error: {:?}", e);
+                            return;
+                        }
+                    }
+                }
+
+                if changed.is_empty() {
+                    continue;
+                }
+
+                changed.sort();
+                changed.dedup();
+                if sender.send(WatchEvent::Changed(changed)).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub(super) fn spawn(paths: Vec<String>, sender: Sender<WatchEvent>) -> std::io::Result<()> {
+        let mut watcher = Watcher {
+            inotify: Inotify::init()?,
+            dirs: HashMap::new(),
+        };
+
+        for path in &paths {
+            watcher.add_path(Path::new(path));
+        }
+
+        thread::spawn(move || watcher.run(sender));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(asset_hashing_enabled: bool) -> Config {
+        Config::from_str(&format!(
+            r#"
+[site]
+title = "Test Site"
+tagline = "A test tagline"
+domain = "example.com"
+author = "Test Author"
+output_dir = "output"
+content_dir = "content"
+template_dir = "templates"
+static_dir = "static"
+site_index_template = "index.html"
+asset_hashing_enabled = {}
+"#,
+            asset_hashing_enabled
+        ))
+        .unwrap()
+    }
+
+    fn paths(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_classify_rebuild_static_only() {
+        let config = test_config(false);
+        assert_eq!(
+            classify_rebuild(
+                &paths(&["static/css/style.css", "static/img/logo.png"]),
+                "site.toml",
+                &config
+            ),
+            Rebuild::Static
+        );
+    }
+
+    #[test]
+    fn test_classify_rebuild_static_with_asset_hashing() {
+        let config = test_config(true);
+        assert_eq!(
+            classify_rebuild(&paths(&["static/css/style.css"]), "site.toml", &config),
+            Rebuild::Full
+        );
+    }
+
+    #[test]
+    fn test_classify_rebuild_content_and_templates() {
+        let config = test_config(true);
+        assert_eq!(
+            classify_rebuild(
+                &paths(&["content/blog/post.md", "templates/base.html"]),
+                "site.toml",
+                &config
+            ),
+            Rebuild::Pages
+        );
+    }
+
+    #[test]
+    fn test_classify_rebuild_mixed_without_hashing() {
+        let config = test_config(false);
+        assert_eq!(
+            classify_rebuild(
+                &paths(&["content/blog/post.md", "static/css/style.css"]),
+                "site.toml",
+                &config
+            ),
+            Rebuild::Pages
+        );
+    }
+
+    #[test]
+    fn test_classify_rebuild_config_change() {
+        let config = test_config(false);
+        let cwd = std::env::current_dir().unwrap();
+        assert_eq!(
+            classify_rebuild(&paths(&["site.toml"]), "site.toml", &config),
+            Rebuild::Full
+        );
+        assert_eq!(
+            classify_rebuild(&[cwd.join("site.toml")], "./site.toml", &config),
+            Rebuild::Full
+        );
+    }
+
+    #[test]
+    fn test_run_watch_loop_debounces_rapid_changes() {
+        let (sender, receiver) = channel();
+        sender
+            .send(WatchEvent::Changed(vec![PathBuf::from("content/a.md")]))
+            .unwrap();
+        sender
+            .send(WatchEvent::Changed(vec![PathBuf::from("content/a.md")]))
+            .unwrap();
+        sender
+            .send(WatchEvent::Changed(vec![PathBuf::from("content/b.md")]))
+            .unwrap();
+        sender.send(WatchEvent::Shutdown).unwrap();
+
+        // Pretend the initial build finished well before the first change
+        let last_build = Instant::now().checked_sub(DEBOUNCE_DURATION).unwrap();
+        let mut rebuilds = Vec::new();
+        run_watch_loop(receiver, last_build, |paths| rebuilds.push(paths.to_vec()));
+
+        // Only the first change is outside the debounce window
+        assert_eq!(rebuilds, vec![vec![PathBuf::from("content/a.md")]]);
+    }
+
+    #[test]
+    fn test_run_watch_loop_stops_on_shutdown() {
+        let (sender, receiver) = channel();
+        sender.send(WatchEvent::Shutdown).unwrap();
+
+        let mut rebuild_count = 0;
+        run_watch_loop(receiver, Instant::now(), |_| rebuild_count += 1);
+
+        assert_eq!(rebuild_count, 0);
+    }
+
+    #[test]
+    fn test_run_watch_loop_stops_when_senders_dropped() {
+        let (sender, receiver) = channel::<WatchEvent>();
+        drop(sender);
+
+        let mut rebuild_count = 0;
+        run_watch_loop(receiver, Instant::now(), |_| rebuild_count += 1);
+
+        assert_eq!(rebuild_count, 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    mod linux {
+        use super::super::*;
+        use std::fs;
+        use tempfile::tempdir;
+
+        /// Waits for a `Changed` event containing `expected`, ignoring unrelated batches.
+        fn wait_for(receiver: &Receiver<WatchEvent>, expected: &std::path::Path) -> bool {
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+                match receiver.recv_timeout(remaining) {
+                    Ok(WatchEvent::Changed(paths)) if paths.iter().any(|p| p == expected) => {
+                        return true;
+                    }
+                    Ok(_) => continue,
+                    Err(_) => return false,
+                }
+            }
+            false
+        }
+
+        #[test]
+        fn test_inotify_backend_reports_file_writes() {
+            let temp_dir = tempdir().unwrap();
+            let content_dir = temp_dir.path().join("content");
+            fs::create_dir_all(&content_dir).unwrap();
+
+            let (sender, receiver) = channel();
+            backend::spawn(vec![content_dir.to_string_lossy().to_string()], sender).unwrap();
+
+            let post = content_dir.join("post.md");
+            fs::write(&post, "# Hello").unwrap();
+
+            assert!(wait_for(&receiver, &post), "write should be reported");
+        }
+
+        #[test]
+        fn test_inotify_backend_watches_new_subdirectories() {
+            let temp_dir = tempdir().unwrap();
+            let content_dir = temp_dir.path().join("content");
+            fs::create_dir_all(&content_dir).unwrap();
+
+            let (sender, receiver) = channel();
+            backend::spawn(vec![content_dir.to_string_lossy().to_string()], sender).unwrap();
+
+            let blog_dir = content_dir.join("blog");
+            fs::create_dir(&blog_dir).unwrap();
+            assert!(
+                wait_for(&receiver, &blog_dir),
+                "new directory should be reported"
+            );
+
+            let post = blog_dir.join("post.md");
+            fs::write(&post, "# Hello").unwrap();
+            assert!(
+                wait_for(&receiver, &post),
+                "writes inside the new directory should be reported"
+            );
+        }
+
+        #[test]
+        fn test_inotify_backend_reports_atomic_rename() {
+            let temp_dir = tempdir().unwrap();
+            let content_dir = temp_dir.path().join("content");
+            fs::create_dir_all(&content_dir).unwrap();
+            let post = content_dir.join("post.md");
+            fs::write(&post, "# Old").unwrap();
+
+            let (sender, receiver) = channel();
+            backend::spawn(vec![content_dir.to_string_lossy().to_string()], sender).unwrap();
+
+            // Editors save by writing a temp file and renaming it over the original
+            let tmp = content_dir.join(".post.md.swp");
+            fs::write(&tmp, "# New").unwrap();
+            fs::rename(&tmp, &post).unwrap();
+
+            assert!(
+                wait_for(&receiver, &post),
+                "rename target should be reported"
+            );
+        }
+
+        #[test]
+        fn test_inotify_backend_filters_single_file_watch() {
+            let temp_dir = tempdir().unwrap();
+            let config_file = temp_dir.path().join("site.toml");
+            fs::write(&config_file, "[site]").unwrap();
+
+            let (sender, receiver) = channel();
+            backend::spawn(vec![config_file.to_string_lossy().to_string()], sender).unwrap();
+
+            // Sibling files of the config file are not reported
+            let sibling = temp_dir.path().join("notes.txt");
+            fs::write(&sibling, "ignored").unwrap();
+            fs::write(&config_file, "[site]\ntitle = \"x\"").unwrap();
+
+            match receiver.recv_timeout(Duration::from_secs(5)) {
+                Ok(WatchEvent::Changed(paths)) => assert_eq!(paths, vec![config_file]),
+                other => panic!("expected config change, got {:?}", other),
+            }
+        }
+    }
 }
@@ -141,7 +141,7 @@ fn test_generates_all_expected_files() {
     let blog_entries: Vec<_> = fs::read_dir(&blog_dir)
         .unwrap()
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file() && e.path().extension().map_or(false, |ext| ext == "html"))
+        .filter(|e| e.path().is_file() && e.path().extension().is_some_and(|ext| ext == "html"))
         .collect();
 
     assert!(blog_entries.len() >= 2, "Should have at least 2 blog posts");
@@ -248,7 +248,7 @@ fn test_blog_post_rendering() {
         .filter_map(|e| e.ok())
         .find(|e| {
             e.path().is_file()
-                && e.path().extension().map_or(false, |ext| ext == "html")
+                && e.path().extension().is_some_and(|ext| ext == "html")
                 && e.file_name().to_string_lossy().contains("first-post")
         })
         .expect("Should find first-post HTML file");
@@ -522,7 +522,7 @@ fn test_draft_content_excluded_by_default() {
         .filter_map(|e| e.ok())
         .filter(|e| {
             e.path().is_file()
-                && e.path().extension().map_or(false, |ext| ext == "html")
+                && e.path().extension().is_some_and(|ext| ext == "html")
                 && e.file_name().to_string_lossy() != "index.html"
         })
         .collect();
@@ -575,7 +575,7 @@ fn test_draft_content_included_with_flag() {
         .filter_map(|e| e.ok())
         .filter(|e| {
             e.path().is_file()
-                && e.path().extension().map_or(false, |ext| ext == "html")
+                && e.path().extension().is_some_and(|ext| ext == "html")
                 && e.file_name().to_string_lossy() != "index.html"
         })
         .collect();
@@ -798,7 +798,1150 @@ fn test_rss_full_content_enabled() {
 
     // Content should include rendered HTML from the posts
     assert!(
-        feed_content.contains("<h2>"),
+        feed_content.contains("<h2 id=\""),
         "content:encoded should contain rendered HTML"
     );
 }
+
+#[test]
+fn test_not_found_page_rendered() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content = config_content.replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\nnot_found_template = \"404.html\"",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+    fs::write(
+        temp_site.path().join("templates/404.html"),
+        "<html><body><h1>Not found on {{ config.site.title }}</h1>\
+         <p class=\"count\">{{ all_content | length }}</p></body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let not_found_path = output_dir.join("404.html");
+    assert!(not_found_path.exists(), "404.html should be generated");
+
+    let html = parse_html_file(&not_found_path);
+    assert_eq!(select_text(&html, "h1"), "Not found on Test Blog");
+    assert_ne!(
+        select_text(&html, "p.count"),
+        "0",
+        "404 template should have access to all content"
+    );
+}
+
+#[test]
+fn test_not_found_page_absent_by_default() {
+    let temp_site = setup_test_site();
+
+    run_ssg(temp_site.path()).success();
+
+    assert!(!temp_site.path().join("output/404.html").exists());
+}
+
+#[test]
+fn test_incremental_rebuild_skips_unchanged_pages() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("0 unchanged, 3 rendered"));
+    assert!(temp_site.path().join(".marie-cache/index.toml").exists());
+
+    let about_path = output_dir.join("pages/about.html");
+    let about_mtime = fs::metadata(&about_path).unwrap().modified().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    // Nothing changed: every page comes from the cache
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("3 unchanged, 0 rendered"));
+
+    // Editing one post re-renders only that post
+    let post_path = temp_site.path().join("content/blog/first-post.md");
+    let mut post = fs::read_to_string(&post_path).unwrap();
+    post.push_str("\nAn incremental edit.\n");
+    fs::write(&post_path, post).unwrap();
+
+    run_ssg(temp_site.path())
+        .stdout(predicate::str::contains("2 unchanged, 1 rendered"))
+        .stdout(predicate::str::contains(
+            "content::render content/blog/first-post.md",
+        ));
+
+    let first_post =
+        fs::read_to_string(output_dir.join("blog/2024-01-15-first-post.html")).unwrap();
+    assert!(first_post.contains("An incremental edit."));
+    assert_eq!(
+        fs::metadata(&about_path).unwrap().modified().unwrap(),
+        about_mtime,
+        "unchanged pages should not be rewritten"
+    );
+
+    // A template change re-renders only the pages using it
+    let template_path = temp_site.path().join("templates/page.html");
+    let mut template = fs::read_to_string(&template_path).unwrap();
+    template.push_str("\n<!-- changed -->\n");
+    fs::write(&template_path, template).unwrap();
+
+    run_ssg(temp_site.path())
+        .stdout(predicate::str::contains("2 unchanged, 1 rendered"))
+        .stdout(predicate::str::contains(
+            "content::render content/pages/about.md",
+        ));
+}
+
+#[test]
+fn test_incremental_rebuild_follows_template_inheritance() {
+    let temp_site = setup_test_site();
+    let templates = temp_site.path().join("templates");
+
+    fs::write(
+        templates.join("base.html"),
+        "<html><body>{% block body %}{% endblock %}</body></html>",
+    )
+    .unwrap();
+    fs::write(
+        templates.join("blog.html"),
+        "{% extends \"base.html\" %}{% block body %}{{ content | safe }}{% endblock %}",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("0 unchanged, 3 rendered"));
+
+    // Changing the parent template re-renders the two blog posts, not the about page
+    fs::write(
+        templates.join("base.html"),
+        "<html><body><main>{% block body %}{% endblock %}</main></body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("1 unchanged, 2 rendered"));
+
+    let post = fs::read_to_string(
+        temp_site
+            .path()
+            .join("output/blog/2024-01-15-first-post.html"),
+    )
+    .unwrap();
+    assert!(post.starts_with("<html><body><main>"));
+}
+
+#[test]
+fn test_incremental_rebuild_restores_deleted_output() {
+    let temp_site = setup_test_site();
+    let about_path = temp_site.path().join("output/pages/about.html");
+
+    run_ssg(temp_site.path()).success();
+    fs::remove_file(&about_path).unwrap();
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("2 unchanged, 1 rendered"));
+    assert!(about_path.exists());
+}
+
+#[test]
+fn test_per_page_template_override() {
+    let temp_site = setup_test_site();
+
+    fs::write(
+        temp_site.path().join("templates/landing.html"),
+        "<html><body><h1 class=\"landing\">{{ meta.title }}</h1>{{ content | safe }}</body></html>",
+    )
+    .unwrap();
+    let meta_path = temp_site.path().join("content/pages/about.meta.toml");
+    let mut meta = fs::read_to_string(&meta_path).unwrap();
+    meta.push_str("template = \"landing.html\"\n");
+    fs::write(&meta_path, meta).unwrap();
+
+    run_ssg(temp_site.path())
+        .stdout(predicate::str::contains("pages/about.html [landing.html]"))
+        .stdout(predicate::str::contains(
+            "2024-01-15-first-post.html [blog.html]",
+        ));
+
+    let html = parse_html_file(&temp_site.path().join("output/pages/about.html"));
+    assert_eq!(select_text(&html, "h1.landing"), "About");
+    assert_eq!(count_elements(&html, "article.page"), 0);
+}
+
+#[test]
+fn test_missing_template_override_names_page_and_template() {
+    let temp_site = setup_test_site();
+
+    let meta_path = temp_site.path().join("content/pages/about.meta.toml");
+    let mut meta = fs::read_to_string(&meta_path).unwrap();
+    meta.push_str("template = \"nope.html\"\n");
+    fs::write(&meta_path, meta).unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("MissingTemplate"))
+        .stdout(predicate::str::contains("content/pages/about.md"))
+        .stdout(predicate::str::contains("nope.html"));
+}
+
+#[test]
+fn test_migrate_to_front_matter_builds_same_site() {
+    let temp_site = setup_test_site();
+    run_ssg(temp_site.path()).success();
+    let about_path = temp_site.path().join("output/pages/about.html");
+    let before = fs::read_to_string(&about_path).unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("migrate")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("5 migrated, 0 skipped"));
+
+    let about_md = temp_site.path().join("content/pages/about.md");
+    assert!(!about_md.with_extension("meta.toml").exists());
+    assert!(fs::read_to_string(&about_md).unwrap().starts_with("+++\n"));
+
+    fs::remove_dir_all(temp_site.path().join("output")).unwrap();
+    run_ssg(temp_site.path()).success();
+    assert_eq!(fs::read_to_string(&about_path).unwrap(), before);
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("migrate")
+        .arg("--split")
+        .assert()
+        .success();
+    assert!(about_md.with_extension("meta.toml").exists());
+    assert!(
+        fs::read_to_string(&about_md)
+            .unwrap()
+            .starts_with("# About")
+    );
+}
+
+#[test]
+fn test_front_matter_conflicting_with_sidecar_fails() {
+    let temp_site = setup_test_site();
+    let about_md = temp_site.path().join("content/pages/about.md");
+    let meta = fs::read_to_string(about_md.with_extension("meta.toml")).unwrap();
+    let body = fs::read_to_string(&about_md).unwrap();
+    fs::write(
+        &about_md,
+        format!("+++\n{}+++\n{}", meta.replace("About", "Changed"), body),
+    )
+    .unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("MetadataConflict"));
+}
+
+#[test]
+fn test_plain_dates_resolve_in_site_timezone() {
+    let temp_site = setup_test_site();
+    let config_path = temp_site.path().join("site.toml");
+    let config = fs::read_to_string(&config_path).unwrap().replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\ntimezone = \"+09:00\"",
+        1,
+    );
+    fs::write(&config_path, config).unwrap();
+
+    let meta_path = temp_site.path().join("content/blog/first-post.meta.toml");
+    let meta = fs::read_to_string(&meta_path).unwrap();
+    let meta = meta
+        .lines()
+        .map(|line| {
+            if line.starts_with("date") {
+                "date = 2024-01-15"
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs::write(&meta_path, meta).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let feed = fs::read_to_string(temp_site.path().join("output/feed.xml")).unwrap();
+    assert!(feed.contains("Mon, 15 Jan 2024 00:00:00 +0900"), "{feed}");
+}
+
+#[test]
+fn test_invalid_date_error_names_field() {
+    let temp_site = setup_test_site();
+    let meta_path = temp_site.path().join("content/blog/first-post.meta.toml");
+    let meta = fs::read_to_string(&meta_path).unwrap();
+    let meta = meta
+        .lines()
+        .map(|line| {
+            if line.starts_with("date") {
+                "date = \"15/01/2024\""
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs::write(&meta_path, meta).unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("TomlParse"))
+        .stdout(predicate::str::contains("first-post.meta.toml"))
+        .stdout(predicate::str::contains("field: Some(\"date\")"));
+}
+
+#[test]
+fn test_metadata_derived_when_missing() {
+    let temp_site = setup_test_site();
+    fs::write(
+        temp_site.path().join("content/blog/2024-02-10-no-meta.md"),
+        "# Derived Post\n\nNo metadata file needed.\n",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let html = parse_html_file(&temp_site.path().join("output/blog/2024-02-10-no-meta.html"));
+    assert_eq!(select_text(&html, "h1.post-title"), "Derived Post");
+    assert_eq!(count_elements(&html, "span.tag"), 0);
+    assert!(select_text(&html, "span.author").contains("Test Author"));
+}
+
+#[test]
+fn test_underivable_metadata_names_file_and_field() {
+    let temp_site = setup_test_site();
+    fs::write(
+        temp_site.path().join("content/pages/contact.md"),
+        "# Contact\n",
+    )
+    .unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("MissingField"))
+        .stdout(predicate::str::contains("content/pages/contact.md"))
+        .stdout(predicate::str::contains("field: \"date\""));
+}
+
+#[test]
+fn test_tag_taxonomy_pages_generated() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content.push_str(
+        "\n[taxonomies.tags]\nindex_template = \"tags.html\"\nterm_template = \"tag.html\"\n",
+    );
+    fs::write(&config_path, config_content).unwrap();
+    fs::write(
+        temp_site.path().join("templates/tags.html"),
+        "<html><body>{% for term in terms %}\
+         <a class=\"term\" href=\"{{ term.url | url }}\">{{ term.name }} ({{ term.count }})</a>\
+         {% endfor %}</body></html>",
+    )
+    .unwrap();
+    fs::write(
+        temp_site.path().join("templates/tag.html"),
+        "<html><body><h1>{{ taxonomy }}: {{ term.name }}</h1>{% for item in contents %}\
+         <li class=\"post\">{{ item.meta.title }}</li>{% endfor %}</body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let tags = parse_html_file(&output_dir.join("tags/index.html"));
+    // meta, rust, ssg, testing, updates (draft-only tags are excluded)
+    assert_eq!(count_elements(&tags, "a.term"), 5);
+    assert!(
+        fs::read_to_string(output_dir.join("tags/index.html"))
+            .unwrap()
+            .contains(r#"href="/tags/testing/">testing (2)</a>"#)
+    );
+
+    let testing = parse_html_file(&output_dir.join("tags/testing/index.html"));
+    assert_eq!(select_text(&testing, "h1"), "tags: testing");
+    assert_eq!(count_elements(&testing, "li.post"), 2);
+    assert_eq!(select_text(&testing, "li.post"), "Second Blog Post");
+    assert!(!output_dir.join("tags/draft/index.html").exists());
+
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    assert!(sitemap.contains("<loc>https://test.example.com/tags/</loc>"));
+    assert!(sitemap.contains("<loc>https://test.example.com/tags/rust/</loc>"));
+}
+
+#[test]
+fn test_paginated_content_type_index() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content = config_content.replacen(
+        "output_naming = \"date\"",
+        "output_naming = \"date\"\npaginate_by = 1",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+    fs::write(
+        temp_site.path().join("templates/blog_index.html"),
+        "<html><body><p class=\"page\">{{ paginator.current }} of {{ paginator.total_pages }}</p>\
+         {% for item in contents %}<h3>{{ item.meta.title }}</h3>{% endfor %}\
+         {% if paginator.next_url %}<a class=\"next\" href=\"{{ paginator.next_url | url }}\">Older</a>{% endif %}\
+         </body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let first = parse_html_file(&output_dir.join("blog/index.html"));
+    assert_eq!(select_text(&first, "p.page"), "1 of 2");
+    assert_eq!(select_text(&first, "h3"), "Second Blog Post");
+    let next = Selector::parse("a.next").unwrap();
+    assert_eq!(
+        first.select(&next).next().unwrap().value().attr("href"),
+        Some("/blog/page/2.html")
+    );
+
+    let second = parse_html_file(&output_dir.join("blog/page/2.html"));
+    assert_eq!(select_text(&second, "p.page"), "2 of 2");
+    assert_eq!(select_text(&second, "h3"), "My First Post");
+    assert_eq!(count_elements(&second, "a.next"), 0);
+
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    assert!(sitemap.contains("<loc>https://test.example.com/blog/page/2.html</loc>"));
+}
+
+#[test]
+fn test_prev_next_and_siblings_in_content_templates() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    fs::write(
+        temp_site.path().join("templates/blog.html"),
+        "<html><body><h1>{{ page.meta.title }}</h1>\
+         {% if prev %}<a class=\"prev\" href=\"/{{ prev.filename | url }}\">{{ prev.meta.title }}</a>{% endif %}\
+         {% if next %}<a class=\"next\" href=\"/{{ next.filename | url }}\">{{ next.meta.title }}</a>{% endif %}\
+         <p class=\"siblings\">{{ siblings | length }}</p></body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("0 unchanged, 3 rendered"));
+
+    // Listing order is newest first: the second post comes before the first
+    let first = parse_html_file(&output_dir.join("blog/2024-01-15-first-post.html"));
+    assert_eq!(select_text(&first, "a.prev"), "Second Blog Post");
+    assert_eq!(count_elements(&first, "a.next"), 0);
+    assert_eq!(select_text(&first, "p.siblings"), "2");
+
+    let second = parse_html_file(&output_dir.join("blog/2024-06-20-second-post.html"));
+    assert_eq!(select_text(&second, "h1"), "Second Blog Post");
+    assert_eq!(select_text(&second, "a.next"), "My First Post");
+    let next = Selector::parse("a.next").unwrap();
+    assert_eq!(
+        second.select(&next).next().unwrap().value().attr("href"),
+        Some("/blog/2024-01-15-first-post.html")
+    );
+
+    // Retitling a post re-renders its neighbours that link to it
+    let meta_path = temp_site.path().join("content/blog/second-post.meta.toml");
+    let meta = fs::read_to_string(&meta_path).unwrap();
+    fs::write(&meta_path, meta.replace("Second Blog Post", "Renamed Post")).unwrap();
+
+    run_ssg(temp_site.path()).stdout(predicate::str::contains("1 unchanged, 2 rendered"));
+    let first = parse_html_file(&output_dir.join("blog/2024-01-15-first-post.html"));
+    assert_eq!(select_text(&first, "a.prev"), "Renamed Post");
+}
+
+#[test]
+fn test_weight_sort_order_for_content_type() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content = config_content.replacen(
+        "output_naming = \"date\"",
+        "output_naming = \"date\"\nsort_by = \"weight\"",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    // Only the older post is weighted, so it now leads the listing
+    let meta_path = temp_site.path().join("content/blog/first-post.meta.toml");
+    let meta = fs::read_to_string(&meta_path).unwrap();
+    fs::write(&meta_path, format!("{}weight = 1\n", meta)).unwrap();
+
+    fs::write(
+        temp_site.path().join("templates/blog.html"),
+        "<html><body>{% if next %}<a class=\"next\">{{ next.meta.title }}</a>{% endif %}</body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    let titles: Vec<String> = index
+        .select(&Selector::parse(".post-summary h3").unwrap())
+        .map(|h| h.text().collect::<String>().trim().to_string())
+        .collect();
+    assert_eq!(titles, vec!["My First Post", "Second Blog Post"]);
+
+    let first = parse_html_file(&output_dir.join("blog/2024-01-15-first-post.html"));
+    assert_eq!(select_text(&first, "a.next"), "Second Blog Post");
+
+    // The site index stays newest first
+    let site_index = fs::read_to_string(output_dir.join("index.html")).unwrap();
+    assert!(
+        site_index.find("Second Blog Post").unwrap() < site_index.find("My First Post").unwrap()
+    );
+}
+
+#[test]
+fn test_nested_sections_render_indexes_and_inherit_config() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content.push_str(
+        r#"
+[content.docs]
+index_template = "docs_index.html"
+content_template = "doc.html"
+
+[content."docs/api"]
+index_template = "docs_index.html"
+content_template = "api.html"
+"#,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    let docs_dir = temp_site.path().join("content/docs");
+    fs::create_dir_all(docs_dir.join("guides/advanced")).unwrap();
+    fs::create_dir_all(docs_dir.join("api")).unwrap();
+    for (path, title) in [
+        ("intro.md", "Introduction"),
+        ("guides/install.md", "Installing"),
+        ("guides/advanced/tuning.md", "Tuning"),
+        ("api/client.md", "Client"),
+    ] {
+        fs::write(
+            docs_dir.join(path),
+            format!("+++\ndate = \"2024-02-01\"\n+++\n\n# {}\n\nBody.\n", title),
+        )
+        .unwrap();
+    }
+
+    let templates = temp_site.path().join("templates");
+    fs::write(
+        templates.join("doc.html"),
+        "<html><body><h1>{{ page.meta.title }}</h1>\
+         <nav>{% for crumb in section.breadcrumbs %}<a href=\"{{ crumb.url | url }}\">{{ crumb.name }}</a>{% endfor %}</nav>\
+         <p class=\"siblings\">{{ siblings | length }}</p></body></html>",
+    )
+    .unwrap();
+    fs::write(
+        templates.join("api.html"),
+        "<html><body><h1 class=\"api\">{{ page.meta.title }}</h1></body></html>",
+    )
+    .unwrap();
+    fs::write(
+        templates.join("docs_index.html"),
+        "<html><body><h1>{{ section.path }}</h1><p class=\"count\">{{ contents | length }}</p>\
+         <ul>{% for child in section.children %}<li>{{ child.name }} ({{ child.count }})</li>{% endfor %}</ul></body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // Pages keep their directories and inherit the parent section's template
+    let tuning = parse_html_file(&output_dir.join("docs/guides/advanced/tuning.html"));
+    assert_eq!(select_text(&tuning, "h1"), "Tuning");
+    assert_eq!(select_text(&tuning, "nav"), "docsguidesadvanced");
+    assert_eq!(select_text(&tuning, "p.siblings"), "1");
+
+    // A nested [content."docs/api"] table overrides the parent's templates
+    let client = parse_html_file(&output_dir.join("docs/api/client.html"));
+    assert_eq!(select_text(&client, "h1.api"), "Client");
+
+    // Every section gets an index listing its pages and subsections
+    let docs_index = parse_html_file(&output_dir.join("docs/index.html"));
+    assert_eq!(select_text(&docs_index, "p.count"), "4");
+    let children: Vec<String> = docs_index
+        .select(&Selector::parse("li").unwrap())
+        .map(|li| li.text().collect())
+        .collect();
+    assert_eq!(children, vec!["api (1)", "guides (2)"]);
+
+    let guides_index = parse_html_file(&output_dir.join("docs/guides/index.html"));
+    assert_eq!(select_text(&guides_index, "h1"), "docs/guides");
+    assert_eq!(select_text(&guides_index, "p.count"), "2");
+    assert!(output_dir.join("docs/guides/advanced/index.html").exists());
+
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    assert!(sitemap.contains("<loc>https://test.example.com/docs/guides/</loc>"));
+}
+
+#[test]
+fn test_root_level_pages_render_standalone() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let config_path = temp_site.path().join("site.toml");
+    let mut config_content = fs::read_to_string(&config_path).unwrap();
+    config_content = config_content.replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\nclean_urls = true\npage_template = \"page.html\"",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    fs::write(
+        temp_site.path().join("content/contact.md"),
+        "+++\ndate = \"2024-02-01\"\n+++\n\n# Contact Us\n\nWrite to us.\n",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // Rendered at /contact/ with site.page_template
+    let contact = parse_html_file(&output_dir.join("contact/index.html"));
+    assert_eq!(select_text(&contact, "h1.page-title"), "Contact Us");
+
+    // No index for a "contact.md" content type, and not listed with the pages type
+    assert!(!output_dir.join("contact.md").exists());
+    let pages_index = fs::read_to_string(output_dir.join("pages/index.html")).unwrap();
+    assert!(!pages_index.contains("Contact Us"));
+
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    assert!(sitemap.contains("<loc>https://test.example.com/contact/</loc>"));
+}
+
+#[test]
+fn test_section_index_content_from_index_md() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    fs::write(
+        temp_site.path().join("content/blog/_index.md"),
+        "+++\ntitle = \"Writing\"\n\n[extra]\nintro_class = \"lead\"\n+++\n\nNotes on **Rust** and static sites.\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_site.path().join("templates/blog_index.html"),
+        "<html><body><h1>{{ section.title }}</h1>\
+         <div class=\"{{ section.extra.intro_class }}\">{{ section.content | safe }}</div>\
+         <p class=\"count\">{{ contents | length }}</p></body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    assert_eq!(select_text(&index, "h1"), "Writing");
+    assert_eq!(select_text(&index, "div.lead strong"), "Rust");
+
+    // _index.md is not a post of its own
+    assert_eq!(select_text(&index, "p.count"), "2");
+    assert!(!output_dir.join("blog/_index.html").exists());
+}
+
+#[test]
+fn test_site_index_content_from_root_index_md() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    fs::write(
+        temp_site.path().join("content/_index.md"),
+        "# Home\n\nHello from the **root**.\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_site.path().join("templates/site_index.html"),
+        "<html><body><h1>{{ section.title }}</h1>\
+         <div class=\"intro\">{{ section.content | safe }}</div>\
+         <ul>{% for child in section.children %}<li>{{ child.path }}</li>{% endfor %}</ul>\
+         </body></html>",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let index = parse_html_file(&output_dir.join("index.html"));
+    assert_eq!(select_text(&index, "h1"), "Home");
+    assert_eq!(select_text(&index, ".intro strong"), "root");
+    assert_eq!(count_elements(&index, "li"), 2);
+    assert!(!output_dir.join("_index.html").exists());
+}
+
+#[test]
+fn test_page_bundle_copies_assets_next_to_page() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let bundle = temp_site.path().join("content/blog/my-trip");
+    fs::create_dir_all(bundle.join("maps")).unwrap();
+    fs::write(
+        bundle.join("index.md"),
+        "+++\ntitle = \"My Trip\"\ndate = \"2024-03-10\"\n\
+         summary = \"![Cover](photo1.jpg)\"\n+++\n\n\
+         ![Beach](photo1.jpg)\n\n[Route](./maps/route.gpx) and [elsewhere](/static/other.gpx)\n",
+    )
+    .unwrap();
+    fs::write(bundle.join("photo1.jpg"), "jpg").unwrap();
+    fs::write(bundle.join("maps/route.gpx"), "gpx").unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // Named after the directory, listed with the other posts of its section
+    let page = fs::read_to_string(output_dir.join("blog/2024-03-10-my-trip.html")).unwrap();
+    assert!(page.contains("src=\"/blog/2024-03-10-my-trip/photo1.jpg\""));
+    assert!(page.contains("href=\"/blog/2024-03-10-my-trip/maps/route.gpx\""));
+    assert!(page.contains("href=\"/static/other.gpx\""));
+    assert!(
+        output_dir
+            .join("blog/2024-03-10-my-trip/photo1.jpg")
+            .exists()
+    );
+    assert!(
+        output_dir
+            .join("blog/2024-03-10-my-trip/maps/route.gpx")
+            .exists()
+    );
+    assert!(!output_dir.join("blog/my-trip").exists());
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    assert_eq!(count_elements(&index, ".post-summary h3"), 3);
+
+    // The summary excerpt links the copied asset in the listing and the feed
+    let index = fs::read_to_string(output_dir.join("blog/index.html")).unwrap();
+    assert!(index.contains("<img src=\"/blog/2024-03-10-my-trip/photo1.jpg\" alt=\"Cover\""));
+    let feed = fs::read_to_string(output_dir.join("feed.xml")).unwrap();
+    assert!(feed.contains("src=&quot;/blog/2024-03-10-my-trip/photo1.jpg&quot;"));
+
+    // With clean URLs the assets sit next to index.html
+    let config_path = temp_site.path().join("site.toml");
+    let config_content = fs::read_to_string(&config_path).unwrap().replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\nclean_urls = true",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let page = fs::read_to_string(output_dir.join("blog/2024-03-10-my-trip/index.html")).unwrap();
+    assert!(page.contains("src=\"/blog/2024-03-10-my-trip/photo1.jpg\""));
+    assert!(
+        output_dir
+            .join("blog/2024-03-10-my-trip/photo1.jpg")
+            .exists()
+    );
+}
+
+#[test]
+fn test_bundle_excerpt_links_resolve_in_index_and_feed() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let bundle = temp_site.path().join("content/blog/trip");
+    fs::create_dir_all(&bundle).unwrap();
+    fs::write(
+        bundle.join("index.md"),
+        "+++\ntitle = \"Trip\"\ndate = \"2024-03-10\"\n+++\n\n# Trip\n\n\
+         ![p](photo.jpg) after [the first post](../first-post.md)\n\n\
+         <!-- more -->\n\nThe rest of the trip.\n",
+    )
+    .unwrap();
+    fs::write(bundle.join("photo.jpg"), "jpg").unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // The excerpt before the marker gets the same rewrites as the page
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    let excerpt = index
+        .select(&Selector::parse(".post-summary .excerpt").unwrap())
+        .map(|e| e.inner_html())
+        .find(|e| e.contains("<img"))
+        .unwrap();
+    assert!(excerpt.contains("src=\"/blog/2024-03-10-trip/photo.jpg\""));
+    assert!(excerpt.contains("href=\"/blog/2024-01-15-first-post.html\""));
+    assert!(!excerpt.contains("The rest of the trip"));
+
+    let feed = fs::read_to_string(output_dir.join("feed.xml")).unwrap();
+    assert!(feed.contains("src=&quot;/blog/2024-03-10-trip/photo.jpg&quot;"));
+    assert!(feed.contains("href=&quot;/blog/2024-01-15-first-post.html&quot;"));
+    assert!(!feed.contains("src=&quot;photo.jpg"));
+    assert!(!feed.contains("first-post.md"));
+}
+
+#[test]
+fn test_markdown_links_resolve_to_output_urls() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    fs::write(
+        temp_site.path().join("content/pages/links.md"),
+        "+++\ndate = \"2024-02-01\"\n+++\n\n# Links\n\n\
+         [First](../blog/first-post.md#intro) and [About](/pages/about.md) \
+         and [Draft](../blog/draft-post.md)\n",
+    )
+    .unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let html = parse_html_file(&output_dir.join("pages/links.html"));
+    let hrefs: Vec<_> = html
+        .select(&Selector::parse(".content a").unwrap())
+        .filter_map(|a| a.value().attr("href"))
+        .collect();
+    assert_eq!(
+        hrefs,
+        vec![
+            "/blog/2024-01-15-first-post.html#intro",
+            "/pages/about.html",
+            // Drafts are not rendered, so the link stays broken (with a warning)
+            "../blog/draft-post.md",
+        ]
+    );
+
+    // With broken_links = "error" the draft link fails the build
+    let config_path = temp_site.path().join("site.toml");
+    let config_content = fs::read_to_string(&config_path).unwrap().replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\nbroken_links = \"error\"",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("BrokenLink"))
+        .stdout(predicate::str::contains("../blog/draft-post.md"));
+}
+
+#[test]
+fn test_output_path_collisions_fail_before_writing() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    // `{stem}` drops the date prefix, so both posts map to blog/intro.html
+    let config_path = temp_site.path().join("site.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .unwrap()
+        .replacen("output_naming = \"date\"", "url_pattern = \"{stem}\"", 1)
+        .replacen(
+            "site_index_template = \"site_index.html\"",
+            "site_index_template = \"site_index.html\"\nasset_hashing_enabled = true",
+            1,
+        );
+    fs::write(&config_path, config_content).unwrap();
+    for name in ["2024-01-01-intro.md", "2025-01-01-intro.md"] {
+        fs::write(
+            temp_site.path().join("content/blog").join(name),
+            "# Intro\n",
+        )
+        .unwrap();
+    }
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("OutputCollisions"))
+        .stdout(predicate::str::contains("content/blog/2024-01-01-intro.md"))
+        .stdout(predicate::str::contains("content/blog/2025-01-01-intro.md"));
+
+    // Not even hashed static assets
+    assert!(!output_dir.join("blog").exists());
+    assert!(!output_dir.join("favicon.ico").exists());
+    assert!(!output_dir.join("static").exists());
+}
+
+#[test]
+fn test_excerpt_from_more_marker_and_fallback() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let blog_dir = temp_site.path().join("content/blog");
+    fs::write(
+        blog_dir.join("2024-02-01-marked.md"),
+        "# Marked\n\nThe **teaser** part.\n\n<!-- more -->\n\nThe rest of the post.\n",
+    )
+    .unwrap();
+    fs::write(
+        blog_dir.join("2024-02-02-plain.md"),
+        "# Plain\n\nOne two three four five six.\n\nSeven eight.\n",
+    )
+    .unwrap();
+
+    let template_path = temp_site.path().join("templates/blog_index.html");
+    let template = fs::read_to_string(&template_path).unwrap().replacen(
+        "<div class=\"tags\">",
+        "<div class=\"excerpt-text\">{{ item.excerpt_text }}</div>\n<div class=\"tags\">",
+        1,
+    );
+    fs::write(&template_path, template).unwrap();
+
+    let config_path = temp_site.path().join("site.toml");
+    let config_content = fs::read_to_string(&config_path).unwrap().replacen(
+        "[content.pages]",
+        "[content.blog.excerpt]\nfallback = \"words\"\nwords = 4\n\n[content.pages]",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let index = fs::read_to_string(output_dir.join("blog/index.html")).unwrap();
+    assert!(index.contains("<p>The <strong>teaser</strong> part.</p>"));
+    assert!(index.contains("<p>One two three four…</p>"));
+    assert!(index.contains("<div class=\"excerpt-text\">The teaser part.</div>"));
+    assert!(!index.contains("The rest of the post"));
+
+    // The marker is not part of the page
+    let page = fs::read_to_string(output_dir.join("blog/2024-02-01-marked.html")).unwrap();
+    assert!(page.contains("The rest of the post."));
+    assert!(!page.contains("more --"));
+}
+
+#[test]
+fn test_table_of_contents_from_headings() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let blog_dir = temp_site.path().join("content/blog");
+    fs::write(
+        blog_dir.join("2024-02-01-marked.md"),
+        "# Marked\n\n[[toc]]\n\n## Install\n\n### From source\n\n## Usage\n",
+    )
+    .unwrap();
+    fs::write(
+        blog_dir.join("2024-02-02-auto.md"),
+        "+++\ntoc = true\n+++\n\n# Auto\n\nIntro.\n\n## Only section\n",
+    )
+    .unwrap();
+
+    let template_path = temp_site.path().join("templates/blog.html");
+    let template = fs::read_to_string(&template_path).unwrap().replacen(
+        "<div class=\"content\">",
+        "<ul class=\"page-toc\">{% for entry in toc %}<li>{{ entry.text }} {{ entry.children | length }}</li>{% endfor %}</ul>\n<div class=\"content\">",
+        1,
+    );
+    fs::write(&template_path, template).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    let marked = parse_html_file(&output_dir.join("blog/2024-02-01-marked.html"));
+    assert_eq!(select_text(&marked, ".page-toc li"), "Install 1");
+    assert_eq!(count_elements(&marked, ".page-toc li"), 2);
+    assert_eq!(count_elements(&marked, ".content nav.toc > ul > li"), 2);
+    assert_eq!(
+        count_elements(&marked, ".content nav.toc a[href=\"#from-source\"]"),
+        1
+    );
+    assert_eq!(count_elements(&marked, ".content h3#from-source"), 1);
+
+    // `toc = true` inserts it after the title
+    let auto = fs::read_to_string(output_dir.join("blog/2024-02-02-auto.html")).unwrap();
+    assert!(auto.contains(
+        "<h1 id=\"auto\">Auto</h1><nav class=\"toc\"><ul><li><a href=\"#only-section\">Only section</a></li></ul></nav>"
+    ));
+
+    // Other pages get no table of contents in their body
+    let first = fs::read_to_string(output_dir.join("blog/2024-01-15-first-post.html")).unwrap();
+    assert!(!first.contains("<nav class=\"toc\">"));
+}
+
+#[test]
+fn test_word_count_and_reading_time() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    fs::write(
+        temp_site.path().join("content/blog/2024-02-01-counted.md"),
+        "# Counted post\n\nSix <em>words</em> in this short sentence.\n\n\
+         ```rust\nfn not_counted() {}\n```\n\n静的サイト\n",
+    )
+    .unwrap();
+
+    let config_path = temp_site.path().join("site.toml");
+    let config_content = fs::read_to_string(&config_path).unwrap().replacen(
+        "site_index_template = \"site_index.html\"",
+        "site_index_template = \"site_index.html\"\nwords_per_minute = 4",
+        1,
+    );
+    fs::write(&config_path, config_content).unwrap();
+
+    let template_path = temp_site.path().join("templates/blog.html");
+    let template = fs::read_to_string(&template_path).unwrap().replacen(
+        "<div class=\"content\">",
+        "<span class=\"words\">{{ word_count }}/{{ page.word_count }}</span>\
+         <span class=\"reading\">{{ reading_time_minutes }}</span>\n<div class=\"content\">",
+        1,
+    );
+    fs::write(&template_path, template).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // 2 title words + 6 + 5 CJK characters, at 4 words per minute
+    let page = parse_html_file(&output_dir.join("blog/2024-02-01-counted.html"));
+    assert_eq!(select_text(&page, ".words"), "13/13");
+    assert_eq!(select_text(&page, ".reading"), "4");
+}
+
+/// Helper to run marie-ssg CLI with extra build arguments
+fn run_ssg_with_args(site_dir: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(site_dir)
+        .arg("build")
+        .arg("-c")
+        .arg("site.toml")
+        .args(args)
+        .assert()
+        .success()
+}
+
+#[test]
+fn test_scheduled_and_expired_content() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let blog_dir = temp_site.path().join("content/blog");
+    fs::write(
+        blog_dir.join("scheduled.md"),
+        "+++\ntitle = \"Scheduled\"\ndate = \"2024-03-01\"\npublish_date = \"2024-06-01T09:00:00Z\"\n+++\n\nSoon.\n",
+    )
+    .unwrap();
+    fs::write(
+        blog_dir.join("offer.md"),
+        "+++\ntitle = \"Offer\"\ndate = \"2024-03-01\"\nexpiry_date = \"2024-05-01\"\n+++\n\nLimited.\n",
+    )
+    .unwrap();
+
+    let scheduled = output_dir.join("blog/2024-03-01-scheduled.html");
+    let offer = output_dir.join("blog/2024-03-01-offer.html");
+    let first_post = output_dir.join("blog/2024-01-15-first-post.html");
+
+    // Before the publish date the post is left out, and so are posts dated later
+    run_ssg_with_args(temp_site.path(), &["--now", "2024-04-01"]);
+    assert!(!scheduled.exists());
+    assert!(offer.exists());
+    assert!(first_post.exists());
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    assert!(!sitemap.contains("scheduled"));
+
+    fs::remove_dir_all(&output_dir).unwrap();
+    run_ssg_with_args(temp_site.path(), &["--now", "2024-01-01"]);
+    assert!(!first_post.exists());
+
+    // --include-future previews scheduled posts
+    fs::remove_dir_all(&output_dir).unwrap();
+    run_ssg_with_args(
+        temp_site.path(),
+        &["--now", "2024-04-01", "--include-future"],
+    );
+    assert!(scheduled.exists());
+
+    // After the expiry date the post is dropped
+    fs::remove_dir_all(&output_dir).unwrap();
+    run_ssg_with_args(temp_site.path(), &["--now", "2024-06-01T09:00:00Z"]);
+    assert!(scheduled.exists());
+    assert!(!offer.exists());
+    // The first post and the scheduled one; the second post is dated 2024-06-20
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    assert_eq!(count_elements(&index, ".post-summary"), 2);
+}
+
+#[test]
+fn test_invalid_now_fails() {
+    let temp_site = setup_test_site();
+
+    cargo_bin_cmd!("marie-ssg")
+        .current_dir(temp_site.path())
+        .arg("build")
+        .arg("--now")
+        .arg("tomorrow")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid --now"));
+}
+
+#[test]
+fn test_updated_date() {
+    let temp_site = setup_test_site();
+    let output_dir = temp_site.path().join("output");
+
+    let meta_path = temp_site.path().join("content/blog/first-post.meta.toml");
+    let meta = fs::read_to_string(&meta_path).unwrap();
+    fs::write(&meta_path, format!("{}updated = \"2024-09-01\"\n", meta)).unwrap();
+
+    let template_path = temp_site.path().join("templates/blog_index.html");
+    let template = fs::read_to_string(&template_path).unwrap().replacen(
+        "<article class=\"post-summary\">",
+        "<article class=\"post-summary\">\
+         {% if item.formatted_updated %}<span class=\"updated\">{{ item.formatted_updated }}</span>{% endif %}",
+        1,
+    );
+    fs::write(&template_path, template).unwrap();
+
+    run_ssg(temp_site.path()).success();
+
+    // The revised post and every listing it is on take its updated date
+    let sitemap = fs::read_to_string(output_dir.join("sitemap.xml")).unwrap();
+    for path in ["blog/2024-01-15-first-post.html", "blog/", ""] {
+        assert!(
+            sitemap.contains(&format!(
+                "<loc>https://test.example.com/{}</loc>\n    <lastmod>2024-09-01</lastmod>",
+                path
+            )),
+            "lastmod of /{} should be the updated date",
+            path
+        );
+    }
+    assert!(sitemap.contains(
+        "<loc>https://test.example.com/blog/2024-06-20-second-post.html</loc>\n    <lastmod>2024-06-20</lastmod>"
+    ));
+
+    let feed = fs::read_to_string(output_dir.join("feed.xml")).unwrap();
+    assert_eq!(feed.matches("<atom:updated>").count(), 1);
+    assert!(feed.contains("<atom:updated>2024-09-01T00:00:00Z</atom:updated>"));
+
+    let index = parse_html_file(&output_dir.join("blog/index.html"));
+    assert_eq!(count_elements(&index, ".updated"), 1);
+    assert_eq!(select_text(&index, ".updated"), "September 01, 2024");
+}